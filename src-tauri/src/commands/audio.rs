@@ -14,19 +14,27 @@ pub async fn set_master_volume(volume: u32) -> Result<(), String> {
     audio::set_master_volume(volume)
 }
 
-/// Adjust master volume by delta (-100 to +100)
+/// Adjust master volume one step in the direction of `delta` (step size comes from the
+/// profile's `volumeStep`). Returns the new volume.
 #[tauri::command]
 pub async fn adjust_master_volume(delta: i32) -> Result<u32, String> {
+    let step = super::config::get_active_profile()
+        .map(|c| c.volume_step)
+        .unwrap_or(2)
+        .clamp(1, 100) as i32;
+
     let current = audio::get_audio_data();
-    let new_volume = ((current.master_volume as i32) + delta).clamp(0, 100) as u32;
+    let new_volume = ((current.master_volume as i32) + delta.signum() * step).clamp(0, 100) as u32;
     audio::set_master_volume(new_volume)?;
     Ok(new_volume)
 }
 
-/// Toggle mute on master volume
+/// Toggle mute on master volume (emits `audio-mute-changed`)
 #[tauri::command]
-pub async fn toggle_mute() -> Result<bool, String> {
-    audio::toggle_mute()
+pub async fn toggle_mute(app: tauri::AppHandle) -> Result<bool, String> {
+    let muted = audio::toggle_mute()?;
+    audio::emit_mute_changed(&app, muted);
+    Ok(muted)
 }
 
 /// Set volume for a specific device
@@ -0,0 +1,21 @@
+//! Clipboard history commands for Tauri
+
+use crate::services::clipboard_history::{self, ClipboardEntry};
+
+/// Get recent clipboard text entries (newest first)
+#[tauri::command]
+pub fn get_clipboard_history() -> Vec<ClipboardEntry> {
+    clipboard_history::get_history()
+}
+
+/// Copy a history entry back to the clipboard
+#[tauri::command]
+pub fn copy_history_item(index: usize) -> Result<(), String> {
+    clipboard_history::copy_item(index)
+}
+
+/// Forget all clipboard history entries
+#[tauri::command]
+pub fn clear_clipboard_history() {
+    clipboard_history::clear_history()
+}
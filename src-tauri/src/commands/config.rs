@@ -1,9 +1,21 @@
 //! Profile-based configuration management
 
+use super::notes::{self, Note};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
-use std::path::PathBuf;
-use tauri::{AppHandle, Manager};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, SystemTime};
+use tauri::{AppHandle, Emitter, Manager};
+
+/// How often the active profile file is checked for external edits
+const PROFILE_WATCH_INTERVAL: Duration = Duration::from_secs(1);
+/// Editors often save in several steps; wait this long for the file to settle
+const PROFILE_WATCH_SETTLE: Duration = Duration::from_millis(300);
+
+/// Modification time of each profile file right after we wrote it
+static OWN_WRITES: OnceLock<Mutex<HashMap<PathBuf, SystemTime>>> = OnceLock::new();
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct WidgetConfig {
@@ -12,6 +24,9 @@ pub struct WidgetConfig {
     pub widget_type: String,
     pub enabled: bool,
     pub order: u32,
+    /// Per-instance settings (e.g. a clock's `{ timezone, format }`, a storage widget's `{ letter }`)
+    #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
+    pub settings: serde_json::Value,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -24,6 +39,9 @@ pub struct DisplayConfig {
     pub theme: String,
     pub opacity: f32,
     pub blur: bool,
+    /// Slide the bar away when the cursor leaves it (revealed at the top edge)
+    #[serde(default, alias = "auto_hide")]
+    pub auto_hide: bool,
 }
 
 impl Default for DisplayConfig {
@@ -34,6 +52,7 @@ impl Default for DisplayConfig {
             theme: "dark".to_string(),
             opacity: 0.95,
             blur: true,
+            auto_hide: false,
         }
     }
 }
@@ -93,6 +112,10 @@ pub struct FolderShortcut {
     pub path: String,
     pub icon: String,
     pub enabled: bool,
+    /// Custom command used to open this folder, with a `{path}` placeholder
+    /// (e.g. `code {path}`); `None` uses the file manager / Explorer
+    #[serde(default)]
+    pub open_with: Option<String>,
 }
 
 /// Folder shortcuts config
@@ -113,6 +136,7 @@ impl Default for FolderShortcutsConfig {
                     path: home.join("Downloads").to_string_lossy().to_string(),
                     icon: "download".to_string(),
                     enabled: true,
+                    open_with: None,
                 },
                 FolderShortcut {
                     id: "documents".to_string(),
@@ -120,6 +144,7 @@ impl Default for FolderShortcutsConfig {
                     path: home.join("Documents").to_string_lossy().to_string(),
                     icon: "file-text".to_string(),
                     enabled: true,
+                    open_with: None,
                 },
                 FolderShortcut {
                     id: "pictures".to_string(),
@@ -127,6 +152,7 @@ impl Default for FolderShortcutsConfig {
                     path: home.join("Pictures").to_string_lossy().to_string(),
                     icon: "image".to_string(),
                     enabled: true,
+                    open_with: None,
                 },
                 FolderShortcut {
                     id: "music".to_string(),
@@ -134,6 +160,7 @@ impl Default for FolderShortcutsConfig {
                     path: home.join("Music").to_string_lossy().to_string(),
                     icon: "music".to_string(),
                     enabled: true,
+                    open_with: None,
                 },
                 FolderShortcut {
                     id: "videos".to_string(),
@@ -141,12 +168,109 @@ impl Default for FolderShortcutsConfig {
                     path: home.join("Videos").to_string_lossy().to_string(),
                     icon: "video".to_string(),
                     enabled: true,
+                    open_with: None,
                 },
             ],
         }
     }
 }
 
+/// Pinned app launcher shown in the bar
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct QuickLaunchItem {
+    pub id: String,
+    pub name: String,
+    /// Executable path (environment variables and `~` are expanded at launch)
+    pub exe_path: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// Custom icon; `None` uses the executable's own icon (`get_process_icon`)
+    #[serde(default)]
+    pub icon: Option<String>,
+}
+
+/// Quick launch config
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct QuickLaunchConfig {
+    pub items: Vec<QuickLaunchItem>,
+}
+
+/// Usage percentages and temperatures (°C) at which a metric becomes "warn" / "crit"
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ThresholdsConfig {
+    pub cpu_warn: f32,
+    pub cpu_crit: f32,
+    pub ram_warn: f32,
+    pub ram_crit: f32,
+    pub gpu_warn: f32,
+    pub gpu_crit: f32,
+    pub temp_warn: f32,
+    pub temp_crit: f32,
+}
+
+impl Default for ThresholdsConfig {
+    fn default() -> Self {
+        Self {
+            cpu_warn: 70.0,
+            cpu_crit: 90.0,
+            ram_warn: 75.0,
+            ram_crit: 90.0,
+            gpu_warn: 70.0,
+            gpu_crit: 90.0,
+            temp_warn: 75.0,
+            temp_crit: 85.0,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkConfig {
+    /// Monthly data cap in GB (0 disables the data-cap warnings)
+    #[serde(default)]
+    pub monthly_data_cap_gb: f64,
+    /// Percentages of the cap that trigger a `data-cap-warning` event
+    #[serde(default = "default_data_cap_warning_percents")]
+    pub data_cap_warning_percents: Vec<u32>,
+    /// Saved adapter configurations (DHCP or static IP) applied via netsh
+    #[serde(default)]
+    pub adapter_profiles: Vec<NetworkAdapterProfile>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkAdapterProfile {
+    pub name: String,
+    /// Adapter (interface) name as shown by `netsh interface show interface`
+    pub adapter: String,
+    pub dhcp: bool,
+    #[serde(default)]
+    pub ip_address: Option<String>,
+    #[serde(default)]
+    pub subnet_mask: Option<String>,
+    #[serde(default)]
+    pub gateway: Option<String>,
+    #[serde(default)]
+    pub dns_servers: Vec<String>,
+}
+
+fn default_data_cap_warning_percents() -> Vec<u32> {
+    vec![80, 100]
+}
+
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        Self {
+            monthly_data_cap_gb: 0.0,
+            data_cap_warning_percents: default_data_cap_warning_percents(),
+            adapter_profiles: Vec::new(),
+        }
+    }
+}
+
 impl Default for WeatherConfig {
     fn default() -> Self {
         Self {
@@ -172,6 +296,32 @@ pub struct AppConfig {
     pub weather: WeatherConfig,
     #[serde(default)]
     pub folder_shortcuts: FolderShortcutsConfig,
+    #[serde(default)]
+    pub network: NetworkConfig,
+    /// Volume change (percent) per scroll-wheel notch over the audio widget
+    #[serde(default = "default_volume_step")]
+    pub volume_step: u32,
+    /// Accent color per widget id (e.g. "cpu-1" -> "#22c55e")
+    #[serde(default)]
+    pub widget_colors: HashMap<String, String>,
+    /// Pre-create popup windows at startup (faster first open, more memory)
+    #[serde(default = "default_true")]
+    pub prewarm_enabled: bool,
+    /// Global accelerator for the task switcher (e.g. "Alt+`"); `None` disables it
+    #[serde(default)]
+    pub task_switcher_hotkey: Option<String>,
+    /// Warning/critical levels used to compute metric status in system snapshots
+    #[serde(default)]
+    pub thresholds: ThresholdsConfig,
+    #[serde(default)]
+    pub quick_launch: QuickLaunchConfig,
+    /// Executable used by `open_folder` instead of Explorer (e.g. Total Commander)
+    #[serde(default)]
+    pub file_manager: Option<String>,
+}
+
+fn default_volume_step() -> u32 {
+    2
 }
 
 impl Default for AppConfig {
@@ -194,65 +344,83 @@ impl AppConfig {
                     widget_type: "cpu".to_string(),
                     enabled: true,
                     order: 0,
+                    settings: serde_json::Value::Null,
                 },
                 WidgetConfig {
                     id: "ram-1".to_string(),
                     widget_type: "ram".to_string(),
                     enabled: true,
                     order: 1,
+                    settings: serde_json::Value::Null,
                 },
                 WidgetConfig {
                     id: "gpu-1".to_string(),
                     widget_type: "gpu".to_string(),
                     enabled: true,
                     order: 2,
+                    settings: serde_json::Value::Null,
                 },
                 WidgetConfig {
                     id: "storage-1".to_string(),
                     widget_type: "storage".to_string(),
                     enabled: true,
                     order: 3,
+                    settings: serde_json::Value::Null,
                 },
                 WidgetConfig {
                     id: "network-1".to_string(),
                     widget_type: "network".to_string(),
                     enabled: true,
                     order: 4,
+                    settings: serde_json::Value::Null,
                 },
                 WidgetConfig {
                     id: "media-1".to_string(),
                     widget_type: "media".to_string(),
                     enabled: true,
                     order: 5,
+                    settings: serde_json::Value::Null,
                 },
                 WidgetConfig {
                     id: "audio-1".to_string(),
                     widget_type: "audio".to_string(),
                     enabled: true,
                     order: 90,
+                    settings: serde_json::Value::Null,
                 },
                 WidgetConfig {
                     id: "headset-1".to_string(),
                     widget_type: "headset".to_string(),
                     enabled: true,
                     order: 91,
+                    settings: serde_json::Value::Null,
                 },
                 WidgetConfig {
                     id: "weather-1".to_string(),
                     widget_type: "weather".to_string(),
                     enabled: true,
                     order: 92,
+                    settings: serde_json::Value::Null,
                 },
                 WidgetConfig {
                     id: "clock-1".to_string(),
                     widget_type: "clock".to_string(),
                     enabled: true,
                     order: 93,
+                    settings: serde_json::Value::Null,
                 },
             ],
             polling: PollingConfig::default(),
             weather: WeatherConfig::default(),
             folder_shortcuts: FolderShortcutsConfig::default(),
+            network: NetworkConfig::default(),
+            volume_step: default_volume_step(),
+            widget_colors: HashMap::new(),
+            prewarm_enabled: true,
+            task_switcher_hotkey: None,
+            thresholds: ThresholdsConfig::default(),
+            quick_launch: QuickLaunchConfig::default(),
+            file_manager: None,
         }
     }
 }
@@ -265,6 +433,23 @@ pub struct ProfileSummary {
     pub modified_at: String,
 }
 
+/// Full application state bundle used to migrate between machines.
+///
+/// Folder shortcuts live inside each profile, so they travel with `profiles`.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppStateBundle {
+    pub version: u32,
+    pub exported_at: String,
+    pub active_profile: String,
+    pub profiles: BTreeMap<String, AppConfig>,
+    /// `None` when the bundle has no notes section; importing then leaves notes untouched
+    #[serde(default)]
+    pub notes: Option<Vec<Note>>,
+}
+
+const APP_STATE_BUNDLE_VERSION: u32 = 1;
+
 /// Get the profiles directory (next to executable)
 fn get_profiles_dir() -> PathBuf {
     std::env::current_exe()
@@ -278,8 +463,9 @@ fn ensure_default_profile(dir: &PathBuf) -> Result<(), String> {
     fs::create_dir_all(dir).map_err(|e| e.to_string())?;
     let default_config = AppConfig::default();
     let content = serde_json::to_string_pretty(&default_config).map_err(|e| e.to_string())?;
-    fs::write(dir.join("default.json"), content).map_err(|e| e.to_string())?;
+    write_profile_file(&dir.join("default.json"), &content)?;
     fs::write(dir.join("_active.txt"), "default").map_err(|e| e.to_string())?;
+    apply_active_config(&default_config);
     Ok(())
 }
 
@@ -301,6 +487,94 @@ fn sanitize_filename(name: &str) -> String {
         .to_lowercase()
 }
 
+/// Parse JSON, reporting the path of the field that failed
+/// (e.g. "invalid value at display.opacity: invalid type: string \"x\", expected f32 at line 9 column 20").
+fn parse_json_with_path<T: serde::de::DeserializeOwned>(content: &str) -> Result<T, String> {
+    let deserializer = &mut serde_json::Deserializer::from_str(content);
+    serde_path_to_error::deserialize(deserializer).map_err(|e| {
+        let path = e.path().to_string();
+        if path == "." {
+            e.into_inner().to_string()
+        } else {
+            format!("invalid value at {path}: {}", e.into_inner())
+        }
+    })
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Write a profile file and remember the write so the profile watcher ignores it.
+fn write_profile_file(path: &Path, content: &str) -> Result<(), String> {
+    fs::write(path, content).map_err(|e| e.to_string())?;
+    if let Some(modified) = modified_time(path) {
+        if let Ok(mut own) = OWN_WRITES.get_or_init(Default::default).lock() {
+            own.insert(path.to_path_buf(), modified);
+        }
+    }
+    Ok(())
+}
+
+fn is_own_write(path: &Path, modified: Option<SystemTime>) -> bool {
+    OWN_WRITES
+        .get()
+        .and_then(|own| own.lock().ok()?.get(path).copied())
+        .is_some_and(|own| Some(own) == modified)
+}
+
+/// Watch the active profile file and reload it when it is edited outside the app.
+///
+/// Emits `profile-externally-changed` with the reloaded config. Files that don't parse
+/// (e.g. half-saved) are skipped until the next change.
+pub fn start_profile_watcher(app: AppHandle) {
+    std::thread::Builder::new()
+        .name("profile-watcher".to_string())
+        .spawn(move || {
+            let mut watched: Option<(PathBuf, Option<SystemTime>)> = None;
+            loop {
+                crate::services::session::sleep_poll(PROFILE_WATCH_INTERVAL);
+
+                let path = get_profiles_dir().join(format!("{}.json", get_active_profile_name()));
+                let modified = modified_time(&path);
+
+                match &watched {
+                    Some((p, m)) if *p == path && *m == modified => continue,
+                    Some((p, _)) if *p == path => {}
+                    // First run or the active profile changed: just take a new baseline.
+                    _ => {
+                        watched = Some((path, modified));
+                        continue;
+                    }
+                }
+
+                if is_own_write(&path, modified) {
+                    watched = Some((path, modified));
+                    continue;
+                }
+
+                std::thread::sleep(PROFILE_WATCH_SETTLE);
+                if modified_time(&path) != modified {
+                    // Still being written; pick it up on the next tick.
+                    continue;
+                }
+                watched = Some((path.clone(), modified));
+
+                let Ok(content) = fs::read_to_string(&path) else {
+                    continue;
+                };
+                match parse_json_with_path::<AppConfig>(&content) {
+                    Ok(config) => {
+                        apply_active_config(&config);
+                        let _ = app.emit("profile-externally-changed", &config);
+                    }
+                    Err(e) => eprintln!("Ignoring invalid profile edit ({}): {e}", path.display()),
+                }
+            }
+        })
+        .ok();
+}
+
 /// List all available profiles
 #[tauri::command]
 pub fn list_profiles() -> Result<Vec<ProfileSummary>, String> {
@@ -355,11 +629,184 @@ pub fn create_profile(name: String) -> Result<String, String> {
 
     let config = AppConfig::default_with_name(&name);
     let content = serde_json::to_string_pretty(&config).map_err(|e| e.to_string())?;
-    fs::write(&path, content).map_err(|e| e.to_string())?;
+    write_profile_file(&path, &content)?;
 
     Ok(filename)
 }
 
+/// Rename a profile, moving it to the sanitized filename of `new_name`
+#[tauri::command]
+pub fn rename_profile(old_filename: String, new_name: String) -> Result<String, String> {
+    let dir = get_profiles_dir();
+    let old_path = dir.join(format!("{}.json", old_filename));
+
+    if !old_path.exists() {
+        return Err("Profile not found".to_string());
+    }
+
+    let new_filename = sanitize_filename(&new_name);
+    let new_path = dir.join(format!("{}.json", new_filename));
+
+    if new_filename != old_filename && new_path.exists() {
+        return Err("Profile already exists".to_string());
+    }
+
+    let content = fs::read_to_string(&old_path).map_err(|e| e.to_string())?;
+    let mut config: AppConfig = serde_json::from_str(&content).map_err(|e| e.to_string())?;
+    config.profile_name = new_name;
+    config.modified_at = chrono::Utc::now().to_rfc3339();
+
+    let content = serde_json::to_string_pretty(&config).map_err(|e| e.to_string())?;
+    write_profile_file(&new_path, &content)?;
+
+    if new_filename != old_filename {
+        fs::remove_file(&old_path).map_err(|e| e.to_string())?;
+
+        if get_active_profile_name() == old_filename {
+            fs::write(dir.join("_active.txt"), &new_filename).map_err(|e| e.to_string())?;
+        }
+    }
+
+    Ok(new_filename)
+}
+
+/// Duplicate a profile under a new name
+#[tauri::command]
+pub fn duplicate_profile(filename: String, new_name: String) -> Result<String, String> {
+    let dir = get_profiles_dir();
+    let source = dir.join(format!("{}.json", filename));
+
+    if !source.exists() {
+        return Err("Profile not found".to_string());
+    }
+
+    let new_filename = sanitize_filename(&new_name);
+    let dest = dir.join(format!("{}.json", new_filename));
+
+    if dest.exists() {
+        return Err("Profile already exists".to_string());
+    }
+
+    let content = fs::read_to_string(&source).map_err(|e| e.to_string())?;
+    let mut config: AppConfig = serde_json::from_str(&content).map_err(|e| e.to_string())?;
+    let now = chrono::Utc::now().to_rfc3339();
+    config.profile_name = new_name;
+    config.created_at = now.clone();
+    config.modified_at = now;
+
+    let content = serde_json::to_string_pretty(&config).map_err(|e| e.to_string())?;
+    write_profile_file(&dest, &content)?;
+
+    Ok(new_filename)
+}
+
+/// Reset one profile to the default layout, keeping its name and creation date.
+///
+/// If it is the active profile, `config-changed` is emitted so the UI reloads.
+#[tauri::command]
+pub fn reset_profile_to_defaults(app: AppHandle, filename: String) -> Result<AppConfig, String> {
+    let dir = get_profiles_dir();
+    let path = dir.join(format!("{}.json", filename));
+
+    if !path.exists() {
+        return Err("Profile not found".to_string());
+    }
+
+    // Keep the identity even if the rest of the file is broken.
+    let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    let existing: serde_json::Value = serde_json::from_str(&content).unwrap_or_default();
+    let name = existing
+        .get("profileName")
+        .and_then(|v| v.as_str())
+        .unwrap_or(&filename)
+        .to_string();
+
+    let mut config = AppConfig::default_with_name(&name);
+    if let Some(created_at) = existing.get("createdAt").and_then(|v| v.as_str()) {
+        config.created_at = created_at.to_string();
+    }
+
+    let content = serde_json::to_string_pretty(&config).map_err(|e| e.to_string())?;
+    write_profile_file(&path, &content)?;
+
+    if get_active_profile_name() == filename {
+        apply_active_config(&config);
+        let _ = app.emit("config-changed", &config);
+    }
+
+    Ok(config)
+}
+
+/// Overwrite the named sections of `config` with the ones from `from`.
+fn merge_profile_sections(
+    config: &mut AppConfig,
+    from: &AppConfig,
+    sections: &[String],
+) -> Result<(), String> {
+    for section in sections {
+        match section.as_str() {
+            "display" => config.display = from.display.clone(),
+            "widgets" => {
+                config.widgets = from.widgets.clone();
+                config.widget_colors = from.widget_colors.clone();
+            }
+            "polling" => config.polling = from.polling.clone(),
+            "weather" => config.weather = from.weather.clone(),
+            "folders" => config.folder_shortcuts = from.folder_shortcuts.clone(),
+            unknown => return Err(format!("Unknown profile section: {unknown}")),
+        }
+    }
+    Ok(())
+}
+
+/// Copy selected sections ("display", "widgets", "polling", "weather", "folders") from
+/// one profile into another. Everything else in the target is left untouched.
+///
+/// If the target is the active profile, `config-changed` is emitted so the UI reloads.
+#[tauri::command]
+pub fn merge_profiles(
+    app: AppHandle,
+    source: String,
+    target: String,
+    sections: Vec<String>,
+) -> Result<AppConfig, String> {
+    if sections.is_empty() {
+        return Err("No sections selected".to_string());
+    }
+    if source == target {
+        return Err("Source and target must be different profiles".to_string());
+    }
+
+    let dir = get_profiles_dir();
+    let source_path = dir.join(format!("{}.json", source));
+    let target_path = dir.join(format!("{}.json", target));
+
+    if !source_path.exists() || !target_path.exists() {
+        return Err("Profile not found".to_string());
+    }
+
+    let content = fs::read_to_string(&source_path).map_err(|e| e.to_string())?;
+    let from: AppConfig = parse_json_with_path(&content)
+        .map_err(|e| format!("Failed to read profile '{source}': {e}"))?;
+    let content = fs::read_to_string(&target_path).map_err(|e| e.to_string())?;
+    let mut config: AppConfig = parse_json_with_path(&content)
+        .map_err(|e| format!("Failed to read profile '{target}': {e}"))?;
+
+    // Nothing is written until every section was applied, so an unknown one aborts cleanly.
+    merge_profile_sections(&mut config, &from, &sections)?;
+    config.modified_at = chrono::Utc::now().to_rfc3339();
+
+    let content = serde_json::to_string_pretty(&config).map_err(|e| e.to_string())?;
+    write_profile_file(&target_path, &content)?;
+
+    if get_active_profile_name() == target {
+        apply_active_config(&config);
+        let _ = app.emit("config-changed", &config);
+    }
+
+    Ok(config)
+}
+
 /// Switch to a different profile
 #[tauri::command]
 pub fn switch_profile(filename: String) -> Result<AppConfig, String> {
@@ -375,7 +822,9 @@ pub fn switch_profile(filename: String) -> Result<AppConfig, String> {
 
     // Load and return profile
     let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
-    serde_json::from_str(&content).map_err(|e| e.to_string())
+    let config: AppConfig = serde_json::from_str(&content).map_err(|e| e.to_string())?;
+    apply_active_config(&config);
+    Ok(config)
 }
 
 /// Save current profile
@@ -387,9 +836,10 @@ pub fn save_current_profile(config: AppConfig) -> Result<(), String> {
 
     let mut updated = config;
     updated.modified_at = chrono::Utc::now().to_rfc3339();
+    apply_active_config(&updated);
 
     let content = serde_json::to_string_pretty(&updated).map_err(|e| e.to_string())?;
-    fs::write(&path, content).map_err(|e| e.to_string())?;
+    write_profile_file(&path, &content)?;
 
     Ok(())
 }
@@ -406,7 +856,7 @@ pub fn get_active_profile() -> Result<AppConfig, String> {
         let config = AppConfig::default();
         fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
         let content = serde_json::to_string_pretty(&config).map_err(|e| e.to_string())?;
-        fs::write(&path, content).map_err(|e| e.to_string())?;
+        write_profile_file(&path, &content)?;
         fs::write(dir.join("_active.txt"), "default").map_err(|e| e.to_string())?;
         return Ok(config);
     }
@@ -415,6 +865,24 @@ pub fn get_active_profile() -> Result<AppConfig, String> {
     serde_json::from_str(&content).map_err(|e| e.to_string())
 }
 
+/// The active profile file exactly as stored on disk (no defaults applied)
+#[tauri::command]
+pub fn get_raw_config() -> Result<serde_json::Value, String> {
+    let path = get_profiles_dir().join(format!("{}.json", get_active_profile_name()));
+    let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&content).map_err(|e| e.to_string())
+}
+
+/// The active profile as the app actually uses it: missing fields filled with their
+/// defaults. Compare with `get_raw_config` to see which settings come from defaults.
+#[tauri::command]
+pub fn get_effective_config() -> Result<serde_json::Value, String> {
+    let path = get_profiles_dir().join(format!("{}.json", get_active_profile_name()));
+    let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    let config: AppConfig = parse_json_with_path(&content)?;
+    serde_json::to_value(&config).map_err(|e| e.to_string())
+}
+
 /// Export a profile to a file
 #[tauri::command]
 pub fn export_profile(filename: String, destination: String) -> Result<(), String> {
@@ -427,15 +895,109 @@ pub fn export_profile(filename: String, destination: String) -> Result<(), Strin
 #[tauri::command]
 pub fn import_profile(source: String) -> Result<String, String> {
     let content = fs::read_to_string(&source).map_err(|e| e.to_string())?;
-    let config: AppConfig = serde_json::from_str(&content).map_err(|e| e.to_string())?;
+    let config: AppConfig = parse_json_with_path(&content)?;
 
     let filename = sanitize_filename(&config.profile_name);
     let dest = get_profiles_dir().join(format!("{}.json", filename));
-    fs::write(&dest, &content).map_err(|e| e.to_string())?;
+    write_profile_file(&dest, &content)?;
 
     Ok(filename)
 }
 
+/// Export every profile, the active profile marker and notes into a single JSON file
+#[tauri::command]
+pub fn export_all(app: AppHandle, destination: String) -> Result<(), String> {
+    let dir = get_profiles_dir();
+    let mut profiles = BTreeMap::new();
+
+    if dir.exists() {
+        for entry in fs::read_dir(&dir).map_err(|e| e.to_string())?.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+            let Some(filename) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+
+            let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+            let config: AppConfig = serde_json::from_str(&content)
+                .map_err(|e| format!("Failed to parse profile '{filename}': {e}"))?;
+            profiles.insert(filename.to_string(), config);
+        }
+    }
+
+    let notes = notes::read_all_notes(&app)?;
+
+    let bundle = AppStateBundle {
+        version: APP_STATE_BUNDLE_VERSION,
+        exported_at: chrono::Utc::now().to_rfc3339(),
+        active_profile: get_active_profile_name(),
+        profiles,
+        notes: Some(notes),
+    };
+
+    let content = serde_json::to_string_pretty(&bundle).map_err(|e| e.to_string())?;
+    fs::write(&destination, content).map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// Restore a bundle written by `export_all`, overwriting existing files.
+///
+/// The whole bundle is validated before anything is written, so a malformed file
+/// leaves the current state untouched. Returns the imported profile filenames.
+#[tauri::command]
+pub fn import_all(app: AppHandle, source: String) -> Result<Vec<String>, String> {
+    let content = fs::read_to_string(&source).map_err(|e| e.to_string())?;
+    let bundle: AppStateBundle =
+        parse_json_with_path(&content).map_err(|e| format!("Invalid app state bundle: {e}"))?;
+
+    if bundle.version > APP_STATE_BUNDLE_VERSION {
+        return Err(format!(
+            "Unsupported app state bundle version: {}",
+            bundle.version
+        ));
+    }
+    if bundle.profiles.is_empty() {
+        return Err("App state bundle contains no profiles".to_string());
+    }
+    if let Some(bad) = bundle
+        .profiles
+        .keys()
+        .find(|name| name.is_empty() || sanitize_filename(name) != **name)
+    {
+        return Err(format!("Invalid profile filename in bundle: '{bad}'"));
+    }
+
+    let dir = get_profiles_dir();
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+
+    let mut imported = Vec::with_capacity(bundle.profiles.len());
+    for (filename, config) in &bundle.profiles {
+        let content = serde_json::to_string_pretty(config).map_err(|e| e.to_string())?;
+        write_profile_file(&dir.join(format!("{}.json", filename)), &content)?;
+        imported.push(filename.clone());
+    }
+
+    let active = if bundle.profiles.contains_key(&bundle.active_profile) {
+        bundle.active_profile.as_str()
+    } else {
+        imported[0].as_str()
+    };
+    fs::write(dir.join("_active.txt"), active).map_err(|e| e.to_string())?;
+    if let Some(config) = bundle.profiles.get(active) {
+        apply_active_config(config);
+    }
+
+    // Goes through the regular notes save so the previous notes land in a backup
+    if let Some(bundle_notes) = &bundle.notes {
+        notes::replace_all_notes(&app, bundle_notes)?;
+    }
+
+    Ok(imported)
+}
+
 /// Save weather configuration
 #[tauri::command]
 pub fn save_weather_config(weather: WeatherConfig) -> Result<(), String> {
@@ -455,7 +1017,7 @@ pub fn save_weather_config(weather: WeatherConfig) -> Result<(), String> {
 
     fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
     let content = serde_json::to_string_pretty(&config).map_err(|e| e.to_string())?;
-    fs::write(&path, content).map_err(|e| e.to_string())?;
+    write_profile_file(&path, &content)?;
 
     Ok(())
 }
@@ -467,6 +1029,276 @@ pub fn get_weather_config() -> Result<WeatherConfig, String> {
     Ok(config.weather)
 }
 
+/// Thresholds of the active profile, cached so the snapshot poll doesn't read the profile
+static ACTIVE_THRESHOLDS: Mutex<Option<ThresholdsConfig>> = Mutex::new(None);
+
+/// Push the active profile's settings to the services that keep their own copy
+/// (data cap for the network usage tracker, metric thresholds for the snapshot poll)
+pub(crate) fn apply_active_config(config: &AppConfig) {
+    crate::services::network_usage::set_data_cap(
+        config.network.monthly_data_cap_gb,
+        &config.network.data_cap_warning_percents,
+    );
+    if let Ok(mut thresholds) = ACTIVE_THRESHOLDS.lock() {
+        *thresholds = Some(config.thresholds.clone());
+    }
+}
+
+/// Metric thresholds of the active profile (loaded from disk only until the cache is set)
+pub(crate) fn active_thresholds() -> ThresholdsConfig {
+    if let Some(thresholds) = ACTIVE_THRESHOLDS.lock().ok().and_then(|t| t.clone()) {
+        return thresholds;
+    }
+    match get_active_profile() {
+        Ok(config) => {
+            apply_active_config(&config);
+            config.thresholds
+        }
+        Err(_) => ThresholdsConfig::default(),
+    }
+}
+
+/// Save network configuration
+#[tauri::command]
+pub fn save_network_config(network: NetworkConfig) -> Result<(), String> {
+    let mut config = get_active_profile()?;
+    config.network = network;
+    config.modified_at = chrono::Utc::now().to_rfc3339();
+    apply_active_config(&config);
+
+    let path = get_profiles_dir().join(format!("{}.json", get_active_profile_name()));
+    let content = serde_json::to_string_pretty(&config).map_err(|e| e.to_string())?;
+    write_profile_file(&path, &content)?;
+
+    Ok(())
+}
+
+/// Get network configuration
+#[tauri::command]
+pub fn get_network_config() -> Result<NetworkConfig, String> {
+    let config = get_active_profile()?;
+    Ok(config.network)
+}
+
+/// Get a widget's per-instance settings (`null` when none were saved)
+#[tauri::command]
+pub fn get_widget_settings(id: String) -> Result<serde_json::Value, String> {
+    let config = get_active_profile()?;
+    config
+        .widgets
+        .into_iter()
+        .find(|w| w.id == id)
+        .map(|w| w.settings)
+        .ok_or_else(|| format!("Widget '{id}' not found"))
+}
+
+/// Replace a widget's per-instance settings (an object, or `null` to clear)
+#[tauri::command]
+pub fn set_widget_settings(id: String, settings: serde_json::Value) -> Result<(), String> {
+    if !settings.is_object() && !settings.is_null() {
+        return Err("Widget settings must be a JSON object".to_string());
+    }
+
+    let mut config = get_active_profile()?;
+    let widget = config
+        .widgets
+        .iter_mut()
+        .find(|w| w.id == id)
+        .ok_or_else(|| format!("Widget '{id}' not found"))?;
+    widget.settings = settings;
+
+    save_current_profile(config)
+}
+
+/// Payload of the `widget-toggled` event
+#[derive(Serialize, Clone, Debug)]
+pub struct WidgetToggled {
+    pub id: String,
+    pub enabled: bool,
+}
+
+/// Check whether a widget is enabled in the active profile
+#[tauri::command]
+pub fn is_widget_enabled(id: String) -> Result<bool, String> {
+    let config = get_active_profile()?;
+    config
+        .widgets
+        .iter()
+        .find(|w| w.id == id)
+        .map(|w| w.enabled)
+        .ok_or_else(|| format!("Widget '{id}' not found"))
+}
+
+/// Enable/disable a single widget in the active profile.
+///
+/// Emits `widget-toggled` and `widgets-updated` (full list) so the bar re-renders.
+#[tauri::command]
+pub fn set_widget_enabled(app: AppHandle, id: String, enabled: bool) -> Result<(), String> {
+    let mut config = get_active_profile()?;
+    let widget = config
+        .widgets
+        .iter_mut()
+        .find(|w| w.id == id)
+        .ok_or_else(|| format!("Widget '{id}' not found"))?;
+
+    if widget.enabled == enabled {
+        return Ok(());
+    }
+    widget.enabled = enabled;
+
+    let widgets = config.widgets.clone();
+    save_current_profile(config)?;
+    let _ = app.emit("widget-toggled", WidgetToggled { id, enabled });
+    let _ = app.emit("widgets-updated", widgets);
+    Ok(())
+}
+
+/// Widget types the frontend knows how to render
+const WIDGET_TYPES: &[&str] = &[
+    "cpu",
+    "ram",
+    "gpu",
+    "storage",
+    "network",
+    "media",
+    "taskswitcher",
+    "notes",
+    "audio",
+    "headset",
+    "weather",
+    "clock",
+];
+
+/// Add a new instance of `widget_type` (id `<type>-<n>`) at the end (emits `widgets-updated`)
+#[tauri::command]
+pub fn add_widget(app: AppHandle, widget_type: String) -> Result<WidgetConfig, String> {
+    if !WIDGET_TYPES.contains(&widget_type.as_str()) {
+        return Err(format!("Unknown widget type '{widget_type}'"));
+    }
+
+    let mut config = get_active_profile()?;
+
+    let prefix = format!("{widget_type}-");
+    let next_index = config
+        .widgets
+        .iter()
+        .filter_map(|w| w.id.strip_prefix(&prefix)?.parse::<u32>().ok())
+        .max()
+        .unwrap_or(0)
+        + 1;
+    let next_order = config
+        .widgets
+        .iter()
+        .map(|w| w.order + 1)
+        .max()
+        .unwrap_or(0);
+
+    let widget = WidgetConfig {
+        id: format!("{prefix}{next_index}"),
+        widget_type,
+        enabled: true,
+        order: next_order,
+        settings: serde_json::Value::Null,
+    };
+    config.widgets.push(widget.clone());
+
+    let widgets = config.widgets.clone();
+    save_current_profile(config)?;
+    let _ = app.emit("widgets-updated", widgets);
+    Ok(widget)
+}
+
+/// Remove a widget instance (and its color override) from the active profile (emits `widgets-updated`)
+#[tauri::command]
+pub fn remove_widget(app: AppHandle, id: String) -> Result<(), String> {
+    let mut config = get_active_profile()?;
+
+    let before = config.widgets.len();
+    config.widgets.retain(|w| w.id != id);
+    if config.widgets.len() == before {
+        return Err(format!("Widget '{id}' not found"));
+    }
+    config.widget_colors.remove(&id);
+
+    let widgets = config.widgets.clone();
+    save_current_profile(config)?;
+    let _ = app.emit("widgets-updated", widgets);
+    Ok(())
+}
+
+/// Reassign widget `order` sequentially following `ids_in_order` (emits `widgets-updated`).
+/// Every widget of the profile must be listed exactly once.
+#[tauri::command]
+pub fn reorder_widgets(app: AppHandle, ids_in_order: Vec<String>) -> Result<(), String> {
+    let mut config = get_active_profile()?;
+
+    if ids_in_order.len() != config.widgets.len() {
+        return Err(format!(
+            "Expected {} widget ids, got {}",
+            config.widgets.len(),
+            ids_in_order.len()
+        ));
+    }
+    for (i, id) in ids_in_order.iter().enumerate() {
+        if !config.widgets.iter().any(|w| &w.id == id) {
+            return Err(format!("Widget '{id}' not found"));
+        }
+        if ids_in_order[..i].contains(id) {
+            return Err(format!("Widget '{id}' listed more than once"));
+        }
+    }
+
+    config
+        .widgets
+        .sort_by_key(|w| ids_in_order.iter().position(|id| id == &w.id));
+    for (i, widget) in config.widgets.iter_mut().enumerate() {
+        widget.order = i as u32;
+    }
+
+    let widgets = config.widgets.clone();
+    save_current_profile(config)?;
+    let _ = app.emit("widgets-updated", widgets);
+    Ok(())
+}
+
+/// Accepts #RGB, #RRGGBB and #RRGGBBAA
+fn is_valid_hex_color(value: &str) -> bool {
+    match value.strip_prefix('#') {
+        Some(hex) => matches!(hex.len(), 3 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit()),
+        None => false,
+    }
+}
+
+/// Get the per-widget accent colors of the active profile
+#[tauri::command]
+pub fn get_widget_colors() -> Result<HashMap<String, String>, String> {
+    let config = get_active_profile()?;
+    Ok(config.widget_colors)
+}
+
+/// Set a widget's accent color. An empty `hex` resets the widget to its default color.
+#[tauri::command]
+pub fn set_widget_color(id: String, hex: String) -> Result<(), String> {
+    let hex = hex.trim().to_lowercase();
+    let mut config = get_active_profile()?;
+
+    if hex.is_empty() {
+        config.widget_colors.remove(&id);
+    } else {
+        if !is_valid_hex_color(&hex) {
+            return Err(format!(
+                "Invalid color '{hex}' (expected #RGB, #RRGGBB or #RRGGBBAA)"
+            ));
+        }
+        if !config.widgets.iter().any(|w| w.id == id) {
+            return Err(format!("Widget '{id}' not found"));
+        }
+        config.widget_colors.insert(id, hex);
+    }
+
+    save_current_profile(config)
+}
+
 /// Factory reset: wipe profiles + app cache and recreate Default profile.
 /// This is intended to recover from corrupted/stale config state.
 #[tauri::command]
@@ -507,3 +1339,199 @@ pub fn factory_reset(app: AppHandle) -> Result<(), String> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sections(names: &[&str]) -> Vec<String> {
+        names.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn widget(id: &str, widget_type: &str, order: u32) -> WidgetConfig {
+        WidgetConfig {
+            id: id.to_string(),
+            widget_type: widget_type.to_string(),
+            enabled: true,
+            order,
+            settings: serde_json::Value::Null,
+        }
+    }
+
+    fn shortcut(id: &str, path: &str) -> FolderShortcut {
+        FolderShortcut {
+            id: id.to_string(),
+            name: id.to_string(),
+            path: path.to_string(),
+            icon: "folder".to_string(),
+            enabled: true,
+            open_with: None,
+        }
+    }
+
+    #[test]
+    fn merged_sections_take_the_source_values() {
+        let mut target = AppConfig::default_with_name("target");
+        let mut source = AppConfig::default_with_name("source");
+        source.display.bar_height = 48;
+        source.display.theme = "light".to_string();
+        source.polling.interval_ms = 250;
+        source.weather.city_name = "Lisboa".to_string();
+
+        merge_profile_sections(
+            &mut target,
+            &source,
+            &sections(&["display", "polling", "weather"]),
+        )
+        .unwrap();
+
+        assert_eq!(target.display.bar_height, 48);
+        assert_eq!(target.display.theme, "light");
+        assert_eq!(target.polling.interval_ms, 250);
+        assert_eq!(target.weather.city_name, "Lisboa");
+    }
+
+    #[test]
+    fn unselected_sections_keep_the_target_values() {
+        let mut target = AppConfig::default_with_name("target");
+        target.polling.interval_ms = 3000;
+        target.volume_step = 7;
+        let mut source = AppConfig::default_with_name("source");
+        source.display.bar_height = 48;
+        source.polling.interval_ms = 250;
+        source.volume_step = 1;
+
+        merge_profile_sections(&mut target, &source, &sections(&["display"])).unwrap();
+
+        assert_eq!(target.display.bar_height, 48);
+        assert_eq!(target.polling.interval_ms, 3000);
+        assert_eq!(target.volume_step, 7);
+        assert_eq!(target.profile_name, "target");
+    }
+
+    #[test]
+    fn widget_list_and_colors_are_replaced_not_appended() {
+        let mut target = AppConfig::default_with_name("target");
+        target.widgets = vec![widget("cpu-1", "cpu", 0), widget("ram-1", "ram", 1)];
+        target
+            .widget_colors
+            .insert("ram".to_string(), "#ff0000".to_string());
+        let mut source = AppConfig::default_with_name("source");
+        source.widgets = vec![widget("clock-1", "clock", 0)];
+        source
+            .widget_colors
+            .insert("clock".to_string(), "#00ff00".to_string());
+
+        merge_profile_sections(&mut target, &source, &sections(&["widgets"])).unwrap();
+
+        let ids: Vec<&str> = target.widgets.iter().map(|w| w.id.as_str()).collect();
+        assert_eq!(ids, ["clock-1"]);
+        assert_eq!(target.widget_colors.len(), 1);
+        assert_eq!(target.widget_colors["clock"], "#00ff00");
+    }
+
+    #[test]
+    fn folder_shortcuts_are_replaced_not_appended() {
+        let mut target = AppConfig::default_with_name("target");
+        target.folder_shortcuts.shortcuts = vec![shortcut("a", "C:\\a"), shortcut("b", "C:\\b")];
+        let mut source = AppConfig::default_with_name("source");
+        source.folder_shortcuts.shortcuts = vec![shortcut("c", "C:\\c")];
+
+        merge_profile_sections(&mut target, &source, &sections(&["folders"])).unwrap();
+
+        let ids: Vec<&str> = target
+            .folder_shortcuts
+            .shortcuts
+            .iter()
+            .map(|s| s.id.as_str())
+            .collect();
+        assert_eq!(ids, ["c"]);
+    }
+
+    #[test]
+    fn empty_source_lists_clear_the_target() {
+        let mut target = AppConfig::default_with_name("target");
+        let mut source = AppConfig::default_with_name("source");
+        source.widgets.clear();
+        source.folder_shortcuts.shortcuts.clear();
+
+        merge_profile_sections(&mut target, &source, &sections(&["widgets", "folders"])).unwrap();
+
+        assert!(target.widgets.is_empty());
+        assert!(target.folder_shortcuts.shortcuts.is_empty());
+    }
+
+    #[test]
+    fn sections_missing_from_the_source_file_merge_as_defaults() {
+        // An older profile written before weather/folders existed.
+        let source: AppConfig = parse_json_with_path(
+            r#"{
+                "profileName": "old",
+                "createdAt": "",
+                "modifiedAt": "",
+                "display": {
+                    "targetMonitor": "primary",
+                    "barHeight": 32,
+                    "theme": "dark",
+                    "opacity": 1.0,
+                    "blur": false
+                },
+                "widgets": [],
+                "polling": { "intervalMs": 1000, "detailedIntervalMs": 5000 }
+            }"#,
+        )
+        .unwrap();
+        let mut target = AppConfig::default_with_name("target");
+        target.weather.city_name = "Porto".to_string();
+        target.weather.enabled = false;
+
+        merge_profile_sections(&mut target, &source, &sections(&["weather", "display"])).unwrap();
+
+        let defaults = WeatherConfig::default();
+        assert_eq!(target.weather.city_name, defaults.city_name);
+        assert_eq!(target.weather.enabled, defaults.enabled);
+        assert!(!target.display.auto_hide);
+    }
+
+    #[test]
+    fn target_fields_missing_from_its_file_get_defaults() {
+        let mut target: AppConfig = parse_json_with_path(
+            r#"{
+                "profileName": "old",
+                "createdAt": "",
+                "modifiedAt": "",
+                "display": {
+                    "targetMonitor": "primary",
+                    "barHeight": 32,
+                    "theme": "dark",
+                    "opacity": 1.0,
+                    "blur": false
+                },
+                "widgets": [],
+                "polling": { "intervalMs": 1000, "detailedIntervalMs": 5000 }
+            }"#,
+        )
+        .unwrap();
+        let mut source = AppConfig::default_with_name("source");
+        source.polling.interval_ms = 500;
+
+        merge_profile_sections(&mut target, &source, &sections(&["polling"])).unwrap();
+
+        assert_eq!(target.polling.interval_ms, 500);
+        assert_eq!(target.volume_step, default_volume_step());
+        assert!(target.prewarm_enabled);
+        assert!(target.quick_launch.items.is_empty());
+        assert_eq!(target.file_manager, None);
+    }
+
+    #[test]
+    fn unknown_section_is_rejected() {
+        let mut target = AppConfig::default_with_name("target");
+        let source = AppConfig::default_with_name("source");
+
+        let err = merge_profile_sections(&mut target, &source, &sections(&["display", "bogus"]))
+            .unwrap_err();
+
+        assert!(err.contains("bogus"));
+    }
+}
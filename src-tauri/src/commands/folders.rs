@@ -1,12 +1,159 @@
 //! Commands for folder shortcuts management
 
 use crate::commands::config::{FolderShortcut, FolderShortcutsConfig};
+use crate::services::paths;
 use crate::FoldersPopupCooldown;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::sync::atomic::Ordering;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tauri::{AppHandle, Manager, State};
 
+/// Error returned by the folder shortcut commands.
+///
+/// `kind` is one of "invalid_path", "path_not_found", "not_a_directory", "duplicate_id",
+/// "not_found", "invalid_open_with" or "config".
+#[derive(Serialize, Debug)]
+pub struct FolderShortcutError {
+    pub kind: &'static str,
+    pub message: String,
+    /// The normalized path the error refers to (if any)
+    pub path: Option<String>,
+}
+
+impl FolderShortcutError {
+    fn new(kind: &'static str, message: impl Into<String>, path: Option<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            path,
+        }
+    }
+}
+
+impl From<String> for FolderShortcutError {
+    fn from(message: String) -> Self {
+        Self::new("config", message, None)
+    }
+}
+
+/// Clean up a folder shortcut path without touching the filesystem.
+///
+/// Expands `%VAR%`, `${VAR}` and a leading `~`, unifies separators, collapses duplicate separators and drops trailing
+/// ones (keeping drive roots like `C:\`). UNC paths (`\\server\share\...`) are kept
+/// as UNC and must include both the server and the share name.
+fn clean_folder_path(raw: &str) -> Result<String, FolderShortcutError> {
+    let trimmed = raw.trim().trim_matches('"').trim();
+    if trimmed.is_empty() {
+        return Err(FolderShortcutError::new(
+            "invalid_path",
+            "Folder path is empty",
+            None,
+        ));
+    }
+
+    let expanded = paths::expand_path(trimmed);
+    let sep = std::path::MAIN_SEPARATOR;
+    let unified: String = expanded
+        .chars()
+        .map(|c| if c == '/' || c == '\\' { sep } else { c })
+        .collect();
+
+    let is_unc = cfg!(windows) && unified.starts_with(&format!("{sep}{sep}"));
+    let parts: Vec<&str> = unified.split(sep).filter(|p| !p.is_empty()).collect();
+
+    let normalized = if is_unc {
+        if parts.len() < 2 {
+            return Err(FolderShortcutError::new(
+                "invalid_path",
+                "Network paths must include a server and a share (\\\\server\\share)",
+                Some(unified),
+            ));
+        }
+        format!("{sep}{sep}{}", parts.join(&sep.to_string()))
+    } else {
+        let mut joined = parts.join(&sep.to_string());
+        if unified.starts_with(sep) {
+            joined.insert(0, sep);
+        }
+        // "C:" alone means "current dir on drive C"; shortcuts always mean the drive root.
+        if joined.len() == 2 && joined.ends_with(':') {
+            joined.push(sep);
+        }
+        joined
+    };
+
+    Ok(normalized)
+}
+
+/// Normalize a folder shortcut path and verify that it points to an existing directory.
+fn normalize_folder_path(raw: &str) -> Result<String, FolderShortcutError> {
+    let normalized = clean_folder_path(raw)?;
+    let path = std::path::Path::new(&normalized);
+    if path.is_dir() {
+        Ok(normalized)
+    } else if path.exists() {
+        Err(FolderShortcutError::new(
+            "not_a_directory",
+            format!("Not a folder: {normalized}"),
+            Some(normalized),
+        ))
+    } else {
+        Err(FolderShortcutError::new(
+            "path_not_found",
+            format!("Folder not found: {normalized}"),
+            Some(normalized),
+        ))
+    }
+}
+
+/// Shortcuts that point at the same folder
+#[derive(Serialize, Debug)]
+pub struct DuplicateShortcutGroup {
+    /// The normalized path shared by the group
+    pub path: String,
+    /// Shortcuts in list order; the first one is kept by `dedupe_folder_shortcuts`
+    pub shortcuts: Vec<FolderShortcut>,
+}
+
+/// Key used to decide whether two shortcuts point at the same folder.
+///
+/// Missing folders still compare by their cleaned-up text; Windows paths are case-insensitive.
+fn duplicate_key(path: &str) -> String {
+    let cleaned = clean_folder_path(path).unwrap_or_else(|_| path.trim().to_string());
+    if cfg!(windows) {
+        cleaned.to_lowercase()
+    } else {
+        cleaned
+    }
+}
+
+/// Group shortcuts sharing the same normalized path; only groups with 2+ entries are kept.
+fn duplicate_groups(shortcuts: &[FolderShortcut]) -> Vec<DuplicateShortcutGroup> {
+    let mut groups: Vec<(String, DuplicateShortcutGroup)> = Vec::new();
+    for shortcut in shortcuts {
+        let key = duplicate_key(&shortcut.path);
+        match groups.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, group)) => group.shortcuts.push(shortcut.clone()),
+            None => groups.push((
+                key,
+                DuplicateShortcutGroup {
+                    path: clean_folder_path(&shortcut.path)
+                        .unwrap_or_else(|_| shortcut.path.clone()),
+                    shortcuts: vec![shortcut.clone()],
+                },
+            )),
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|(_, group)| group)
+        .filter(|group| group.shortcuts.len() > 1)
+        .collect()
+}
+
 /// Get folder shortcuts from active profile
 #[tauri::command]
 pub fn get_folder_shortcuts() -> Result<FolderShortcutsConfig, String> {
@@ -24,7 +171,7 @@ pub fn save_folder_shortcuts(shortcuts: FolderShortcutsConfig) -> Result<(), Str
 
 /// Add a new folder shortcut
 #[tauri::command]
-pub fn add_folder_shortcut(shortcut: FolderShortcut) -> Result<(), String> {
+pub fn add_folder_shortcut(mut shortcut: FolderShortcut) -> Result<(), FolderShortcutError> {
     let mut config = super::config::get_active_profile()?;
 
     // Check for duplicate ID
@@ -34,11 +181,61 @@ pub fn add_folder_shortcut(shortcut: FolderShortcut) -> Result<(), String> {
         .iter()
         .any(|s| s.id == shortcut.id)
     {
-        return Err("Folder shortcut with this ID already exists".to_string());
+        return Err(FolderShortcutError::new(
+            "duplicate_id",
+            "Folder shortcut with this ID already exists",
+            None,
+        ));
     }
 
+    shortcut.path = normalize_folder_path(&shortcut.path)?;
+    normalize_open_with(&mut shortcut)?;
     config.folder_shortcuts.shortcuts.push(shortcut);
-    super::config::save_current_profile(config)
+    Ok(super::config::save_current_profile(config)?)
+}
+
+/// Add a folder shortcut for a folder dropped onto the bar.
+///
+/// The name comes from the folder name and the id is generated; files are rejected.
+#[tauri::command]
+pub fn add_shortcut_from_path(path: String) -> Result<FolderShortcut, FolderShortcutError> {
+    let normalized = normalize_folder_path(&path)?;
+
+    // Drive roots have no file name ("C:\" -> "C:")
+    let name = Path::new(&normalized)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| {
+            normalized
+                .trim_end_matches(std::path::MAIN_SEPARATOR)
+                .to_string()
+        });
+
+    let existing = super::config::get_active_profile()?
+        .folder_shortcuts
+        .shortcuts;
+    let mut stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    let id = loop {
+        let id = format!("folder-{stamp}");
+        if !existing.iter().any(|s| s.id == id) {
+            break id;
+        }
+        stamp += 1;
+    };
+
+    let shortcut = FolderShortcut {
+        id,
+        name,
+        path: normalized,
+        icon: "folder".to_string(),
+        enabled: true,
+        open_with: None,
+    };
+    add_folder_shortcut(shortcut.clone())?;
+    Ok(shortcut)
 }
 
 /// Remove a folder shortcut by ID
@@ -49,31 +246,115 @@ pub fn remove_folder_shortcut(id: String) -> Result<(), String> {
     super::config::save_current_profile(config)
 }
 
+/// Report groups of folder shortcuts that point at the same folder
+#[tauri::command]
+pub fn find_duplicate_shortcuts() -> Result<Vec<DuplicateShortcutGroup>, String> {
+    let config = super::config::get_active_profile()?;
+    Ok(duplicate_groups(&config.folder_shortcuts.shortcuts))
+}
+
+/// Remove duplicate folder shortcuts, keeping the first one of each group.
+///
+/// Returns the shortcuts that were removed.
+#[tauri::command]
+pub fn dedupe_folder_shortcuts() -> Result<Vec<FolderShortcut>, String> {
+    let mut config = super::config::get_active_profile()?;
+
+    let removed: Vec<FolderShortcut> = duplicate_groups(&config.folder_shortcuts.shortcuts)
+        .into_iter()
+        .flat_map(|group| group.shortcuts.into_iter().skip(1))
+        .collect();
+    if removed.is_empty() {
+        return Ok(removed);
+    }
+
+    config
+        .folder_shortcuts
+        .shortcuts
+        .retain(|s| !removed.iter().any(|r| r.id == s.id));
+    super::config::save_current_profile(config)?;
+    Ok(removed)
+}
+
 /// Update a folder shortcut
 #[tauri::command]
-pub fn update_folder_shortcut(shortcut: FolderShortcut) -> Result<(), String> {
+pub fn update_folder_shortcut(mut shortcut: FolderShortcut) -> Result<(), FolderShortcutError> {
     let mut config = super::config::get_active_profile()?;
 
-    if let Some(existing) = config
+    let Some(existing) = config
         .folder_shortcuts
         .shortcuts
         .iter_mut()
         .find(|s| s.id == shortcut.id)
-    {
-        *existing = shortcut;
-        super::config::save_current_profile(config)
+    else {
+        return Err(FolderShortcutError::new(
+            "not_found",
+            "Folder shortcut not found",
+            None,
+        ));
+    };
+
+    shortcut.path = normalize_folder_path(&shortcut.path)?;
+    normalize_open_with(&mut shortcut)?;
+    *existing = shortcut;
+    Ok(super::config::save_current_profile(config)?)
+}
+
+/// Result of `open_folder`
+#[derive(Serialize, Debug)]
+pub struct OpenFolderResult {
+    /// The folder that was actually opened
+    pub opened_path: String,
+    /// Set when the requested folder was missing and a parent was opened instead
+    pub warning: Option<String>,
+}
+
+/// File manager configured in the active profile, if it is set and still exists.
+///
+/// A missing executable is logged and ignored so folders still open in Explorer.
+fn configured_file_manager() -> Option<String> {
+    let configured = super::config::get_active_profile().ok()?.file_manager?;
+    let configured = configured.trim().trim_matches('"').trim();
+    if configured.is_empty() {
+        return None;
+    }
+
+    let expanded = paths::expand_path(configured);
+    if Path::new(&expanded).is_file() {
+        Some(expanded)
     } else {
-        Err("Folder shortcut not found".to_string())
+        eprintln!("File manager not found: {expanded}; falling back to Explorer");
+        None
     }
 }
 
-/// Open a folder in Windows Explorer
+/// Open a folder in Windows Explorer (or the file manager configured in the profile).
+///
+/// If the folder no longer exists, the nearest existing parent is opened instead and the
+/// result carries a warning. A shortcut's `open_with` command (shortcut `id`, or matched by
+/// path) takes precedence; if it's no longer valid the default opener is used with a warning.
 #[tauri::command]
 pub fn open_folder(
     app: AppHandle,
     cooldown: State<'_, FoldersPopupCooldown>,
     path: String,
-) -> Result<(), String> {
+    id: Option<String>,
+) -> Result<OpenFolderResult, String> {
+    let requested = PathBuf::from(paths::expand_path(path.trim()));
+    let target = requested
+        .ancestors()
+        .find(|p| !p.as_os_str().is_empty() && p.is_dir())
+        .map(Path::to_path_buf)
+        .ok_or_else(|| format!("Folder not found: {}", requested.display()))?;
+
+    let warning = (target != requested).then(|| {
+        format!(
+            "Folder not found: {}. Opened {} instead.",
+            requested.display(),
+            target.display()
+        )
+    });
+
     // Prevent the folders menu from immediately reopening due to Windows click-through
     // when the Explorer window steals focus.
     const COOLDOWN_MS: u64 = 1500;
@@ -104,10 +385,52 @@ pub fn open_folder(
         }
     });
 
+    let mut warning = warning;
+    let custom = match shortcut_open_with(id.as_deref(), &requested) {
+        Some(template) => match build_open_with(&template, &target.to_string_lossy()) {
+            Ok(command) => Some(command),
+            Err(e) => {
+                eprintln!("{e}; opening with the default file manager");
+                warning = Some(match warning {
+                    Some(w) => format!("{w} {e}"),
+                    None => e,
+                });
+                None
+            }
+        },
+        None => None,
+    };
+
+    match custom {
+        Some((program, args)) => {
+            Command::new(&program)
+                .args(&args)
+                .spawn()
+                .map_err(|e| format!("Failed to open folder with {}: {}", program.display(), e))?;
+        }
+        None => open_in_file_manager(&target)?,
+    }
+
+    Ok(OpenFolderResult {
+        opened_path: target.to_string_lossy().to_string(),
+        warning,
+    })
+}
+
+/// Open `target` in the configured file manager, or Explorer (xdg-open elsewhere).
+fn open_in_file_manager(target: &Path) -> Result<(), String> {
+    if let Some(file_manager) = configured_file_manager() {
+        Command::new(&file_manager)
+            .arg(target)
+            .spawn()
+            .map_err(|e| format!("Failed to open folder with {}: {}", file_manager, e))?;
+        return Ok(());
+    }
+
     #[cfg(windows)]
     {
         Command::new("explorer")
-            .arg(&path)
+            .arg(target)
             .spawn()
             .map_err(|e| format!("Failed to open folder: {}", e))?;
     }
@@ -115,7 +438,7 @@ pub fn open_folder(
     #[cfg(not(windows))]
     {
         Command::new("xdg-open")
-            .arg(&path)
+            .arg(target)
             .spawn()
             .map_err(|e| format!("Failed to open folder: {}", e))?;
     }
@@ -123,8 +446,197 @@ pub fn open_folder(
     Ok(())
 }
 
+/// `open_with` template of the shortcut being opened: looked up by `id`, or by path when
+/// the caller didn't pass one.
+fn shortcut_open_with(id: Option<&str>, requested: &Path) -> Option<String> {
+    let shortcuts = super::config::get_active_profile()
+        .ok()?
+        .folder_shortcuts
+        .shortcuts;
+    let shortcut = match id {
+        Some(id) => shortcuts.into_iter().find(|s| s.id == id)?,
+        None => {
+            let key = duplicate_key(&requested.to_string_lossy());
+            shortcuts
+                .into_iter()
+                .find(|s| duplicate_key(&s.path) == key)?
+        }
+    };
+    shortcut.open_with.filter(|t| !t.trim().is_empty())
+}
+
+/// Split a command template into program + arguments.
+///
+/// Arguments are separated by whitespace; double quotes group one containing spaces
+/// (`"C:\Program Files\App\app.exe" --new-window {path}`).
+fn split_command_template(template: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut has_token = false;
+
+    for c in template.chars() {
+        match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                has_token = true;
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if has_token {
+                    parts.push(std::mem::take(&mut current));
+                    has_token = false;
+                }
+            }
+            c => {
+                current.push(c);
+                has_token = true;
+            }
+        }
+    }
+    if has_token {
+        parts.push(current);
+    }
+    parts
+}
+
+/// Resolve a template's program: a path (variables expanded) or a bare name found on PATH.
+fn resolve_program(program: &str) -> Option<PathBuf> {
+    let expanded = PathBuf::from(paths::expand_path(program));
+    if expanded.is_absolute() || expanded.components().count() > 1 {
+        return expanded.is_file().then_some(expanded);
+    }
+
+    // Bare names like "code" are usually code.cmd / code.exe on Windows
+    let extensions: Vec<String> = if cfg!(windows) {
+        std::env::var("PATHEXT")
+            .unwrap_or_else(|_| ".COM;.EXE;.BAT;.CMD".to_string())
+            .split(';')
+            .filter(|e| !e.is_empty())
+            .map(str::to_string)
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    std::env::split_paths(&std::env::var_os("PATH")?).find_map(|dir| {
+        let candidate = dir.join(&expanded);
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+        extensions
+            .iter()
+            .map(|ext| dir.join(format!("{}{}", expanded.display(), ext)))
+            .find(|c| c.is_file())
+    })
+}
+
+/// Validate an `open_with` template and build the command for `path`: (program, args)
+fn build_open_with(template: &str, path: &str) -> Result<(PathBuf, Vec<String>), String> {
+    if !template.contains("{path}") {
+        return Err(format!(
+            "Open-with command must contain {{path}}: {}",
+            template.trim()
+        ));
+    }
+
+    let mut parts = split_command_template(template);
+    if parts.is_empty() {
+        return Err("Open-with command is empty".to_string());
+    }
+    let program = parts.remove(0);
+    let program_path =
+        resolve_program(&program).ok_or_else(|| format!("Program not found: {program}"))?;
+
+    let args = parts
+        .into_iter()
+        .map(|arg| arg.replace("{path}", path))
+        .collect();
+    Ok((program_path, args))
+}
+
+/// Trim a shortcut's `open_with` (empty means "none") and check that it is usable.
+fn normalize_open_with(shortcut: &mut FolderShortcut) -> Result<(), FolderShortcutError> {
+    shortcut.open_with = shortcut
+        .open_with
+        .as_deref()
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(str::to_string);
+
+    if let Some(template) = &shortcut.open_with {
+        build_open_with(template, &shortcut.path)
+            .map_err(|e| FolderShortcutError::new("invalid_open_with", e, None))?;
+    }
+    Ok(())
+}
+
 /// Verify if a folder path exists
 #[tauri::command]
 pub fn verify_folder_path(path: String) -> bool {
-    std::path::Path::new(&path).is_dir()
+    std::path::Path::new(&paths::expand_path(path.trim())).is_dir()
+}
+
+/// Result of `get_folder_size`
+#[derive(Serialize, Debug)]
+pub struct FolderSize {
+    pub bytes: u64,
+    pub file_count: u64,
+    /// The walk stopped at the time/entry cap, so the totals are a lower bound
+    pub truncated: bool,
+}
+
+const FOLDER_SIZE_MAX_ENTRIES: u64 = 500_000;
+const FOLDER_SIZE_MAX_DURATION: Duration = Duration::from_secs(5);
+
+/// Sum file sizes under `root`. Unreadable folders are skipped; symlinks and junctions are
+/// not followed (they'd be counted twice or loop).
+fn folder_size(root: &Path) -> FolderSize {
+    let started = std::time::Instant::now();
+    let mut result = FolderSize {
+        bytes: 0,
+        file_count: 0,
+        truncated: false,
+    };
+    let mut entries = 0u64;
+    let mut pending = vec![root.to_path_buf()];
+
+    while let Some(dir) = pending.pop() {
+        let Ok(read_dir) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in read_dir.flatten() {
+            entries += 1;
+            if entries > FOLDER_SIZE_MAX_ENTRIES || started.elapsed() > FOLDER_SIZE_MAX_DURATION {
+                result.truncated = true;
+                return result;
+            }
+
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            if file_type.is_dir() {
+                pending.push(entry.path());
+            } else if file_type.is_file() {
+                if let Ok(metadata) = entry.metadata() {
+                    result.bytes += metadata.len();
+                    result.file_count += 1;
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// Total size of a folder (capped at a few seconds / entries for huge trees)
+#[tauri::command]
+pub async fn get_folder_size(path: String) -> Result<FolderSize, String> {
+    let root = PathBuf::from(paths::expand_path(path.trim()));
+    if !root.is_dir() {
+        return Err(format!("Folder not found: {}", root.display()));
+    }
+
+    tauri::async_runtime::spawn_blocking(move || folder_size(&root))
+        .await
+        .map_err(|e| e.to_string())
 }
@@ -1,9 +1,16 @@
 //! Headset commands for Tauri
 
+#[cfg(feature = "headset")]
 use crate::services::headset::{self, HeadsetData};
+#[cfg(feature = "headset")]
+use crate::services::hid_headset;
 use serde::Serialize;
+#[cfg(all(windows, feature = "headset"))]
 use std::path::PathBuf;
 
+#[cfg(not(feature = "headset"))]
+const HEADSET_DISABLED: &str = "Headset support is disabled in this build";
+
 #[derive(Serialize, Clone, Debug)]
 pub struct IcueSdkStatus {
     /// Whether the SDK DLL is installed and accessible
@@ -19,19 +26,134 @@ pub struct IcueSdkStatus {
 }
 
 /// Get current headset data (battery, status, etc.)
+/// Falls back to known generic HID headsets when iCUE reports no device
+#[cfg(feature = "headset")]
 #[tauri::command]
 pub fn get_headset_data() -> HeadsetData {
-    headset::get_headset_data()
+    let data = headset::get_headset_data();
+    if data.device_id.is_empty() {
+        if let Some(hid) = hid_headset::get_headset_data() {
+            return hid;
+        }
+    }
+    data
+}
+
+#[cfg(not(feature = "headset"))]
+#[tauri::command]
+pub fn get_headset_data() -> Result<(), String> {
+    Err(HEADSET_DISABLED.to_string())
+}
+
+/// Set the headset equalizer preset (1-5)
+#[cfg(feature = "headset")]
+#[tauri::command]
+pub fn set_headset_equalizer(device_id: String, preset: i32) -> Result<(), String> {
+    headset::set_equalizer_preset(&device_id, preset)
+}
+
+#[cfg(not(feature = "headset"))]
+#[tauri::command]
+pub fn set_headset_equalizer(_device_id: String, _preset: i32) -> Result<(), String> {
+    Err(HEADSET_DISABLED.to_string())
+}
+
+/// Enable or disable headset sidetone
+#[cfg(feature = "headset")]
+#[tauri::command]
+pub fn set_headset_sidetone(device_id: String, enabled: bool) -> Result<(), String> {
+    headset::set_sidetone(&device_id, enabled)
+}
+
+#[cfg(not(feature = "headset"))]
+#[tauri::command]
+pub fn set_headset_sidetone(_device_id: String, _enabled: bool) -> Result<(), String> {
+    Err(HEADSET_DISABLED.to_string())
+}
+
+/// Enable or disable headset surround sound (7.1)
+#[cfg(feature = "headset")]
+#[tauri::command]
+pub fn set_headset_surround(device_id: String, enabled: bool) -> Result<(), String> {
+    headset::set_surround_sound(&device_id, enabled)
+}
+
+#[cfg(not(feature = "headset"))]
+#[tauri::command]
+pub fn set_headset_surround(_device_id: String, _enabled: bool) -> Result<(), String> {
+    Err(HEADSET_DISABLED.to_string())
+}
+
+/// Toggle the headset microphone and return whether it is now enabled
+#[cfg(feature = "headset")]
+#[tauri::command]
+pub fn toggle_headset_mic(device_id: String) -> Result<bool, String> {
+    headset::toggle_mic(&device_id)
+}
+
+#[cfg(not(feature = "headset"))]
+#[tauri::command]
+pub fn toggle_headset_mic(_device_id: String) -> Result<bool, String> {
+    Err(HEADSET_DISABLED.to_string())
+}
+
+/// Set all headset LEDs to one color
+#[cfg(feature = "headset")]
+#[tauri::command]
+pub fn set_headset_color(device_id: String, r: u8, g: u8, b: u8) -> Result<(), String> {
+    headset::set_color(&device_id, r, g, b)
+}
+
+#[cfg(not(feature = "headset"))]
+#[tauri::command]
+pub fn set_headset_color(_device_id: String, _r: u8, _g: u8, _b: u8) -> Result<(), String> {
+    Err(HEADSET_DISABLED.to_string())
+}
+
+/// Set the headset lighting effect ("static" or "breathing")
+#[cfg(feature = "headset")]
+#[tauri::command]
+pub fn set_headset_lighting_effect(effect: String) -> Result<(), String> {
+    headset::set_lighting_effect(&effect)
+}
+
+#[cfg(not(feature = "headset"))]
+#[tauri::command]
+pub fn set_headset_lighting_effect(_effect: String) -> Result<(), String> {
+    Err(HEADSET_DISABLED.to_string())
+}
+
+/// Raw info for every device iCUE sees, to help report unsupported models
+#[cfg(feature = "headset")]
+#[tauri::command]
+pub fn debug_headset_devices() -> Result<Vec<headset::CorsairDeviceDebugInfo>, String> {
+    headset::debug_devices()
+}
+
+#[cfg(not(feature = "headset"))]
+#[tauri::command]
+pub fn debug_headset_devices() -> Result<(), String> {
+    Err(HEADSET_DISABLED.to_string())
 }
 
 /// Check if iCUE SDK is installed and available
 #[tauri::command]
 pub fn check_icue_sdk() -> IcueSdkStatus {
-    #[cfg(windows)]
+    #[cfg(not(feature = "headset"))]
+    {
+        IcueSdkStatus {
+            installed: false,
+            sdk_path: None,
+            icue_running: false,
+            error: Some(HEADSET_DISABLED.to_string()),
+            version: None,
+        }
+    }
+    #[cfg(all(windows, feature = "headset"))]
     {
         check_icue_sdk_windows()
     }
-    #[cfg(not(windows))]
+    #[cfg(all(not(windows), feature = "headset"))]
     {
         IcueSdkStatus {
             installed: false,
@@ -43,7 +165,7 @@ pub fn check_icue_sdk() -> IcueSdkStatus {
     }
 }
 
-#[cfg(windows)]
+#[cfg(all(windows, feature = "headset"))]
 fn check_icue_sdk_windows() -> IcueSdkStatus {
     use std::process::Command;
 
@@ -134,17 +256,21 @@ fn check_icue_sdk_windows() -> IcueSdkStatus {
 /// This opens the Corsair download page since the SDK requires iCUE to be installed
 #[tauri::command]
 pub async fn install_icue_sdk() -> Result<String, String> {
-    #[cfg(windows)]
+    #[cfg(not(feature = "headset"))]
+    {
+        Err(HEADSET_DISABLED.to_string())
+    }
+    #[cfg(all(windows, feature = "headset"))]
     {
         install_icue_sdk_windows().await
     }
-    #[cfg(not(windows))]
+    #[cfg(all(not(windows), feature = "headset"))]
     {
         Err("iCUE SDK is only available on Windows".to_string())
     }
 }
 
-#[cfg(windows)]
+#[cfg(all(windows, feature = "headset"))]
 async fn install_icue_sdk_windows() -> Result<String, String> {
     use std::process::Command;
 
@@ -1,6 +1,6 @@
 //! Media commands for Tauri
 
-use crate::services::media::{self, MediaData};
+use crate::services::media::{self, MediaArtwork, MediaData};
 
 /// Get current media data
 #[tauri::command]
@@ -26,8 +26,40 @@ pub fn media_previous() -> Result<(), String> {
     media::previous_track()
 }
 
+/// Send a media key ("playpause", "next", "prev" or "stop") as if pressed on the keyboard.
+/// Fallback for apps that don't respond to the SMTC session controls.
+#[tauri::command]
+pub fn send_media_key(key: String) -> Result<(), String> {
+    media::send_media_key(&key)
+}
+
 /// Seek to specific position in seconds
 #[tauri::command]
 pub fn media_seek(position_seconds: f64) -> Result<(), String> {
     media::seek_to_position(position_seconds)
 }
+
+/// Skip forward/backward relative to the current position (negative = back)
+#[tauri::command]
+pub fn media_skip(offset_seconds: f64) -> Result<(), String> {
+    media::skip_by(offset_seconds)
+}
+
+/// Toggle shuffle on the current media session
+#[tauri::command]
+pub fn media_toggle_shuffle() -> Result<(), String> {
+    media::toggle_shuffle()
+}
+
+/// Set repeat mode: "none", "track" or "list"
+#[tauri::command]
+pub fn media_set_repeat(mode: String) -> Result<(), String> {
+    media::set_repeat(&mode)
+}
+
+/// Get the current track's artwork at full resolution (any source app).
+/// Returns `None` when there's no artwork or it is larger than `max_bytes`.
+#[tauri::command]
+pub fn get_media_artwork(max_bytes: usize) -> Result<Option<MediaArtwork>, String> {
+    media::get_artwork(max_bytes)
+}
@@ -1,12 +1,16 @@
 pub mod audio;
+pub mod clipboard;
 pub mod config;
 pub mod folders;
 pub mod headset;
 pub mod media;
 pub mod monitor;
+pub mod network;
 pub mod notes;
 pub mod popup;
+pub mod quick_launch;
 pub mod startup;
 pub mod system;
+pub mod timer;
 pub mod weather;
 pub mod windows;
@@ -1,14 +1,15 @@
 //! Monitor management Tauri commands
 
-use crate::services::appbar;
+use crate::commands::config;
+use crate::services::{appbar, display, gamma, screenshot, window_effects};
 use crate::TaskbarState;
 use serde::Serialize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
-use tauri::{AppHandle, Manager, PhysicalPosition, PhysicalSize, State, WebviewWindow};
+use tauri::{AppHandle, Emitter, Manager, PhysicalPosition, PhysicalSize, State, WebviewWindow};
 
 fn verbose_logs_enabled() -> bool {
-    std::env::var_os("BAR_VERBOSE_LOGS").is_some()
+    crate::services::logging::verbose_logs_enabled()
 }
 
 #[derive(Serialize, Clone, Debug)]
@@ -29,11 +30,27 @@ pub struct MonitorInfo {
     pub y: i32,
     /// Scale factor (DPI)
     pub scale_factor: f64,
+    /// Current refresh rate in Hz (0 if unknown)
+    pub refresh_hz: u32,
+    /// Whether the display is currently in HDR mode
+    pub hdr_enabled: bool,
 }
 
-fn list_monitors_for(window: &WebviewWindow) -> Vec<MonitorInfo> {
+/// Stable id: based on monitor position + size (enumeration order can differ between windows)
+fn stable_monitor_id(m: &tauri::Monitor) -> String {
+    format!(
+        "{}:{}:{}:{}",
+        m.position().x,
+        m.position().y,
+        m.size().width,
+        m.size().height
+    )
+}
+
+pub(crate) fn list_monitors_for(window: &WebviewWindow) -> Vec<MonitorInfo> {
     let monitors = window.available_monitors().unwrap_or_default();
     let primary = window.primary_monitor().ok().flatten();
+    let hdr_outputs = display::hdr_outputs();
 
     monitors
         .iter()
@@ -48,14 +65,12 @@ fn list_monitors_for(window: &WebviewWindow) -> Vec<MonitorInfo> {
                 .map(|p| p.name() == m.name())
                 .unwrap_or(false);
 
-            // Stable id: based on monitor position + size (enumeration order can differ between windows)
-            let stable_id = format!(
-                "{}:{}:{}:{}",
-                m.position().x,
-                m.position().y,
-                m.size().width,
-                m.size().height
-            );
+            let stable_id = stable_monitor_id(m);
+
+            // On Windows the monitor name is the GDI device name (\\.\DISPLAY1)
+            let device_name = m.name().map(String::as_str).unwrap_or_default();
+            let refresh_hz = display::refresh_rate(device_name).unwrap_or(0);
+            let hdr_enabled = hdr_outputs.contains(device_name);
 
             MonitorInfo {
                 id: stable_id,
@@ -66,6 +81,8 @@ fn list_monitors_for(window: &WebviewWindow) -> Vec<MonitorInfo> {
                 x: m.position().x,
                 y: m.position().y,
                 scale_factor: m.scale_factor(),
+                refresh_hz,
+                hdr_enabled,
             }
         })
         .collect()
@@ -127,30 +144,40 @@ pub fn set_taskbar_monitor(
 
     let height = bar_height.unwrap_or(28);
 
+    // On mixed-DPI setups, take secondary monitors' rect straight from Win32 so the bar
+    // spans exactly the physical width of the target monitor.
+    let (target_x, target_y, target_width) = if target.is_primary {
+        (target.x, target.y, target.width)
+    } else {
+        appbar::get_monitor_size_for_point(target.x, target.y)
+            .map(|(x, y, width, _)| (x, y, width as u32))
+            .unwrap_or((target.x, target.y, target.width))
+    };
+
     if verbose_logs_enabled() {
         eprintln!(
             "Target monitor found: {} at ({}, {}) size {}x{}",
-            target.name, target.x, target.y, target.width, target.height
+            target.name, target_x, target_y, target_width, target.height
         );
     }
 
     // Position the window at the top of the target monitor
     window
-        .set_position(PhysicalPosition::new(target.x, target.y))
+        .set_position(PhysicalPosition::new(target_x, target_y))
         .map_err(|e| e.to_string())?;
 
     // Set the window size to span the full width of the monitor
     window
-        .set_size(PhysicalSize::new(target.width, height))
+        .set_size(PhysicalSize::new(target_width, height))
         .map_err(|e| e.to_string())?;
 
     // Update shared state with new bounds
     if let Ok(mut bounds) = taskbar_state.bounds.lock() {
-        *bounds = Some((target.x, target.y, target.width, height));
+        *bounds = Some((target_x, target_y, target_width, height));
         if verbose_logs_enabled() {
             eprintln!(
                 "Updated taskbar_state.bounds to ({}, {}, {}, {})",
-                target.x, target.y, target.width, height
+                target_x, target_y, target_width, height
             );
         }
     }
@@ -161,15 +188,15 @@ pub fn set_taskbar_monitor(
         if let Ok(hwnd) = window.hwnd() {
             let result = appbar::register_appbar(
                 hwnd.0 as isize,
-                target.x,
-                target.y,
-                target.width as i32,
+                target_x,
+                target_y,
+                target_width as i32,
                 height as i32,
             );
             if verbose_logs_enabled() {
                 eprintln!(
                     "AppBar register result: {:?} - moved to monitor {} at ({}, {}) size {}x{}",
-                    result, monitor_id, target.x, target.y, target.width, height
+                    result, monitor_id, target_x, target_y, target_width, height
                 );
             }
 
@@ -181,6 +208,154 @@ pub fn set_taskbar_monitor(
     Ok(())
 }
 
+/// Change a monitor's resolution and refresh rate.
+///
+/// The mode must be one the driver reports; otherwise the error lists the available
+/// modes. The work area changes with the resolution, so the AppBar is re-registered
+/// afterwards. Monitor ids include the size, so if the bar lives on the changed monitor
+/// its saved `targetMonitor` is moved to the new id.
+#[tauri::command(rename_all = "camelCase")]
+pub fn set_display_resolution(
+    app: AppHandle,
+    taskbar_state: State<'_, Arc<TaskbarState>>,
+    monitor_id: String,
+    width: u32,
+    height: u32,
+    refresh_hz: u32,
+) -> Result<(), String> {
+    let window = app
+        .get_webview_window("main")
+        .ok_or("Main window not found")?;
+
+    let device_name = window
+        .available_monitors()
+        .map_err(|e| e.to_string())?
+        .iter()
+        .find(|m| stable_monitor_id(m) == monitor_id)
+        .and_then(|m| m.name().cloned())
+        .ok_or("Monitor not found")?;
+
+    let requested = display::DisplayMode {
+        width,
+        height,
+        refresh_hz,
+    };
+    let modes = display::list_modes(&device_name);
+    if !modes.contains(&requested) {
+        let available = modes
+            .iter()
+            .map(|m| m.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        return Err(format!(
+            "Display mode {requested} is not supported. Available modes: {available}"
+        ));
+    }
+
+    display::set_mode(&device_name, requested)?;
+
+    let mut profile = config::get_active_profile()?;
+    if profile.display.target_monitor == monitor_id {
+        if let Some(moved) = list_monitors_for(&window)
+            .into_iter()
+            .find(|m| m.name == device_name)
+        {
+            profile.display.target_monitor = moved.id;
+            config::save_current_profile(profile.clone())?;
+        }
+    }
+
+    set_taskbar_monitor(
+        app,
+        taskbar_state,
+        profile.display.target_monitor,
+        Some(profile.display.bar_height),
+    )
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct NativeTaskbarInfo {
+    pub visible: bool,
+    /// (x, y, width, height) in physical pixels
+    pub rect: (i32, i32, i32, i32),
+    /// Whether the visible native taskbar intersects the bar's current bounds
+    pub overlaps_bar: bool,
+}
+
+/// Check whether the native Windows taskbar is visible and whether it collides with the bar
+#[tauri::command]
+pub fn is_native_taskbar_visible(
+    taskbar_state: State<'_, Arc<TaskbarState>>,
+) -> Result<NativeTaskbarInfo, String> {
+    let (visible, rect) = appbar::native_taskbar_state().ok_or("Native taskbar not found")?;
+
+    let (tx, ty, tw, th) = rect;
+    let overlaps_bar = visible
+        && taskbar_state
+            .bounds
+            .lock()
+            .ok()
+            .and_then(|b| *b)
+            .is_some_and(|(x, y, w, h)| {
+                x < tx + tw && tx < x + w as i32 && y < ty + th && ty < y + h as i32
+            });
+
+    Ok(NativeTaskbarInfo {
+        visible,
+        rect,
+        overlaps_bar,
+    })
+}
+
+/// Collapse the bar for auto-hide: the AppBar keeps a 1px reveal strip and the window is hidden.
+#[cfg(windows)]
+pub(crate) fn auto_hide_collapse(window: &WebviewWindow, state: &TaskbarState) {
+    let Some((x, y, width, _)) = state.bounds.lock().ok().and_then(|b| *b) else {
+        return;
+    };
+    if let Ok(hwnd) = window.hwnd() {
+        let _ = appbar::update_appbar_position(hwnd.0 as isize, x, y, width as i32, 1);
+    }
+    let _ = window.hide();
+    state.auto_hidden.store(true, Ordering::SeqCst);
+}
+
+/// Restore the full bar after `auto_hide_collapse`
+pub(crate) fn auto_hide_reveal(window: &WebviewWindow, state: &TaskbarState) {
+    state.auto_hidden.store(false, Ordering::SeqCst);
+    let Some((x, y, width, height)) = state.bounds.lock().ok().and_then(|b| *b) else {
+        return;
+    };
+    #[cfg(windows)]
+    if let Ok(hwnd) = window.hwnd() {
+        let _ = appbar::update_appbar_position(hwnd.0 as isize, x, y, width as i32, height as i32);
+    }
+    let _ = window.set_position(PhysicalPosition::new(x, y));
+    let _ = window.set_size(PhysicalSize::new(width, height));
+    let _ = window.show();
+}
+
+/// Enable or disable auto-hide (the bar slides away when the cursor leaves it
+/// and comes back when the cursor touches the top edge).
+#[tauri::command]
+pub fn set_auto_hide(
+    app: AppHandle,
+    taskbar_state: State<'_, Arc<TaskbarState>>,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut profile = config::get_active_profile()?;
+    profile.display.auto_hide = enabled;
+    config::save_current_profile(profile)?;
+
+    taskbar_state.auto_hide.store(enabled, Ordering::SeqCst);
+    if !enabled && taskbar_state.auto_hidden.load(Ordering::SeqCst) {
+        if let Some(window) = app.get_webview_window("main") {
+            auto_hide_reveal(&window, &taskbar_state);
+        }
+    }
+    Ok(())
+}
+
 /// Unregister the AppBar when closing
 #[tauri::command]
 pub fn unregister_taskbar_appbar(window: tauri::Window) -> Result<(), String> {
@@ -262,3 +437,105 @@ pub fn preview_taskbar_height(
 
     Ok(())
 }
+
+/// Apply opacity/blur to the taskbar immediately and persist them to the active profile.
+///
+/// Opacity is painted by the webview (via the `opacity-preview` event); blur is applied
+/// natively. On Windows versions without blur support the settings are still saved and
+/// an error explains why the blur isn't visible.
+#[tauri::command]
+pub fn apply_window_effects(app: AppHandle, opacity: f32, blur: bool) -> Result<(), String> {
+    if !opacity.is_finite() {
+        return Err("Opacity must be a number between 0 and 1".to_string());
+    }
+    let opacity = opacity.clamp(0.0, 1.0);
+
+    let window = app
+        .get_webview_window("main")
+        .ok_or("Main window not found")?;
+
+    let _ = app.emit("opacity-preview", serde_json::json!({ "opacity": opacity }));
+    let _ = app.emit("blur-preview", serde_json::json!({ "blur": blur }));
+
+    let mut profile = config::get_active_profile()?;
+    profile.display.opacity = opacity;
+    profile.display.blur = blur;
+    config::save_current_profile(profile)?;
+
+    #[cfg(windows)]
+    {
+        let hwnd = window.hwnd().map_err(|e| e.to_string())?;
+        window_effects::set_blur(hwnd.0 as isize, blur)
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = window;
+        window_effects::set_blur(0, blur)
+    }
+}
+
+/// Capture a monitor by id, or the monitor the window is on when no id is given.
+fn capture_monitor_area(
+    window: &WebviewWindow,
+    monitor_id: Option<&str>,
+) -> Result<screenshot::Capture, String> {
+    let (x, y, width, height) = match monitor_id {
+        Some(id) => {
+            let monitor = list_monitors_for(window)
+                .into_iter()
+                .find(|m| m.id == id)
+                .ok_or("Monitor not found")?;
+            (monitor.x, monitor.y, monitor.width, monitor.height)
+        }
+        None => {
+            let monitor = window
+                .current_monitor()
+                .map_err(|e| e.to_string())?
+                .ok_or("Monitor not found")?;
+            (
+                monitor.position().x,
+                monitor.position().y,
+                monitor.size().width,
+                monitor.size().height,
+            )
+        }
+    };
+
+    screenshot::capture_rect(x, y, width, height)
+}
+
+/// Take a screenshot of a monitor (id from `list_monitors`) as a base64 PNG data URL
+#[tauri::command(rename_all = "camelCase")]
+pub fn capture_monitor(window: WebviewWindow, monitor_id: String) -> Result<String, String> {
+    let capture = capture_monitor_area(&window, Some(&monitor_id))?;
+    screenshot::to_png_data_url(&capture)
+}
+
+/// Copy a screenshot of a monitor to the clipboard (defaults to the monitor the bar is on)
+#[tauri::command(rename_all = "camelCase")]
+pub fn capture_to_clipboard(app: AppHandle, monitor_id: Option<String>) -> Result<(), String> {
+    use tauri_plugin_clipboard_manager::ClipboardExt;
+
+    let window = app
+        .get_webview_window("main")
+        .ok_or("Main window not found")?;
+    let capture = capture_monitor_area(&window, monitor_id.as_deref())?;
+
+    let image = tauri::image::Image::new(&capture.rgba, capture.width, capture.height);
+    app.clipboard()
+        .write_image(&image)
+        .map_err(|e| format!("Failed to copy screenshot: {e}"))
+}
+
+/// Warm the display via the gamma ramp (kelvin clamped to 1000-6500); returns the applied value
+#[tauri::command]
+pub fn set_color_temperature(kelvin: u32) -> Result<u32, String> {
+    gamma::set_color_temperature(kelvin)
+}
+
+/// Restore the neutral (identity) gamma ramp
+#[tauri::command]
+pub fn reset_color_temperature() -> Result<(), String> {
+    gamma::reset_color_temperature()
+}
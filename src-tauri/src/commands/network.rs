@@ -0,0 +1,518 @@
+//! Network tools commands (adapter profiles, metered connections)
+
+use crate::commands::config::NetworkAdapterProfile;
+use crate::services::{network, public_ip, wifi};
+use serde::Serialize;
+use std::net::Ipv4Addr;
+
+#[cfg(windows)]
+use std::process::Command;
+
+fn parse_ipv4(value: &str, field: &str) -> Result<Ipv4Addr, String> {
+    value
+        .trim()
+        .parse::<Ipv4Addr>()
+        .map_err(|_| format!("Invalid {field}: '{value}' is not a valid IPv4 address"))
+}
+
+/// Validate a profile before it is stored or applied
+fn validate_profile(profile: &NetworkAdapterProfile) -> Result<(), String> {
+    if profile.name.trim().is_empty() {
+        return Err("Profile name cannot be empty".to_string());
+    }
+
+    let adapter = profile.adapter.trim();
+    if adapter.is_empty() {
+        return Err("Adapter name cannot be empty".to_string());
+    }
+    if adapter.chars().any(|c| c == '"' || c.is_control()) {
+        return Err(format!("Invalid adapter name: '{adapter}'"));
+    }
+
+    for dns in &profile.dns_servers {
+        parse_ipv4(dns, "DNS server")?;
+    }
+
+    if profile.dhcp {
+        return Ok(());
+    }
+
+    let ip_address = profile
+        .ip_address
+        .as_deref()
+        .ok_or_else(|| "A static profile requires an IP address".to_string())?;
+    let ip = parse_ipv4(ip_address, "IP address")?;
+
+    let subnet_mask = profile
+        .subnet_mask
+        .as_deref()
+        .ok_or_else(|| "A static profile requires a subnet mask".to_string())?;
+    let mask = u32::from(parse_ipv4(subnet_mask, "subnet mask")?);
+
+    // A valid mask is a contiguous run of 1 bits followed by 0 bits.
+    if mask == 0 || mask.leading_ones() + mask.trailing_zeros() != 32 {
+        return Err(format!("Invalid subnet mask: '{subnet_mask}'"));
+    }
+
+    let ip_bits = u32::from(ip);
+    if ip.is_unspecified() || ip.is_loopback() || ip.is_multicast() || ip.is_broadcast() {
+        return Err(format!("Invalid IP address: '{ip_address}'"));
+    }
+    // Host part must not be all zeros (network) or all ones (broadcast) unless /31 or /32.
+    if mask.leading_ones() < 31 && (ip_bits & !mask == 0 || ip_bits & !mask == !mask) {
+        return Err(format!(
+            "IP address '{ip_address}' is the network or broadcast address of its subnet"
+        ));
+    }
+
+    if let Some(gateway) = profile.gateway.as_deref().filter(|g| !g.trim().is_empty()) {
+        let gateway_bits = u32::from(parse_ipv4(gateway, "gateway")?);
+        if gateway_bits & mask != ip_bits & mask {
+            return Err(format!(
+                "Gateway '{gateway}' is not in the same subnet as '{ip_address}'"
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Run a console tool to completion and return its trimmed stdout; non-zero exit codes
+/// become errors (netsh and ipconfig write their errors to stdout)
+#[cfg(windows)]
+pub(crate) fn run_console<S: AsRef<std::ffi::OsStr>>(
+    program: &str,
+    args: &[S],
+) -> Result<String, String> {
+    let tool = program.trim_end_matches(".exe");
+    let output = Command::new(program)
+        .args(args)
+        .output()
+        .map_err(|e| format!("Failed to run {tool}: {e}"))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if output.status.success() {
+        return Ok(stdout);
+    }
+
+    let message = if stdout.is_empty() {
+        String::from_utf8_lossy(&output.stderr).trim().to_string()
+    } else {
+        stdout
+    };
+    Err(format!("{tool} failed: {message}"))
+}
+
+#[cfg(windows)]
+fn run_netsh(args: &[String]) -> Result<(), String> {
+    run_console("netsh.exe", args).map(|_| ())
+}
+
+/// Get the network adapter profiles stored in the active profile
+#[tauri::command]
+pub fn get_network_profiles() -> Result<Vec<NetworkAdapterProfile>, String> {
+    let config = super::config::get_active_profile()?;
+    Ok(config.network.adapter_profiles)
+}
+
+/// Add or replace (by name) a network adapter profile
+#[tauri::command]
+pub fn save_network_profile(profile: NetworkAdapterProfile) -> Result<(), String> {
+    validate_profile(&profile)?;
+
+    let mut config = super::config::get_active_profile()?;
+    let profiles = &mut config.network.adapter_profiles;
+
+    match profiles.iter_mut().find(|p| p.name == profile.name) {
+        Some(existing) => *existing = profile,
+        None => profiles.push(profile),
+    }
+
+    super::config::save_current_profile(config)
+}
+
+/// Apply a network adapter profile via `netsh` (requires administrator privileges)
+#[tauri::command]
+pub fn apply_network_profile(profile: NetworkAdapterProfile) -> Result<(), String> {
+    validate_profile(&profile)?;
+
+    #[cfg(windows)]
+    {
+        if !super::startup::is_running_as_admin() {
+            return Err(
+                "Applying a network profile requires administrator privileges. Restart the app as administrator."
+                    .to_string(),
+            );
+        }
+
+        let name_arg = format!("name={}", profile.adapter.trim());
+
+        if profile.dhcp {
+            run_netsh(&[
+                "interface".into(),
+                "ipv4".into(),
+                "set".into(),
+                "address".into(),
+                name_arg.clone(),
+                "source=dhcp".into(),
+            ])?;
+        } else {
+            let mut args: Vec<String> = vec![
+                "interface".into(),
+                "ipv4".into(),
+                "set".into(),
+                "address".into(),
+                name_arg.clone(),
+                "source=static".into(),
+                format!(
+                    "address={}",
+                    profile.ip_address.as_deref().unwrap_or_default().trim()
+                ),
+                format!(
+                    "mask={}",
+                    profile.subnet_mask.as_deref().unwrap_or_default().trim()
+                ),
+            ];
+            if let Some(gateway) = profile.gateway.as_deref().filter(|g| !g.trim().is_empty()) {
+                args.push(format!("gateway={}", gateway.trim()));
+            }
+            run_netsh(&args)?;
+        }
+
+        // DNS: explicit servers win; otherwise DHCP profiles go back to automatic DNS.
+        match profile.dns_servers.split_first() {
+            Some((primary, rest)) => {
+                run_netsh(&[
+                    "interface".into(),
+                    "ipv4".into(),
+                    "set".into(),
+                    "dnsservers".into(),
+                    name_arg.clone(),
+                    "source=static".into(),
+                    format!("address={}", primary.trim()),
+                    "register=primary".into(),
+                    "validate=no".into(),
+                ])?;
+                for (i, dns) in rest.iter().enumerate() {
+                    run_netsh(&[
+                        "interface".into(),
+                        "ipv4".into(),
+                        "add".into(),
+                        "dnsservers".into(),
+                        name_arg.clone(),
+                        format!("address={}", dns.trim()),
+                        format!("index={}", i + 2),
+                        "validate=no".into(),
+                    ])?;
+                }
+            }
+            None => {
+                let mut args: Vec<String> = vec![
+                    "interface".into(),
+                    "ipv4".into(),
+                    "set".into(),
+                    "dnsservers".into(),
+                    name_arg,
+                ];
+                if profile.dhcp {
+                    args.push("source=dhcp".into());
+                } else {
+                    args.push("source=static".into());
+                    args.push("address=none".into());
+                }
+                run_netsh(&args)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(not(windows))]
+    {
+        Err("apply_network_profile is only supported on Windows".into())
+    }
+}
+
+/// Disable and re-enable a network adapter via `netsh`. Requires administrator privileges.
+#[tauri::command]
+pub async fn reset_network_adapter(name: String) -> Result<String, String> {
+    let name = name.trim().to_string();
+    if name.is_empty() || name.contains('"') {
+        return Err("Invalid adapter name".to_string());
+    }
+
+    #[cfg(windows)]
+    {
+        if !super::startup::is_running_as_admin() {
+            return Err(
+                "Resetting a network adapter requires administrator privileges. Restart the app as administrator."
+                    .to_string(),
+            );
+        }
+
+        tauri::async_runtime::spawn_blocking(move || {
+            let name_arg = format!("name={name}");
+            let mut output = run_console(
+                "netsh.exe",
+                &["interface", "set", "interface", &name_arg, "admin=disabled"],
+            )?;
+
+            // Give the driver a moment to tear down before bringing it back
+            std::thread::sleep(std::time::Duration::from_secs(2));
+
+            let enabled = run_console(
+                "netsh.exe",
+                &["interface", "set", "interface", &name_arg, "admin=enabled"],
+            )?;
+            if !enabled.is_empty() {
+                if !output.is_empty() {
+                    output.push('\n');
+                }
+                output.push_str(&enabled);
+            }
+            Ok(output)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+    }
+
+    #[cfg(not(windows))]
+    {
+        Err("reset_network_adapter is only supported on Windows".into())
+    }
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct MeteredState {
+    /// Connection profile name (SSID for Wi-Fi, network name for Ethernet)
+    pub interface: String,
+    pub metered: bool,
+    /// "unknown" | "unrestricted" | "fixed" | "variable"
+    pub cost_type: String,
+    pub is_wlan: bool,
+    /// Whether `set_metered_connection` can change the setting from this process
+    pub writable: bool,
+    /// Why the setting is read-only, when it is
+    pub read_only_reason: Option<String>,
+}
+
+#[cfg(windows)]
+const DEFAULT_MEDIA_COST_KEY: &str =
+    r"SOFTWARE\Microsoft\Windows NT\CurrentVersion\NetworkList\DefaultMediaCost";
+
+/// Find a connection profile by name, or the current internet profile when `interface` is empty
+#[cfg(windows)]
+fn find_connection_profile(
+    interface: Option<&str>,
+) -> Result<windows::Networking::Connectivity::ConnectionProfile, String> {
+    use windows::Networking::Connectivity::NetworkInformation;
+
+    match interface.map(str::trim).filter(|i| !i.is_empty()) {
+        None => NetworkInformation::GetInternetConnectionProfile()
+            .map_err(|_| "No active internet connection".to_string()),
+        Some(name) => {
+            let profiles =
+                NetworkInformation::GetConnectionProfiles().map_err(|e| e.to_string())?;
+            profiles
+                .into_iter()
+                .find(|p| {
+                    p.ProfileName()
+                        .map(|n| n.to_string().eq_ignore_ascii_case(name))
+                        .unwrap_or(false)
+                })
+                .ok_or_else(|| format!("Connection profile '{name}' not found"))
+        }
+    }
+}
+
+/// Check whether the Ethernet default media cost can be written by this process.
+/// The key is owned by TrustedInstaller on stock installs, so this is usually false.
+#[cfg(windows)]
+fn can_write_default_media_cost() -> bool {
+    use windows::core::HSTRING;
+    use windows::Win32::System::Registry::{
+        RegCloseKey, RegOpenKeyExW, HKEY, HKEY_LOCAL_MACHINE, KEY_SET_VALUE,
+    };
+
+    unsafe {
+        let mut hkey = HKEY::default();
+        let status = RegOpenKeyExW(
+            HKEY_LOCAL_MACHINE,
+            &HSTRING::from(DEFAULT_MEDIA_COST_KEY),
+            0,
+            KEY_SET_VALUE,
+            &mut hkey,
+        );
+        if status.is_err() {
+            return false;
+        }
+        let _ = RegCloseKey(hkey);
+        true
+    }
+}
+
+#[cfg(windows)]
+fn write_default_media_cost(metered: bool) -> Result<(), String> {
+    use windows::core::HSTRING;
+    use windows::Win32::Foundation::ERROR_ACCESS_DENIED;
+    use windows::Win32::System::Registry::{
+        RegCloseKey, RegOpenKeyExW, RegSetValueExW, HKEY, HKEY_LOCAL_MACHINE, KEY_SET_VALUE,
+        REG_DWORD,
+    };
+
+    // 1 = unrestricted, 2 = fixed (metered)
+    let value: u32 = if metered { 2 } else { 1 };
+
+    unsafe {
+        let mut hkey = HKEY::default();
+        let status = RegOpenKeyExW(
+            HKEY_LOCAL_MACHINE,
+            &HSTRING::from(DEFAULT_MEDIA_COST_KEY),
+            0,
+            KEY_SET_VALUE,
+            &mut hkey,
+        );
+        if status == ERROR_ACCESS_DENIED {
+            return Err(
+                "The Ethernet metered setting is read-only (DefaultMediaCost is owned by TrustedInstaller)"
+                    .to_string(),
+            );
+        }
+        if status.is_err() {
+            return Err(format!("Failed to open DefaultMediaCost: {status:?}"));
+        }
+
+        let status = RegSetValueExW(
+            hkey,
+            &HSTRING::from("Ethernet"),
+            0,
+            REG_DWORD,
+            Some(&value.to_le_bytes()),
+        );
+        let _ = RegCloseKey(hkey);
+
+        if status.is_err() {
+            return Err(format!("Failed to write DefaultMediaCost: {status:?}"));
+        }
+    }
+
+    Ok(())
+}
+
+/// Get the metered state of a connection (empty/missing `interface` = current internet connection)
+#[tauri::command]
+pub fn get_metered_state(interface: Option<String>) -> Result<MeteredState, String> {
+    #[cfg(windows)]
+    {
+        use windows::Networking::Connectivity::NetworkCostType;
+
+        let profile = find_connection_profile(interface.as_deref())?;
+        let name = profile
+            .ProfileName()
+            .map(|n| n.to_string())
+            .unwrap_or_default();
+        let is_wlan = profile.IsWlanConnectionProfile().unwrap_or(false);
+        let cost = profile
+            .GetConnectionCost()
+            .and_then(|c| c.NetworkCostType())
+            .unwrap_or(NetworkCostType::Unknown);
+
+        let cost_type = match cost {
+            NetworkCostType::Unrestricted => "unrestricted",
+            NetworkCostType::Fixed => "fixed",
+            NetworkCostType::Variable => "variable",
+            _ => "unknown",
+        };
+
+        let read_only_reason = if is_wlan {
+            (!super::startup::is_running_as_admin()).then(|| {
+                "Changing a Wi-Fi profile cost requires administrator privileges".to_string()
+            })
+        } else if !can_write_default_media_cost() {
+            Some(
+                "The Ethernet metered setting is owned by TrustedInstaller and is read-only"
+                    .to_string(),
+            )
+        } else {
+            None
+        };
+
+        Ok(MeteredState {
+            interface: name,
+            metered: cost == NetworkCostType::Fixed || cost == NetworkCostType::Variable,
+            cost_type: cost_type.to_string(),
+            is_wlan,
+            writable: read_only_reason.is_none(),
+            read_only_reason,
+        })
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = interface;
+        Err("get_metered_state is only supported on Windows".into())
+    }
+}
+
+/// Mark a connection as metered or unmetered.
+///
+/// Wi-Fi profiles are changed via `netsh wlan set profileparameter cost=...`; wired connections
+/// via the Ethernet `DefaultMediaCost` registry value (which applies to all Ethernet networks).
+#[tauri::command]
+pub fn set_metered_connection(interface: Option<String>, metered: bool) -> Result<(), String> {
+    #[cfg(windows)]
+    {
+        let profile = find_connection_profile(interface.as_deref())?;
+
+        if !profile.IsWlanConnectionProfile().unwrap_or(false) {
+            return write_default_media_cost(metered);
+        }
+
+        if !super::startup::is_running_as_admin() {
+            return Err(
+                "Changing a Wi-Fi profile cost requires administrator privileges".to_string(),
+            );
+        }
+
+        let name = profile
+            .ProfileName()
+            .map_err(|e| e.to_string())?
+            .to_string();
+        run_netsh(&[
+            "wlan".into(),
+            "set".into(),
+            "profileparameter".into(),
+            format!("name={name}"),
+            format!("cost={}", if metered { "Fixed" } else { "Unrestricted" }),
+        ])
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = (interface, metered);
+        Err("set_metered_connection is only supported on Windows".into())
+    }
+}
+
+/// Connected Wi-Fi network (SSID, signal quality, band); `has_adapter` is false without Wi-Fi hardware
+#[tauri::command]
+pub fn get_wifi_info() -> Result<wifi::WifiInfo, String> {
+    wifi::get_wifi_info()
+}
+
+/// Public IP address, cached for 5 minutes; runs off the main thread so a slow lookup
+/// never blocks the local network stats
+#[tauri::command]
+pub async fn get_public_ip() -> Result<public_ip::PublicIp, String> {
+    tauri::async_runtime::spawn_blocking(public_ip::get_public_ip)
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Round-trip latency to a host (ICMP, or TCP connect to port 443 when ICMP is blocked)
+#[tauri::command]
+pub async fn ping_host(host: String) -> Result<network::PingResult, String> {
+    tauri::async_runtime::spawn_blocking(move || network::ping_host(&host))
+        .await
+        .map_err(|e| e.to_string())?
+}
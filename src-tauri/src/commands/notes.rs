@@ -1,7 +1,10 @@
+use pulldown_cmark::{html, CowStr, Event, Options, Parser, Tag};
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
-use tauri::{AppHandle, Manager};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Note {
@@ -9,6 +12,9 @@ pub struct Note {
     pub title: String,
     pub content: String,
     pub updated_at: String,
+    /// Unix timestamp (seconds) at which `note-reminder` fires; cleared once it has fired
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remind_at: Option<i64>,
 }
 
 fn notes_file_path(app: &AppHandle) -> Result<PathBuf, String> {
@@ -21,6 +27,14 @@ fn notes_file_path(app: &AppHandle) -> Result<PathBuf, String> {
     Ok(dir.join("notes.json"))
 }
 
+/// Serializes every read-modify-write of notes.json (commands, the reminder thread, import).
+static NOTES_LOCK: Mutex<()> = Mutex::new(());
+
+fn lock_notes() -> MutexGuard<'static, ()> {
+    // The guarded data lives on disk, so a panic mid-update leaves nothing to poison.
+    NOTES_LOCK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
 fn load_notes(app: &AppHandle) -> Result<Vec<Note>, String> {
     let path = notes_file_path(app)?;
     if !path.exists() {
@@ -35,10 +49,39 @@ fn load_notes(app: &AppHandle) -> Result<Vec<Note>, String> {
     serde_json::from_str::<Vec<Note>>(&content).map_err(|e| format!("Failed to parse notes: {e}"))
 }
 
+/// Number of rotating backups kept next to notes.json (`notes.json.1` is the newest).
+const NOTE_BACKUP_COUNT: u32 = 3;
+
+fn note_backup_path(path: &Path, index: u32) -> PathBuf {
+    path.with_extension(format!("json.{index}"))
+}
+
+/// Shift `notes.json.N` up by one and copy the current notes.json into `notes.json.1`.
+fn rotate_note_backups(path: &Path) -> Result<(), String> {
+    if !path.exists() {
+        return Ok(());
+    }
+
+    for index in (1..NOTE_BACKUP_COUNT).rev() {
+        let from = note_backup_path(path, index);
+        if from.exists() {
+            let to = note_backup_path(path, index + 1);
+            let _ = fs::remove_file(&to);
+            fs::rename(&from, &to).map_err(|e| format!("Failed to rotate notes backup: {e}"))?;
+        }
+    }
+
+    fs::copy(path, note_backup_path(path, 1))
+        .map_err(|e| format!("Failed to back up notes: {e}"))?;
+    Ok(())
+}
+
 fn save_notes(app: &AppHandle, notes: &[Note]) -> Result<(), String> {
     let path = notes_file_path(app)?;
     let tmp = path.with_extension("json.tmp");
 
+    rotate_note_backups(&path)?;
+
     let content = serde_json::to_string_pretty(notes)
         .map_err(|e| format!("Failed to serialize notes: {e}"))?;
     fs::write(&tmp, content).map_err(|e| format!("Failed to write temp notes file: {e}"))?;
@@ -50,6 +93,18 @@ fn save_notes(app: &AppHandle, notes: &[Note]) -> Result<(), String> {
     Ok(())
 }
 
+/// Read all notes while no update is in flight.
+pub(crate) fn read_all_notes(app: &AppHandle) -> Result<Vec<Note>, String> {
+    let _guard = lock_notes();
+    load_notes(app)
+}
+
+/// Replace all notes (e.g. from an imported bundle); the previous file lands in a backup.
+pub(crate) fn replace_all_notes(app: &AppHandle, notes: &[Note]) -> Result<(), String> {
+    let _guard = lock_notes();
+    save_notes(app, notes)
+}
+
 fn now_rfc3339() -> String {
     chrono::Utc::now().to_rfc3339()
 }
@@ -73,7 +128,7 @@ fn generate_note_id(existing: &[Note]) -> String {
 /// List all notes (sorted by updated_at desc).
 #[tauri::command]
 pub fn list_notes(app: AppHandle) -> Result<Vec<Note>, String> {
-    let mut notes = load_notes(&app)?;
+    let mut notes = read_all_notes(&app)?;
     notes.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
     Ok(notes)
 }
@@ -81,12 +136,14 @@ pub fn list_notes(app: AppHandle) -> Result<Vec<Note>, String> {
 /// Create a new note.
 #[tauri::command]
 pub fn create_note(app: AppHandle, title: Option<String>) -> Result<Note, String> {
+    let _guard = lock_notes();
     let mut notes = load_notes(&app)?;
     let note = Note {
         id: generate_note_id(&notes),
         title: title.unwrap_or_else(|| "Nova nota".to_string()),
         content: String::new(),
         updated_at: now_rfc3339(),
+        remind_at: None,
     };
 
     notes.push(note.clone());
@@ -102,6 +159,7 @@ pub fn update_note(
     title: String,
     content: String,
 ) -> Result<Note, String> {
+    let _guard = lock_notes();
     let mut notes = load_notes(&app)?;
     let idx = notes
         .iter()
@@ -120,8 +178,247 @@ pub fn update_note(
 /// Delete a note by id.
 #[tauri::command]
 pub fn delete_note(app: AppHandle, id: String) -> Result<(), String> {
+    let _guard = lock_notes();
     let mut notes = load_notes(&app)?;
     notes.retain(|n| n.id != id);
     save_notes(&app, &notes)?;
     Ok(())
 }
+
+fn update_note_reminder(
+    app: &AppHandle,
+    note_id: &str,
+    remind_at: Option<i64>,
+) -> Result<Note, String> {
+    let _guard = lock_notes();
+    let mut notes = load_notes(app)?;
+    let note = notes
+        .iter_mut()
+        .find(|n| n.id == note_id)
+        .ok_or_else(|| "Note not found".to_string())?;
+
+    // A reminder isn't an edit: updated_at (and the list order) stays as is.
+    note.remind_at = remind_at;
+    let updated = note.clone();
+    save_notes(app, &notes)?;
+    Ok(updated)
+}
+
+/// Schedule a reminder (unix timestamp in seconds) for a note.
+#[tauri::command]
+pub fn set_note_reminder(app: AppHandle, note_id: String, remind_at: i64) -> Result<Note, String> {
+    if remind_at <= chrono::Utc::now().timestamp() {
+        return Err("Reminder time must be in the future".to_string());
+    }
+    update_note_reminder(&app, &note_id, Some(remind_at))
+}
+
+/// Remove a note's reminder.
+#[tauri::command]
+pub fn clear_note_reminder(app: AppHandle, note_id: String) -> Result<Note, String> {
+    update_note_reminder(&app, &note_id, None)
+}
+
+/// How often due reminders are checked
+const REMINDER_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Emit `note-reminder` for every due reminder, clear them, and flash the bar.
+fn fire_due_reminders(app: &AppHandle) -> Result<(), String> {
+    let _guard = lock_notes();
+    let mut notes = load_notes(app)?;
+    let now = chrono::Utc::now().timestamp();
+
+    let mut fired = Vec::new();
+    for note in notes.iter_mut() {
+        if note.remind_at.is_some_and(|at| at <= now) {
+            note.remind_at = None;
+            fired.push(note.clone());
+        }
+    }
+    if fired.is_empty() {
+        return Ok(());
+    }
+
+    // Clear first so a failing emit can't make a reminder fire every minute.
+    save_notes(app, &notes)?;
+    for note in &fired {
+        let _ = app.emit("note-reminder", note);
+    }
+
+    #[cfg(windows)]
+    if let Some(window) = app.get_webview_window("main") {
+        if let Ok(hwnd) = window.hwnd() {
+            let _ = crate::services::windows::flash_window(hwnd.0 as isize, 3);
+        }
+    }
+    Ok(())
+}
+
+/// Check note reminders once a minute. Reminders live in notes.json, so the ones that
+/// came due while the app was closed fire right after startup.
+pub fn start_reminder_watcher(app: AppHandle) {
+    std::thread::Builder::new()
+        .name("note-reminders".to_string())
+        .spawn(move || {
+            // A broken notes.json fails the same way every minute; report it once.
+            let mut last_error: Option<String> = None;
+            loop {
+                match fire_due_reminders(&app) {
+                    Ok(()) => last_error = None,
+                    Err(e) => {
+                        if last_error.as_deref() != Some(e.as_str())
+                            || crate::services::logging::verbose_logs_enabled()
+                        {
+                            eprintln!("Note reminders: {e}");
+                        }
+                        last_error = Some(e);
+                    }
+                }
+                crate::services::session::sleep_poll(REMINDER_CHECK_INTERVAL);
+            }
+        })
+        .ok();
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NoteBackup {
+    /// File name, e.g. "notes.json.1" (1 = most recent)
+    pub name: String,
+    /// Last modification time (RFC 3339)
+    pub modified_at: String,
+    pub size_bytes: u64,
+    /// Number of notes in the backup (None if it can't be parsed)
+    pub note_count: Option<usize>,
+}
+
+/// List the rotating notes backups, most recent first.
+#[tauri::command]
+pub fn list_note_backups(app: AppHandle) -> Result<Vec<NoteBackup>, String> {
+    let path = notes_file_path(&app)?;
+    let mut backups = Vec::new();
+
+    for index in 1..=NOTE_BACKUP_COUNT {
+        let backup = note_backup_path(&path, index);
+        let Ok(meta) = fs::metadata(&backup) else {
+            continue;
+        };
+
+        let modified_at = meta
+            .modified()
+            .map(|t| chrono::DateTime::<chrono::Utc>::from(t).to_rfc3339())
+            .unwrap_or_default();
+        let note_count = fs::read_to_string(&backup)
+            .ok()
+            .and_then(|c| serde_json::from_str::<Vec<Note>>(&c).ok())
+            .map(|notes| notes.len());
+
+        backups.push(NoteBackup {
+            name: backup
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            modified_at,
+            size_bytes: meta.len(),
+            note_count,
+        });
+    }
+
+    Ok(backups)
+}
+
+/// Swap a backup into place. The current notes take the backup's slot, so a restore
+/// can be undone by restoring the same name again.
+#[tauri::command]
+pub fn restore_note_backup(app: AppHandle, name: String) -> Result<Vec<Note>, String> {
+    let _guard = lock_notes();
+    let path = notes_file_path(&app)?;
+    let backup = (1..=NOTE_BACKUP_COUNT)
+        .map(|index| note_backup_path(&path, index))
+        .find(|p| p.file_name().is_some_and(|n| n.to_string_lossy() == name))
+        .ok_or_else(|| format!("Unknown notes backup: {name}"))?;
+
+    let content =
+        fs::read_to_string(&backup).map_err(|e| format!("Failed to read notes backup: {e}"))?;
+    let notes = serde_json::from_str::<Vec<Note>>(&content)
+        .map_err(|e| format!("Notes backup is corrupt: {e}"))?;
+
+    let swap = path.with_extension("json.swap");
+    if path.exists() {
+        fs::rename(&path, &swap).map_err(|e| format!("Failed to move current notes: {e}"))?;
+    }
+    if let Err(e) = fs::rename(&backup, &path) {
+        let _ = fs::rename(&swap, &path);
+        return Err(format!("Failed to restore notes backup: {e}"));
+    }
+    if swap.exists() {
+        fs::rename(&swap, &backup).map_err(|e| format!("Failed to keep previous notes: {e}"))?;
+    }
+
+    Ok(notes)
+}
+
+/// Whether a link/image destination is safe to emit (no `javascript:`, `data:`, ...).
+fn is_safe_url(url: &str) -> bool {
+    // Browsers ignore whitespace/control chars inside the scheme (`java\tscript:`).
+    let cleaned: String = url
+        .chars()
+        .filter(|c| !c.is_whitespace() && !c.is_control())
+        .collect();
+
+    // Relative URLs and fragments have no scheme.
+    let scheme_end = match cleaned.find([':', '/', '?', '#']) {
+        Some(i) if cleaned[i..].starts_with(':') => i,
+        _ => return true,
+    };
+
+    matches!(
+        cleaned[..scheme_end].to_ascii_lowercase().as_str(),
+        "http" | "https" | "mailto"
+    )
+}
+
+/// Render a note's content from markdown to sanitized HTML.
+///
+/// Raw HTML in the note is escaped (rendered as text) instead of passed through, and
+/// links/images with unsafe schemes are neutralized, so the result is safe to inject.
+#[tauri::command(rename_all = "camelCase")]
+pub fn render_note_markdown(app: AppHandle, note_id: String) -> Result<String, String> {
+    let notes = read_all_notes(&app)?;
+    let note = notes
+        .iter()
+        .find(|n| n.id == note_id)
+        .ok_or_else(|| "Note not found".to_string())?;
+
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let events = Parser::new_ext(&note.content, options).map(|event| match event {
+        Event::Html(raw) | Event::InlineHtml(raw) => Event::Text(raw),
+        Event::Start(Tag::Link {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) if !is_safe_url(&dest_url) => Event::Start(Tag::Link {
+            link_type,
+            dest_url: CowStr::Borrowed("#"),
+            title,
+            id,
+        }),
+        Event::Start(Tag::Image {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) if !is_safe_url(&dest_url) => Event::Start(Tag::Image {
+            link_type,
+            dest_url: CowStr::Borrowed(""),
+            title,
+            id,
+        }),
+        other => other,
+    });
+
+    let mut out = String::with_capacity(note.content.len() * 3 / 2);
+    html::push_html(&mut out, events);
+    Ok(out)
+}
@@ -1,15 +1,55 @@
 //! Popup window commands for dropdowns
 
+use std::collections::{HashMap, VecDeque};
 use std::sync::atomic::Ordering;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, OnceLock};
 use std::time::Duration;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 use tauri::{AppHandle, Manager, State, WebviewUrl, WebviewWindowBuilder};
 
 use crate::FoldersPopupCooldown;
 use crate::PinnedPopups;
 use crate::TaskbarState;
 
+/// Number of recent opens averaged per popup by `get_popup_metrics`
+const LATENCY_SAMPLES: usize = 20;
+
+/// Recent open latencies (ms) per popup label
+static OPEN_LATENCIES: OnceLock<Mutex<HashMap<String, VecDeque<f64>>>> = OnceLock::new();
+
+fn record_open_latency(popup_name: &str, started: Instant) {
+    let ms = started.elapsed().as_secs_f64() * 1000.0;
+    if let Ok(mut latencies) = OPEN_LATENCIES.get_or_init(Default::default).lock() {
+        let samples = latencies.entry(popup_name.to_string()).or_default();
+        if samples.len() == LATENCY_SAMPLES {
+            samples.pop_front();
+        }
+        samples.push_back(ms);
+    }
+}
+
+/// Average open latency (ms) of the last few opens, per popup label.
+///
+/// Measured from the open command to the popup being shown, so prewarmed popups should
+/// be far below ones that are created on first open.
+#[tauri::command]
+pub fn get_popup_metrics() -> HashMap<String, f64> {
+    OPEN_LATENCIES
+        .get_or_init(Default::default)
+        .lock()
+        .map(|latencies| {
+            latencies
+                .iter()
+                .filter(|(_, samples)| !samples.is_empty())
+                .map(|(name, samples)| {
+                    let avg = samples.iter().sum::<f64>() / samples.len() as f64;
+                    (name.clone(), avg)
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 fn clamp_to_monitor(
     x: f64,
     y: f64,
@@ -45,6 +85,8 @@ async fn open_popup(
     width: f64,
     height: f64,
 ) -> Result<(), String> {
+    let started = Instant::now();
+
     // Position popups relative to the taskbar monitor.
     // Frontend provides x/y in taskbar-window coordinates (0..width), so translate using the
     // current taskbar window origin stored in TaskbarState.
@@ -87,6 +129,7 @@ async fn open_popup(
         // Re-enable interactions (prewarm sets ignore to true while hidden).
         let _ = popup.set_ignore_cursor_events(false);
         let _ = popup.show();
+        record_open_latency(popup_name, started);
         let _ = popup.set_focus();
         return Ok(());
     }
@@ -109,6 +152,7 @@ async fn open_popup(
     .resizable(false)
     .build()
     .map_err(|e| e.to_string())?;
+    record_open_latency(popup_name, started);
 
     // Hide popup when it loses focus (keeps window alive for instant reopen)
     let popup_clone = popup.clone();
@@ -730,33 +774,60 @@ pub async fn close_storage_popup(app: AppHandle) -> Result<(), String> {
     Ok(())
 }
 
+/// Popups that can be prewarmed, as (window label, route).
+///
+/// Note: power-popup is NOT prewarmed because fullscreen opaque windows
+/// don't hide properly on Windows; we destroy/recreate it each time.
+const PREWARM_POPUPS: [(&str, &str); 13] = [
+    ("cpu-popup", "/?popup=cpu"),
+    ("ram-popup", "/?popup=ram"),
+    ("gpu-popup", "/?popup=gpu"),
+    ("storage-popup", "/?popup=storage"),
+    ("network-popup", "/?popup=network"),
+    ("audio-popup", "/?popup=audio"),
+    ("headset-popup", "/?popup=headset"),
+    ("calendar-popup", "/?popup=calendar"),
+    ("media-popup", "/?popup=media"),
+    ("weather-popup", "/?popup=weather"),
+    ("notes-popup", "/?popup=notes"),
+    ("settings-popup", "/?popup=settings"),
+    ("dev-color-popup", "/?popup=dev-color"),
+];
+
 /// Pre-create popup windows hidden/offscreen to eliminate the first-open creation lag.
 ///
+/// `popups` lists popup names to prewarm (e.g. `"cpu"` or `"cpu-popup"`); unknown names
+/// are ignored and `None` prewarms all of them. Does nothing when `prewarmEnabled` is
+/// off in the active profile.
+///
 /// This is intentionally best-effort: failures should not break the app.
 #[tauri::command]
-pub async fn prewarm_popups(app: AppHandle) -> Result<(), String> {
+pub async fn prewarm_popups(app: AppHandle, popups: Option<Vec<String>>) -> Result<(), String> {
+    let prewarm_enabled = crate::commands::config::get_active_profile()
+        .map(|p| p.prewarm_enabled)
+        .unwrap_or(true);
+    if !prewarm_enabled {
+        return Ok(());
+    }
+
+    let popups: Vec<(&str, &str)> = match popups {
+        None => PREWARM_POPUPS.to_vec(),
+        Some(names) => PREWARM_POPUPS
+            .iter()
+            .filter(|(label, _)| {
+                names.iter().any(|name| {
+                    let name = name.trim();
+                    *label == name || label.strip_suffix("-popup") == Some(name)
+                })
+            })
+            .copied()
+            .collect(),
+    };
+
     // Create offscreen and (ideally) invisible so the user never sees a flash.
     let offscreen_x = -10_000.0;
     let offscreen_y = -10_000.0;
 
-    // Note: power-popup is NOT prewarmed because fullscreen opaque windows
-    // don't hide properly on Windows; we destroy/recreate it each time.
-    let popups: [(&str, &str); 13] = [
-        ("cpu-popup", "/?popup=cpu"),
-        ("ram-popup", "/?popup=ram"),
-        ("gpu-popup", "/?popup=gpu"),
-        ("storage-popup", "/?popup=storage"),
-        ("network-popup", "/?popup=network"),
-        ("audio-popup", "/?popup=audio"),
-        ("headset-popup", "/?popup=headset"),
-        ("calendar-popup", "/?popup=calendar"),
-        ("media-popup", "/?popup=media"),
-        ("weather-popup", "/?popup=weather"),
-        ("notes-popup", "/?popup=notes"),
-        ("settings-popup", "/?popup=settings"),
-        ("dev-color-popup", "/?popup=dev-color"),
-    ];
-
     for (label, url) in popups {
         if app.get_webview_window(label).is_some() {
             continue;
@@ -807,6 +878,44 @@ pub async fn prewarm_popups(app: AppHandle) -> Result<(), String> {
     Ok(())
 }
 
+/// Hide every open popup except pinned ones (e.g. for an Escape handler or a profile switch).
+///
+/// Hidden popups ignore cursor events again, like prewarmed ones. The power popup is
+/// closed instead of hidden since it is recreated on every open.
+#[tauri::command]
+pub async fn close_all_popups(
+    app: AppHandle,
+    pinned_popups: State<'_, PinnedPopups>,
+) -> Result<(), String> {
+    let pinned = pinned_popups
+        .set
+        .lock()
+        .map_err(|_| "Pinned lock poisoned".to_string())?
+        .clone();
+
+    let labels = PREWARM_POPUPS
+        .iter()
+        .map(|(label, _)| *label)
+        .chain(["folders-popup", "taskswitcher-popup"]);
+    for label in labels {
+        if pinned.contains(label) {
+            continue;
+        }
+        if let Some(popup) = app.get_webview_window(label) {
+            if popup.is_visible().unwrap_or(false) {
+                let _ = popup.hide();
+            }
+            let _ = popup.set_ignore_cursor_events(true);
+        }
+    }
+
+    if let Some(popup) = app.get_webview_window("power-popup") {
+        let _ = popup.close();
+    }
+
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn set_popup_pinned(
     app: AppHandle,
@@ -844,3 +953,106 @@ pub fn get_popup_pinned(
         .map_err(|_| "Pinned lock poisoned".to_string())?;
     Ok(set.contains(&popup_name))
 }
+
+fn note_window_label(note_id: &str) -> Result<String, String> {
+    // Window labels only accept a restricted charset; note ids are generated as `note_<millis>`.
+    if note_id.is_empty()
+        || !note_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        return Err(format!("Invalid note id: {note_id}"));
+    }
+    Ok(format!("note-{note_id}"))
+}
+
+/// Open a note in its own always-on-top window (desktop sticky note).
+///
+/// Note windows are pinned automatically so they survive focus loss.
+#[tauri::command(rename_all = "camelCase")]
+pub async fn open_note_window(
+    app: AppHandle,
+    pinned_popups: State<'_, PinnedPopups>,
+    note_id: String,
+) -> Result<(), String> {
+    let label = note_window_label(&note_id)?;
+
+    if let Ok(mut set) = pinned_popups.set.lock() {
+        set.insert(label.clone());
+    }
+
+    // Already torn off: just bring it to front.
+    if let Some(window) = app.get_webview_window(&label) {
+        let _ = window.set_ignore_cursor_events(false);
+        let _ = window.show();
+        let _ = window.set_focus();
+        return Ok(());
+    }
+
+    let width = 280.0;
+    let height = 300.0;
+
+    let cursor = app.cursor_position().map_err(|e| e.to_string())?;
+    let (x, y) = match app
+        .monitor_from_point(cursor.x, cursor.y)
+        .map_err(|e| e.to_string())?
+    {
+        Some(monitor) => clamp_to_monitor(
+            cursor.x - width / 2.0,
+            cursor.y - 24.0,
+            width,
+            height,
+            &monitor,
+        ),
+        None => (cursor.x, cursor.y),
+    };
+
+    let window = WebviewWindowBuilder::new(
+        &app,
+        &label,
+        WebviewUrl::App(format!("/?popup=note&id={}", note_id).into()),
+    )
+    .title(&label)
+    .inner_size(width, height)
+    .position(x, y)
+    .decorations(false)
+    .transparent(false)
+    .always_on_top(true)
+    .skip_taskbar(true)
+    .focused(true)
+    .shadow(true)
+    .resizable(true)
+    .build()
+    .map_err(|e| e.to_string())?;
+
+    // Drop the pin once the window is gone so the set doesn't accumulate stale labels.
+    let pinned_set = pinned_popups.set.clone();
+    window.on_window_event(move |event| {
+        if let tauri::WindowEvent::Destroyed = event {
+            if let Ok(mut set) = pinned_set.lock() {
+                set.remove(&label);
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Close (destroy) a note window opened with `open_note_window`.
+#[tauri::command(rename_all = "camelCase")]
+pub async fn close_note_window(
+    app: AppHandle,
+    pinned_popups: State<'_, PinnedPopups>,
+    note_id: String,
+) -> Result<(), String> {
+    let label = note_window_label(&note_id)?;
+
+    if let Ok(mut set) = pinned_popups.set.lock() {
+        set.remove(&label);
+    }
+
+    if let Some(window) = app.get_webview_window(&label) {
+        window.destroy().map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
@@ -0,0 +1,71 @@
+//! Commands for quick launch (apps pinned to the bar)
+
+use crate::commands::config::{QuickLaunchConfig, QuickLaunchItem};
+use crate::services::paths;
+use std::path::Path;
+use std::process::Command;
+
+/// Get quick launch items from active profile
+#[tauri::command]
+pub fn get_quick_launch() -> Result<QuickLaunchConfig, String> {
+    let config = super::config::get_active_profile()?;
+    Ok(config.quick_launch)
+}
+
+/// Pin an app to the bar
+#[tauri::command]
+pub fn add_quick_launch(mut item: QuickLaunchItem) -> Result<(), String> {
+    let mut config = super::config::get_active_profile()?;
+
+    if item.id.trim().is_empty() {
+        return Err("Quick launch item needs an id".to_string());
+    }
+    if config.quick_launch.items.iter().any(|i| i.id == item.id) {
+        return Err("Quick launch item with this ID already exists".to_string());
+    }
+
+    item.exe_path = item.exe_path.trim().trim_matches('"').trim().to_string();
+    if !Path::new(&paths::expand_path(&item.exe_path)).is_file() {
+        return Err(format!("Executable not found: {}", item.exe_path));
+    }
+
+    config.quick_launch.items.push(item);
+    super::config::save_current_profile(config)
+}
+
+/// Unpin an app by ID
+#[tauri::command]
+pub fn remove_quick_launch(id: String) -> Result<(), String> {
+    let mut config = super::config::get_active_profile()?;
+    config.quick_launch.items.retain(|i| i.id != id);
+    super::config::save_current_profile(config)
+}
+
+/// Start a pinned app
+#[tauri::command]
+pub fn launch_quick_item(id: String) -> Result<(), String> {
+    let config = super::config::get_active_profile()?;
+    let item = config
+        .quick_launch
+        .items
+        .iter()
+        .find(|i| i.id == id)
+        .ok_or("Quick launch item not found")?;
+
+    let exe = paths::expand_path(&item.exe_path);
+    let exe = Path::new(&exe);
+    if !exe.is_file() {
+        return Err(format!("Executable not found: {}", exe.display()));
+    }
+
+    let mut command = Command::new(exe);
+    command.args(&item.args);
+    // Many apps expect to start in their install folder (relative config/plugin paths)
+    if let Some(dir) = exe.parent().filter(|d| d.is_dir()) {
+        command.current_dir(dir);
+    }
+    command
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("Failed to launch {}: {}", item.name, e))
+}
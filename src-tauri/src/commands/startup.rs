@@ -13,6 +13,8 @@ use std::process::Command;
 
 use tauri::AppHandle;
 
+use crate::services::startup_programs::{self, StartupEntry, StartupLocation};
+
 const BAT_FILENAME: &str = "BarMinimalTools.bat";
 const LEGACY_TASK_NAME: &str = "BarMinimalTools";
 
@@ -135,6 +137,24 @@ pub fn startup_disable() -> Result<(), String> {
     }
 }
 
+/// List every program launched at sign-in (Run keys + Startup folder)
+#[tauri::command]
+pub fn list_startup_programs() -> Vec<StartupEntry> {
+    startup_programs::list_startup_programs()
+}
+
+/// Enable or disable another program's startup entry (same mechanism as Task Manager).
+///
+/// `HKLM` entries need administrator rights.
+#[tauri::command]
+pub fn set_startup_program_enabled(
+    name: String,
+    location: StartupLocation,
+    enabled: bool,
+) -> Result<(), String> {
+    startup_programs::set_enabled(&name, location, enabled)
+}
+
 /// Check if the application is running with administrator privileges
 #[tauri::command]
 pub fn is_running_as_admin() -> bool {
@@ -1,8 +1,13 @@
 //! System data Tauri commands
 
-use crate::services::network;
-use crate::services::{cpu, gpu, ram, storage, WmiService};
+use crate::services::wmi_service::LoadAverages;
+use crate::services::{
+    bluetooth, color_picker, focus_assist, idle, keepawake, logging, network, network_usage,
+    session, theme, windows_update,
+};
+use crate::services::{cpu, gpu, processes, ram, recycle_bin, storage, telemetry_log, WmiService};
 use serde::Serialize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use tauri::State;
 
@@ -134,15 +139,68 @@ fn send_win_shortcut(
 
     Ok(())
 }
+/// Severity of a metric against the active profile's thresholds
+#[derive(Serialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum MetricLevel {
+    Normal,
+    Warn,
+    Crit,
+}
+
+impl MetricLevel {
+    fn from_value(value: f32, warn: f32, crit: f32) -> Self {
+        if value >= crit {
+            MetricLevel::Crit
+        } else if value >= warn {
+            MetricLevel::Warn
+        } else {
+            MetricLevel::Normal
+        }
+    }
+}
+
+/// Per-metric status so popups and the bar share the same color logic
+#[derive(Serialize)]
+pub struct MetricStatus {
+    pub cpu: MetricLevel,
+    pub ram: MetricLevel,
+    pub gpu: MetricLevel,
+    /// GPU temperature ("normal" when the GPU doesn't report one)
+    pub temp: MetricLevel,
+}
+
 #[derive(Serialize)]
 pub struct SystemSnapshot {
     pub cpu: cpu::CpuData,
     pub ram: ram::RamData,
     pub gpu: gpu::GpuData,
     pub storage: storage::StorageData,
+    pub status: MetricStatus,
     pub timestamp: i64,
 }
 
+fn metric_status(
+    cpu: &cpu::CpuData,
+    ram: &ram::RamData,
+    gpu: &gpu::GpuData,
+    thresholds: &crate::commands::config::ThresholdsConfig,
+) -> MetricStatus {
+    let (gpu_usage, gpu_temp) = match gpu {
+        gpu::GpuData::Basic(basic) => (basic.usage_percent, None),
+        gpu::GpuData::Detailed(detailed) => (detailed.basic.usage_percent, detailed.temperature_c),
+    };
+
+    MetricStatus {
+        cpu: MetricLevel::from_value(cpu.total_usage, thresholds.cpu_warn, thresholds.cpu_crit),
+        ram: MetricLevel::from_value(ram.usage_percent, thresholds.ram_warn, thresholds.ram_crit),
+        gpu: MetricLevel::from_value(gpu_usage, thresholds.gpu_warn, thresholds.gpu_crit),
+        temp: gpu_temp
+            .map(|t| MetricLevel::from_value(t, thresholds.temp_warn, thresholds.temp_crit))
+            .unwrap_or(MetricLevel::Normal),
+    }
+}
+
 /// Get a complete system snapshot with all hardware data (using cached WMI data)
 #[tauri::command]
 pub async fn get_system_snapshot(
@@ -150,16 +208,245 @@ pub async fn get_system_snapshot(
 ) -> Result<SystemSnapshot, String> {
     let timestamp = chrono::Utc::now().timestamp_millis();
     let cached = wmi_service.get_cached_data();
+    let thresholds = crate::commands::config::active_thresholds();
+
+    let cpu = cpu::get_cpu_info_cached(&cached);
+    let ram = ram::get_ram_info_cached(&cached);
+    let gpu = gpu::get_gpu_info_cached(&cached);
+    let status = metric_status(&cpu, &ram, &gpu, &thresholds);
 
     Ok(SystemSnapshot {
-        cpu: cpu::get_cpu_info_cached(&cached),
-        ram: ram::get_ram_info_cached(&cached),
-        gpu: gpu::get_gpu_info_cached(&cached),
+        cpu,
+        ram,
+        gpu,
         storage: storage::get_storage_info_cached(&cached),
+        status,
         timestamp,
     })
 }
 
+/// Append CPU/GPU/RAM/network telemetry to a CSV file every `interval_ms`
+#[tauri::command]
+pub fn start_telemetry_logging(
+    wmi_service: State<'_, Arc<WmiService>>,
+    path: String,
+    interval_ms: u64,
+) -> Result<(), String> {
+    telemetry_log::start(Arc::clone(&wmi_service), &path, interval_ms)
+}
+
+/// Stop CSV telemetry logging; returns whether a logger was running
+#[tauri::command]
+pub fn stop_telemetry_logging() -> bool {
+    telemetry_log::stop()
+}
+
+/// Top `limit` processes by "cpu" or "memory" usage
+///
+/// CPU usage is measured since the previous call (the first call samples for half a second).
+#[tauri::command]
+pub async fn get_top_processes(
+    by: String,
+    limit: usize,
+) -> Result<Vec<processes::ProcessUsage>, String> {
+    tauri::async_runtime::spawn_blocking(move || processes::get_top_processes(&by, limit))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// End a process by PID (critical system processes and this app are refused)
+#[tauri::command]
+pub fn kill_process(pid: u32) -> Result<(), String> {
+    processes::kill_process(pid)
+}
+
+/// Keep the system and display awake while enabled; returns the new state
+#[tauri::command]
+pub fn set_keep_awake(enabled: bool) -> Result<bool, String> {
+    keepawake::set_keep_awake(enabled)?;
+    Ok(keepawake::is_enabled())
+}
+
+/// Whether keep-awake is currently enabled
+#[tauri::command]
+pub fn get_keep_awake() -> bool {
+    keepawake::is_enabled()
+}
+
+/// Windows accent color and whether apps use the dark theme
+#[tauri::command]
+pub fn get_system_accent_color() -> Result<theme::AccentColor, String> {
+    theme::get_accent_color()
+}
+
+/// Static payload returned by `ping`
+#[derive(Serialize)]
+pub struct Pong {
+    pub ok: bool,
+    pub version: &'static str,
+}
+
+/// Payload returned by `get_system_uptime`
+#[derive(Serialize)]
+pub struct SystemUptime {
+    pub uptime_seconds: u64,
+    /// Unix timestamp (seconds) of the last boot
+    pub boot_time_unix: i64,
+}
+
+/// Platform-specific features compiled into this build
+#[derive(Serialize)]
+pub struct Capabilities {
+    pub platform: &'static str,
+    pub arch: &'static str,
+    pub version: &'static str,
+    /// WMI/PDH hardware sensors (CPU, RAM, storage, network)
+    pub hardware_monitor: bool,
+    /// NVIDIA GPU stats through NVML
+    pub nvidia: bool,
+    /// Core Audio volume/mute control
+    pub audio: bool,
+    /// Headset battery/status over HID
+    pub headset: bool,
+    /// System media transport controls (now playing)
+    pub media: bool,
+    /// Window enumeration for the task switcher
+    pub task_switcher: bool,
+    /// Replacing/hiding the native Windows taskbar
+    pub native_taskbar: bool,
+}
+
+/// Health check for the IPC layer. Never touches platform APIs.
+#[tauri::command]
+pub fn ping() -> Pong {
+    Pong {
+        ok: true,
+        version: env!("CARGO_PKG_VERSION"),
+    }
+}
+
+/// Report which platform-specific features are compiled in.
+#[tauri::command]
+pub fn get_capabilities() -> Capabilities {
+    Capabilities {
+        platform: std::env::consts::OS,
+        arch: std::env::consts::ARCH,
+        version: env!("CARGO_PKG_VERSION"),
+        hardware_monitor: cfg!(windows),
+        nvidia: cfg!(all(windows, feature = "nvidia")),
+        audio: cfg!(windows),
+        headset: cfg!(all(windows, feature = "headset")),
+        media: cfg!(windows),
+        task_switcher: cfg!(windows),
+        native_taskbar: cfg!(windows),
+    }
+}
+
+/// Whether the workstation is currently locked (background polling is throttled while it is).
+#[tauri::command]
+pub fn get_session_state() -> session::SessionState {
+    session::get_state()
+}
+
+/// Snapshot of internal state to attach to bug reports.
+///
+/// Only reads what the app already tracks; nothing here probes the hardware.
+#[tauri::command]
+pub fn collect_diagnostics(
+    app: tauri::AppHandle,
+    taskbar_state: State<'_, Arc<crate::TaskbarState>>,
+    wmi_service: State<'_, Arc<WmiService>>,
+) -> serde_json::Value {
+    use tauri::Manager as _;
+
+    let bounds = taskbar_state
+        .bounds
+        .lock()
+        .ok()
+        .and_then(|b| *b)
+        .map(|(x, y, width, height)| {
+            serde_json::json!({ "x": x, "y": y, "width": width, "height": height })
+        });
+    let monitors = app
+        .get_webview_window("main")
+        .map(|w| crate::commands::monitor::list_monitors_for(&w))
+        .unwrap_or_default();
+    let active_profile = crate::commands::config::get_active_profile()
+        .map(|p| p.profile_name)
+        .ok();
+    let icue = crate::commands::headset::check_icue_sdk();
+
+    serde_json::json!({
+        "version": env!("CARGO_PKG_VERSION"),
+        "platform": std::env::consts::OS,
+        "appbar": {
+            "registered": crate::services::appbar::is_appbar_registered(),
+            "bounds": bounds,
+            "fullscreen_hidden": taskbar_state.fullscreen_hidden.load(Ordering::SeqCst),
+            "transition": taskbar_state.appbar_transition.load(Ordering::SeqCst),
+        },
+        "monitors": monitors,
+        "wmi_ready": wmi_service.is_ready(),
+        "nvml_available": gpu::nvml_available(),
+        "icue": icue,
+        // LibreHardwareMonitor integration isn't part of this build.
+        "lhm_available": serde_json::Value::Null,
+        "session_locked": session::is_locked(),
+        "verbose_logs": logging::verbose_logs_enabled(),
+        "active_profile": active_profile,
+    })
+}
+
+/// Turn verbose diagnostic logging on or off without restarting
+#[tauri::command]
+pub fn set_verbose_logging(enabled: bool) {
+    logging::set_verbose_logs(enabled);
+}
+
+/// Seconds since the last keyboard or mouse input (0 where unavailable)
+#[tauri::command]
+pub fn get_idle_seconds() -> u64 {
+    idle::get_idle_seconds()
+}
+
+/// Time since boot, from the tick counter (no WMI query needed)
+#[tauri::command]
+pub fn get_system_uptime() -> Result<SystemUptime, String> {
+    #[cfg(windows)]
+    {
+        use windows::Win32::System::SystemInformation::GetTickCount64;
+
+        let uptime_seconds = unsafe { GetTickCount64() } / 1000;
+        Ok(SystemUptime {
+            uptime_seconds,
+            boot_time_unix: chrono::Utc::now().timestamp() - uptime_seconds as i64,
+        })
+    }
+
+    #[cfg(not(windows))]
+    {
+        Err("Uptime is only supported on Windows".to_string())
+    }
+}
+
+/// Read the color of the pixel under the cursor
+#[tauri::command]
+pub fn pick_screen_color() -> Result<color_picker::ScreenColor, String> {
+    color_picker::pick_color()
+}
+
+/// Sample colors under the cursor (emitting `color-sampled`) until the user clicks.
+///
+/// Left click returns the picked color; right click or a 30s timeout returns `None`.
+#[tauri::command]
+pub async fn start_color_eyedropper(
+    app: tauri::AppHandle,
+) -> Result<Option<color_picker::ScreenColor>, String> {
+    tauri::async_runtime::spawn_blocking(move || color_picker::run_eyedropper(app))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
 /// Get CPU data only
 #[tauri::command]
 pub async fn get_cpu_data(wmi_service: State<'_, Arc<WmiService>>) -> Result<cpu::CpuData, String> {
@@ -181,6 +468,12 @@ pub async fn get_gpu_data(wmi_service: State<'_, Arc<WmiService>>) -> Result<gpu
     Ok(gpu::get_gpu_info_cached(&cached))
 }
 
+/// List processes using the NVIDIA GPU (empty when NVML is unavailable)
+#[tauri::command]
+pub async fn get_gpu_processes() -> Result<Vec<gpu::GpuProcess>, String> {
+    Ok(gpu::get_gpu_processes())
+}
+
 /// Get storage data only
 #[tauri::command]
 pub async fn get_storage_data(
@@ -190,6 +483,22 @@ pub async fn get_storage_data(
     Ok(storage::get_storage_info_cached(&cached))
 }
 
+/// Item count and total size of the Recycle Bin (all drives)
+#[tauri::command]
+pub fn get_recycle_bin_info() -> Result<recycle_bin::RecycleBinInfo, String> {
+    recycle_bin::get_info()
+}
+
+/// Empty the Recycle Bin and return its new state.
+///
+/// With `confirm`, Windows shows its own confirmation dialog (and progress/sound).
+#[tauri::command]
+pub async fn empty_recycle_bin(confirm: bool) -> Result<recycle_bin::RecycleBinInfo, String> {
+    tauri::async_runtime::spawn_blocking(move || recycle_bin::empty(confirm))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
 /// Get network data only
 #[tauri::command]
 pub async fn get_network_data(
@@ -199,6 +508,24 @@ pub async fn get_network_data(
     Ok(network::get_network_info_cached(&cached.network))
 }
 
+/// Get rolling 1-minute/5-minute CPU and GPU usage averages
+#[tauri::command]
+pub async fn get_load_averages(
+    wmi_service: State<'_, Arc<WmiService>>,
+) -> Result<LoadAverages, String> {
+    Ok(wmi_service.get_load_averages())
+}
+
+/// Get persisted network usage totals.
+///
+/// `range` is "daily" (last 31 days) or "monthly" (every tracked month).
+#[tauri::command]
+pub fn get_network_usage_history(
+    range: String,
+) -> Result<Vec<network_usage::NetworkUsageEntry>, String> {
+    network_usage::get_usage_history(&range)
+}
+
 /// Best-effort: return the number of notifications currently present in the Windows
 /// Notification Center / Action Center.
 ///
@@ -251,6 +578,27 @@ pub async fn get_unread_notification_count() -> Result<Option<u32>, String> {
     }
 }
 
+/// Paired Bluetooth devices with connection state and battery level (when reported)
+#[tauri::command]
+pub async fn get_bluetooth_devices() -> Result<Vec<bluetooth::BtDevice>, String> {
+    tauri::async_runtime::spawn_blocking(bluetooth::get_bluetooth_devices)
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Whether Focus Assist is suppressing notifications ("off", "priority", "alarms" or
+/// "unknown" when the state can't be read)
+#[tauri::command]
+pub fn get_focus_assist_state() -> focus_assist::FocusAssistState {
+    focus_assist::get_state()
+}
+
+/// Get Windows Update status (pending reboot, active hours)
+#[tauri::command]
+pub fn get_windows_update_status() -> windows_update::WindowsUpdateStatus {
+    windows_update::get_status()
+}
+
 #[cfg(windows)]
 fn run_process(program: &str, args: &[&str]) -> Result<(), String> {
     Command::new(program)
@@ -288,6 +636,52 @@ pub fn system_restart() -> Result<(), String> {
     }
 }
 
+/// Schedule a restart in `delay_minutes` (e.g. to finish installing updates).
+#[tauri::command]
+pub fn schedule_restart(delay_minutes: u32) -> Result<(), String> {
+    // `shutdown /t` accepts at most 10 years; anything close to that is a mistake.
+    const MAX_DELAY_MINUTES: u32 = 7 * 24 * 60;
+    if delay_minutes == 0 || delay_minutes > MAX_DELAY_MINUTES {
+        return Err(format!(
+            "delay_minutes must be between 1 and {MAX_DELAY_MINUTES}"
+        ));
+    }
+
+    #[cfg(windows)]
+    {
+        let seconds = (delay_minutes * 60).to_string();
+        return run_process("shutdown.exe", &["/r", "/t", &seconds]);
+    }
+
+    #[cfg(not(windows))]
+    {
+        Err("schedule_restart is only supported on Windows".into())
+    }
+}
+
+/// Cancel a restart/shutdown scheduled with `schedule_restart`.
+#[tauri::command]
+pub fn cancel_scheduled_restart() -> Result<(), String> {
+    #[cfg(windows)]
+    {
+        let status = Command::new("shutdown.exe")
+            .arg("/a")
+            .status()
+            .map_err(|e| e.to_string())?;
+        match status.code() {
+            Some(0) => Ok(()),
+            // ERROR_NO_SHUTDOWN_IN_PROGRESS
+            Some(1116) => Err("No scheduled restart to cancel".into()),
+            code => Err(format!("shutdown /a failed (exit code {code:?})")),
+        }
+    }
+
+    #[cfg(not(windows))]
+    {
+        Err("cancel_scheduled_restart is only supported on Windows".into())
+    }
+}
+
 /// Sign out the current user session (Windows).
 #[tauri::command]
 pub fn system_sign_out() -> Result<(), String> {
@@ -389,6 +783,26 @@ pub fn open_task_manager() -> Result<(), String> {
     }
 }
 
+/// Flush the DNS resolver cache (`ipconfig /flushdns`). Requires administrator privileges.
+#[tauri::command]
+pub fn flush_dns() -> Result<String, String> {
+    #[cfg(windows)]
+    {
+        if !super::startup::is_running_as_admin() {
+            return Err(
+                "Flushing DNS requires administrator privileges. Restart the app as administrator."
+                    .to_string(),
+            );
+        }
+        super::network::run_console("ipconfig.exe", &["/flushdns"])
+    }
+
+    #[cfg(not(windows))]
+    {
+        Err("flush_dns is only supported on Windows".into())
+    }
+}
+
 /// Quit the Bar app (with AppBar cleanup).
 #[tauri::command]
 pub fn quit_app(app: tauri::AppHandle) -> Result<(), String> {
@@ -401,6 +815,11 @@ pub fn quit_app(app: tauri::AppHandle) -> Result<(), String> {
         }
     }
 
+    crate::services::network_usage::flush();
+    crate::services::telemetry_log::stop();
+    crate::services::gamma::reset_on_exit();
+    crate::services::windows::stop_event_watcher();
+
     // Avoid tearing down the WebView while the command IPC is still completing.
     // This reduces noisy Chromium shutdown logs like:
     // "Failed to unregister class Chrome_WidgetWin_0. Error = 1412".
@@ -442,3 +861,46 @@ pub fn open_notification_center() -> Result<(), String> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ping_reports_ok_and_crate_version() {
+        let pong = ping();
+        assert!(pong.ok);
+        assert_eq!(pong.version, env!("CARGO_PKG_VERSION"));
+    }
+
+    #[test]
+    fn ping_serializes_stable_shape() {
+        let value = serde_json::to_value(ping()).unwrap();
+        assert_eq!(value["ok"], serde_json::Value::Bool(true));
+        assert!(value["version"].is_string());
+    }
+
+    #[test]
+    fn capabilities_match_build_target() {
+        let caps = get_capabilities();
+        assert_eq!(caps.platform, std::env::consts::OS);
+        assert_eq!(caps.arch, std::env::consts::ARCH);
+        assert_eq!(caps.version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(caps.hardware_monitor, cfg!(windows));
+        assert_eq!(caps.audio, cfg!(windows));
+        assert_eq!(caps.media, cfg!(windows));
+        assert_eq!(caps.task_switcher, cfg!(windows));
+        assert_eq!(caps.native_taskbar, cfg!(windows));
+    }
+
+    #[test]
+    fn feature_gated_capabilities_are_off_outside_windows() {
+        let caps = get_capabilities();
+        if !cfg!(windows) {
+            assert!(!caps.nvidia);
+            assert!(!caps.headset);
+        }
+        assert_eq!(caps.nvidia, cfg!(all(windows, feature = "nvidia")));
+        assert_eq!(caps.headset, cfg!(all(windows, feature = "headset")));
+    }
+}
@@ -0,0 +1,22 @@
+//! Countdown timer commands for Tauri
+
+use crate::services::timer::{self, TimerState};
+use tauri::AppHandle;
+
+/// Start a countdown; progress arrives as `timer-tick` / `timer-finished` events
+#[tauri::command]
+pub fn start_timer(app: AppHandle, seconds: u64, label: String) -> Result<TimerState, String> {
+    timer::start_timer(app, seconds, label)
+}
+
+/// Cancel an active timer
+#[tauri::command]
+pub fn cancel_timer(id: String) -> Result<(), String> {
+    timer::cancel_timer(&id)
+}
+
+/// List active timers (soonest to finish first)
+#[tauri::command]
+pub fn list_timers() -> Vec<TimerState> {
+    timer::list_timers()
+}
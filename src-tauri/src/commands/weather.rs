@@ -1,6 +1,6 @@
 //! Weather commands for Tauri
 
-use crate::services::weather::{self, LocationData, WeatherData};
+use crate::services::weather::{self, AirQualityData, GeoResult, LocationData, WeatherData};
 
 /// Get current weather data by coordinates
 #[tauri::command]
@@ -8,6 +8,18 @@ pub fn get_weather(lat: f64, lon: f64) -> WeatherData {
     weather::get_weather(lat, lon)
 }
 
+/// Fetch fresh weather data, bypassing the 10-minute cache (e.g. after changing location)
+#[tauri::command]
+pub fn refresh_weather(lat: f64, lon: f64) -> WeatherData {
+    weather::refresh_weather(lat, lon)
+}
+
+/// Get current air quality (PM2.5, PM10, ozone, AQI) by coordinates
+#[tauri::command]
+pub fn get_air_quality(lat: f64, lon: f64) -> AirQualityData {
+    weather::get_air_quality(lat, lon)
+}
+
 /// Get weather icon URL
 #[tauri::command]
 pub fn get_weather_icon_url(icon: String) -> String {
@@ -19,3 +31,30 @@ pub fn get_weather_icon_url(icon: String) -> String {
 pub fn get_current_location() -> LocationData {
     weather::get_current_location()
 }
+
+/// Location the weather widget should use.
+///
+/// With auto-location disabled this is built from the saved coordinates without any
+/// network request; otherwise it falls back to IP geolocation.
+#[tauri::command]
+pub fn get_effective_location() -> Result<LocationData, String> {
+    let config = crate::commands::config::get_weather_config()?;
+    if config.use_auto_location {
+        return Ok(weather::get_current_location());
+    }
+
+    Ok(LocationData {
+        latitude: config.latitude,
+        longitude: config.longitude,
+        city: config.city_name,
+        region: String::new(),
+        country: String::new(),
+        success: true,
+    })
+}
+
+/// Search a city by name (for picking weather coordinates)
+#[tauri::command]
+pub fn geocode_city(query: String) -> Result<Vec<GeoResult>, String> {
+    weather::geocode_city(&query)
+}
@@ -1,6 +1,10 @@
 //! Commands for window management (Task Switcher)
 
+use crate::commands::{config, monitor};
+use crate::services::hotkey;
+use crate::services::vdesktop::{self, VirtualDesktop};
 use crate::services::windows::{self, WindowInfo, WindowList};
+use tauri::{AppHandle, Manager};
 
 /// Get list of all visible windows
 #[tauri::command]
@@ -20,8 +24,94 @@ pub fn focus_window(hwnd: isize) -> Result<(), String> {
     windows::focus_window(hwnd)
 }
 
-/// Get icon for a process (returns base64 encoded PNG)
+/// Flash a window's taskbar button `count` times to request attention
 #[tauri::command]
-pub fn get_process_icon(process_path: String) -> Option<String> {
-    windows::get_process_icon(&process_path)
+pub fn flash_window(hwnd: isize, count: u32) -> Result<(), String> {
+    windows::flash_window(hwnd, count)
+}
+
+/// Minimize all windows / restore them (Win+D); returns whether the desktop is now shown
+#[tauri::command]
+pub fn toggle_show_desktop() -> Result<bool, String> {
+    windows::toggle_show_desktop()
+}
+
+/// Move a window onto a monitor (`monitor_id` as returned by `list_monitors`)
+#[tauri::command]
+pub fn move_window_to_monitor(
+    app: AppHandle,
+    hwnd: isize,
+    monitor_id: String,
+) -> Result<(), String> {
+    let window = app
+        .get_webview_window("main")
+        .ok_or("Main window not found")?;
+    let monitor = monitor::list_monitors_for(&window)
+        .into_iter()
+        .find(|m| m.id == monitor_id)
+        .ok_or_else(|| format!("Unknown monitor: {monitor_id}"))?;
+
+    windows::move_window_to_monitor(hwnd, monitor.x, monitor.y)
+}
+
+/// Snap a window to a half/quadrant of its monitor ("left", "top-right", "maximize", ...)
+#[tauri::command]
+pub fn snap_window(hwnd: isize, layout: String) -> Result<(), String> {
+    windows::snap_window(hwnd, &layout)
+}
+
+/// List virtual desktops in task view order
+#[tauri::command]
+pub fn list_virtual_desktops() -> Result<Vec<VirtualDesktop>, String> {
+    vdesktop::list_virtual_desktops()
+}
+
+/// Get the active virtual desktop
+#[tauri::command]
+pub fn get_current_desktop() -> Result<VirtualDesktop, String> {
+    vdesktop::get_current_desktop()
+}
+
+/// Switch to the virtual desktop at `index` (0-based)
+#[tauri::command]
+pub fn switch_virtual_desktop(index: usize) -> Result<(), String> {
+    vdesktop::switch_virtual_desktop(index)
+}
+
+/// Get icon for a process (returns base64 encoded PNG).
+/// `size` is the desired edge in pixels (default 32); the closest of 32/48/256 is used.
+#[tauri::command]
+pub fn get_process_icon(process_path: String, size: Option<u32>) -> Option<String> {
+    windows::get_process_icon(&process_path, size.unwrap_or(32))
+}
+
+/// Clear the process icon cache
+#[tauri::command]
+pub fn clear_icon_cache() {
+    windows::clear_icon_cache()
+}
+
+/// Set (or clear with `None`) the global task switcher hotkey and save it in the profile.
+///
+/// Pressing it emits `taskswitcher-open` / `taskswitcher-navigate` ("next", or "prev" with
+/// Shift) and releasing its modifiers emits `taskswitcher-release`.
+#[tauri::command]
+pub fn set_task_switcher_hotkey(app: AppHandle, hotkey: Option<String>) -> Result<(), String> {
+    let hotkey = hotkey
+        .map(|h| h.trim().to_string())
+        .filter(|h| !h.is_empty());
+    match &hotkey {
+        Some(accelerator) => hotkey::register(app, accelerator)?,
+        None => hotkey::unregister(&app),
+    }
+
+    let mut profile = config::get_active_profile()?;
+    profile.task_switcher_hotkey = hotkey;
+    config::save_current_profile(profile)
+}
+
+/// Accelerator suggested by the settings UI
+#[tauri::command]
+pub fn get_default_task_switcher_hotkey() -> &'static str {
+    hotkey::DEFAULT_TASK_SWITCHER_HOTKEY
 }
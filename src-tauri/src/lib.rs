@@ -2,8 +2,8 @@ pub mod commands;
 pub mod services;
 
 use commands::{
-    audio, config, folders, headset, media, monitor, notes, popup, startup, system, weather,
-    windows,
+    audio, clipboard, config, folders, headset, media, monitor, network, notes, popup,
+    quick_launch, startup, system, timer, weather, windows,
 };
 use services::WmiService;
 use std::collections::HashSet;
@@ -25,6 +25,10 @@ pub struct TaskbarState {
     pub fullscreen_hidden: AtomicBool,
     /// When true, background watchers should not register/unregister the AppBar.
     pub appbar_transition: AtomicBool,
+    /// Auto-hide mode is enabled (`display.autoHide`)
+    pub auto_hide: AtomicBool,
+    /// The bar is currently collapsed to its reveal strip by auto-hide
+    pub auto_hidden: AtomicBool,
 }
 
 /// Shared state to keep certain popups open even when they lose focus.
@@ -62,6 +66,8 @@ impl Default for TaskbarState {
             bounds: Mutex::new(None),
             fullscreen_hidden: AtomicBool::new(false),
             appbar_transition: AtomicBool::new(false),
+            auto_hide: AtomicBool::new(false),
+            auto_hidden: AtomicBool::new(false),
         }
     }
 }
@@ -80,7 +86,8 @@ pub fn run() {
             MacosLauncher::LaunchAgent,
             Some(vec![]),
         ))
-        .plugin(tauri_plugin_clipboard_manager::init());
+        .plugin(tauri_plugin_clipboard_manager::init())
+        .plugin(tauri_plugin_global_shortcut::Builder::new().build());
 
     // In dev, it's common to have a previous instance still running in the tray.
     // Disabling single-instance there avoids the new process immediately exiting
@@ -101,36 +108,100 @@ pub fn run() {
         .manage(folders_popup_cooldown)
         .invoke_handler(tauri::generate_handler![
             // System commands
+            system::ping,
+            system::get_capabilities,
+            system::collect_diagnostics,
+            system::get_session_state,
+            system::set_verbose_logging,
+            system::get_idle_seconds,
+            system::get_system_uptime,
+            system::pick_screen_color,
+            system::start_color_eyedropper,
             system::get_system_snapshot,
+            system::start_telemetry_logging,
+            system::stop_telemetry_logging,
+            system::get_top_processes,
+            system::kill_process,
+            system::set_keep_awake,
+            system::get_keep_awake,
+            system::get_system_accent_color,
             system::get_cpu_data,
             system::get_ram_data,
             system::get_gpu_data,
+            system::get_gpu_processes,
             system::get_storage_data,
+            system::get_recycle_bin_info,
+            system::empty_recycle_bin,
             system::get_network_data,
+            system::get_network_usage_history,
+            system::get_load_averages,
+            system::get_windows_update_status,
             system::open_notification_center,
             system::get_unread_notification_count,
+            system::get_focus_assist_state,
+            system::get_bluetooth_devices,
             system::system_shutdown,
             system::system_restart,
+            system::schedule_restart,
+            system::cancel_scheduled_restart,
             system::system_lock,
             system::system_sign_out,
             system::system_restart_explorer,
             system::open_task_manager,
+            system::flush_dns,
             system::quit_app,
             // Monitor commands
             monitor::list_monitors,
             monitor::set_taskbar_monitor,
             monitor::preview_taskbar_height,
             monitor::unregister_taskbar_appbar,
+            monitor::apply_window_effects,
+            monitor::capture_monitor,
+            monitor::capture_to_clipboard,
+            monitor::set_display_resolution,
+            monitor::is_native_taskbar_visible,
+            monitor::set_auto_hide,
+            monitor::set_color_temperature,
+            monitor::reset_color_temperature,
+            // Network tools commands
+            network::get_network_profiles,
+            network::save_network_profile,
+            network::apply_network_profile,
+            network::reset_network_adapter,
+            network::get_metered_state,
+            network::set_metered_connection,
+            network::get_wifi_info,
+            network::get_public_ip,
+            network::ping_host,
             // Config commands
             config::list_profiles,
             config::create_profile,
+            config::rename_profile,
+            config::duplicate_profile,
+            config::reset_profile_to_defaults,
+            config::merge_profiles,
             config::switch_profile,
             config::save_current_profile,
             config::export_profile,
             config::import_profile,
+            config::export_all,
+            config::import_all,
             config::get_active_profile,
+            config::get_raw_config,
+            config::get_effective_config,
             config::save_weather_config,
             config::get_weather_config,
+            config::save_network_config,
+            config::get_network_config,
+            config::is_widget_enabled,
+            config::set_widget_enabled,
+            config::reorder_widgets,
+            config::add_widget,
+            config::remove_widget,
+            config::get_widget_settings,
+            config::set_widget_settings,
+            config::get_widget_colors,
+            config::set_widget_color,
             config::factory_reset,
             // Audio commands
             audio::get_audio_data,
@@ -139,8 +210,19 @@ pub fn run() {
             audio::toggle_mute,
             audio::set_device_volume,
             audio::set_default_audio_device,
+            // Clipboard history commands
+            clipboard::get_clipboard_history,
+            clipboard::copy_history_item,
+            clipboard::clear_clipboard_history,
             // Headset commands
             headset::get_headset_data,
+            headset::set_headset_equalizer,
+            headset::set_headset_sidetone,
+            headset::set_headset_surround,
+            headset::toggle_headset_mic,
+            headset::set_headset_color,
+            headset::set_headset_lighting_effect,
+            headset::debug_headset_devices,
             headset::check_icue_sdk,
             headset::install_icue_sdk,
             headset::get_icue_setup_instructions,
@@ -149,11 +231,20 @@ pub fn run() {
             media::media_play_pause,
             media::media_next,
             media::media_previous,
+            media::send_media_key,
             media::media_seek,
+            media::media_skip,
+            media::media_toggle_shuffle,
+            media::media_set_repeat,
+            media::get_media_artwork,
             // Weather commands
             weather::get_weather,
+            weather::refresh_weather,
+            weather::get_air_quality,
             weather::get_weather_icon_url,
             weather::get_current_location,
+            weather::get_effective_location,
+            weather::geocode_city,
             // Popup commands
             popup::open_storage_popup,
             popup::open_cpu_popup,
@@ -171,8 +262,12 @@ pub fn run() {
             popup::open_folders_popup,
             popup::open_dev_color_popup,
             popup::open_taskswitcher_popup,
+            popup::open_note_window,
+            popup::close_note_window,
             popup::close_storage_popup,
             popup::prewarm_popups,
+            popup::close_all_popups,
+            popup::get_popup_metrics,
             popup::set_popup_pinned,
             popup::get_popup_pinned,
             popup::set_folders_popup_cooldown,
@@ -182,29 +277,94 @@ pub fn run() {
             notes::create_note,
             notes::update_note,
             notes::delete_note,
+            notes::set_note_reminder,
+            notes::clear_note_reminder,
+            notes::render_note_markdown,
+            notes::list_note_backups,
+            notes::restore_note_backup,
+
+            // Timer commands
+            timer::start_timer,
+            timer::cancel_timer,
+            timer::list_timers,
 
             // Folders commands
             folders::get_folder_shortcuts,
             folders::save_folder_shortcuts,
             folders::add_folder_shortcut,
+            folders::add_shortcut_from_path,
             folders::remove_folder_shortcut,
             folders::update_folder_shortcut,
+            folders::find_duplicate_shortcuts,
+            folders::dedupe_folder_shortcuts,
             folders::open_folder,
             folders::verify_folder_path,
+            folders::get_folder_size,
+
+            // Quick launch commands
+            quick_launch::get_quick_launch,
+            quick_launch::add_quick_launch,
+            quick_launch::remove_quick_launch,
+            quick_launch::launch_quick_item,
 
             // Startup (Windows startup folder .bat)
             startup::startup_is_enabled,
             startup::startup_enable,
             startup::startup_disable,
+            startup::list_startup_programs,
+            startup::set_startup_program_enabled,
             startup::is_running_as_admin,
 
             // Windows/Task Switcher commands
             windows::get_window_list,
             windows::get_foreground_window,
             windows::focus_window,
+            windows::flash_window,
+            windows::toggle_show_desktop,
+            windows::move_window_to_monitor,
+            windows::snap_window,
+            windows::list_virtual_desktops,
+            windows::get_current_desktop,
+            windows::switch_virtual_desktop,
             windows::get_process_icon,
+            windows::clear_icon_cache,
+            windows::set_task_switcher_hotkey,
+            windows::get_default_task_switcher_hotkey,
         ])
         .setup(move |app| {
+            // Load persisted network usage before the WMI loop starts feeding samples
+            services::network_usage::init(app.handle());
+            if let Ok(profile) = config::get_active_profile() {
+                config::apply_active_config(&profile);
+                taskbar_state
+                    .auto_hide
+                    .store(profile.display.auto_hide, Ordering::SeqCst);
+                if let Some(accelerator) = &profile.task_switcher_hotkey {
+                    if let Err(e) = services::hotkey::register(app.handle().clone(), accelerator) {
+                        eprintln!("Task switcher hotkey not registered: {e}");
+                    }
+                }
+
+                #[cfg(windows)]
+                if let Some(window) = app.get_webview_window("main") {
+                    if let Ok(hwnd) = window.hwnd() {
+                        let _ = services::window_effects::set_blur(
+                            hwnd.0 as isize,
+                            profile.display.blur,
+                        );
+                    }
+                }
+            }
+
+            services::windows_update::start_watcher(app.handle().clone());
+            services::audio::start_mute_watcher(app.handle().clone());
+            services::session::start_watcher(app.handle().clone());
+            services::theme::start_watcher(app.handle().clone());
+            services::clipboard_history::start_watcher(app.handle().clone());
+            services::windows::start_event_watcher(app.handle().clone());
+            config::start_profile_watcher(app.handle().clone());
+            notes::start_reminder_watcher(app.handle().clone());
+
             // Setup system tray
             let show_item = MenuItem::with_id(app, "show", "Mostrar/Ocultar", true, None::<&str>)?;
             let quit_item = MenuItem::with_id(app, "quit", "Sair", true, None::<&str>)?;
@@ -234,6 +394,10 @@ pub fn run() {
                                     let _ = services::unregister_appbar(hwnd.0 as isize);
                                 }
                             }
+                            services::network_usage::flush();
+                            services::telemetry_log::stop();
+                            services::gamma::reset_on_exit();
+                            services::windows::stop_event_watcher();
                             let app_handle = app.clone();
                             tauri::async_runtime::spawn(async move {
                                 std::thread::sleep(Duration::from_millis(75));
@@ -276,7 +440,6 @@ pub fn run() {
 
                 let bar_height: i32 = 32; // Fixed height for the bar
                 let (screen_width, _) = services::get_primary_screen_size();
-                let verbose_logs_enabled = std::env::var_os("BAR_VERBOSE_LOGS").is_some();
                 if let Some(window) = app.get_webview_window("main") {
                     // Enforce fixed position at (0,0) to prevent movement
                     let win_clone = window.clone();
@@ -303,12 +466,12 @@ pub fn run() {
 
                     // Log actual window size after setting
                     if let Ok(size) = window.outer_size() {
-                        if verbose_logs_enabled {
+                        if services::logging::verbose_logs_enabled() {
                             eprintln!("Window actual size: {}x{}", size.width, size.height);
                         }
                     }
                     if let Ok(pos) = window.outer_position() {
-                        if verbose_logs_enabled {
+                        if services::logging::verbose_logs_enabled() {
                             eprintln!("Window actual position: ({}, {})", pos.x, pos.y);
                         }
                     }
@@ -356,12 +519,15 @@ pub fn run() {
                                 let was_hidden = state_for_watcher.fullscreen_hidden.load(Ordering::SeqCst);
                                 if is_fullscreen && !was_hidden {
                                     #[cfg(debug_assertions)]
-                                    if verbose_logs_enabled {
+                                    if services::logging::verbose_logs_enabled() {
                                         eprintln!("Auto-hide: fullscreen detected, hiding bar + unregistering AppBar");
                                     }
-                                    if let (Ok(pos), Ok(size)) = (watch_window.outer_position(), watch_window.outer_size()) {
-                                        if let Ok(mut bounds) = state_for_watcher.bounds.lock() {
-                                            *bounds = Some((pos.x, pos.y, size.width, size.height));
+                                    // While auto-hidden the window is collapsed; keep the full bounds.
+                                    if !state_for_watcher.auto_hidden.swap(false, Ordering::SeqCst) {
+                                        if let (Ok(pos), Ok(size)) = (watch_window.outer_position(), watch_window.outer_size()) {
+                                            if let Ok(mut bounds) = state_for_watcher.bounds.lock() {
+                                                *bounds = Some((pos.x, pos.y, size.width, size.height));
+                                            }
                                         }
                                     }
                                     state_for_watcher.fullscreen_hidden.store(true, Ordering::SeqCst);
@@ -369,7 +535,7 @@ pub fn run() {
                                     let _ = services::unregister_appbar(hwnd_val);
                                 } else if !is_fullscreen && was_hidden {
                                     #[cfg(debug_assertions)]
-                                    if verbose_logs_enabled {
+                                    if services::logging::verbose_logs_enabled() {
                                         eprintln!("Auto-show: leaving fullscreen, showing bar + registering AppBar");
                                     }
                                     state_for_watcher.fullscreen_hidden.store(false, Ordering::SeqCst);
@@ -396,12 +562,75 @@ pub fn run() {
                         }
                     });
                 }
+
+                // Auto-hide: collapse the bar when the cursor leaves it, reveal at the top edge
+                if let Some(window) = app.get_webview_window("main") {
+                    let state_for_auto_hide = taskbar_state.clone();
+                    let app_handle = app.handle().clone();
+                    std::thread::spawn(move || {
+                        let mut outside_since: Option<std::time::Instant> = None;
+                        loop {
+                            std::thread::sleep(Duration::from_millis(100));
+
+                            let state = &state_for_auto_hide;
+                            if !state.auto_hide.load(Ordering::SeqCst)
+                                || state.fullscreen_hidden.load(Ordering::SeqCst)
+                                || state.appbar_transition.load(Ordering::SeqCst)
+                            {
+                                outside_since = None;
+                                continue;
+                            }
+
+                            let Some((x, y, width, height)) = state.bounds.lock().ok().and_then(|b| *b) else {
+                                continue;
+                            };
+                            let Some((cx, cy)) = services::appbar::cursor_position() else {
+                                continue;
+                            };
+                            let visible = window.is_visible().unwrap_or(false);
+                            let within_x = cx >= x && cx < x + width as i32;
+
+                            if state.auto_hidden.load(Ordering::SeqCst) {
+                                if visible {
+                                    // Shown by something else (monitor switch, tray, fullscreen exit)
+                                    state.auto_hidden.store(false, Ordering::SeqCst);
+                                } else if within_x && cy <= y + 1 {
+                                    monitor::auto_hide_reveal(&window, state);
+                                }
+                                outside_since = None;
+                                continue;
+                            }
+
+                            // Hidden from the tray: leave it alone.
+                            if !visible {
+                                outside_since = None;
+                                continue;
+                            }
+
+                            let over_bar = within_x && cy >= y && cy < y + height as i32;
+                            let popup_open = app_handle
+                                .webview_windows()
+                                .iter()
+                                .any(|(label, w)| label != "main" && w.is_visible().unwrap_or(false));
+                            if over_bar || popup_open {
+                                outside_since = None;
+                                continue;
+                            }
+
+                            let since = *outside_since.get_or_insert_with(std::time::Instant::now);
+                            if since.elapsed() >= Duration::from_secs(1) {
+                                monitor::auto_hide_collapse(&window, state);
+                                outside_since = None;
+                            }
+                        }
+                    });
+                }
             }
             Ok(())
         })
         .on_window_event(|window, event| {
             #[cfg(debug_assertions)]
-            if std::env::var_os("BAR_VERBOSE_LOGS").is_some() {
+            if services::logging::verbose_logs_enabled() {
                 eprintln!("Window event: label={} event={:?}", window.label(), event);
             }
 
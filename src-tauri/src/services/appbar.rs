@@ -7,6 +7,11 @@ static APPBAR_REGISTERED: AtomicBool = AtomicBool::new(false);
 // SHAppBarMessage/ABM_* calls can be timing-sensitive and must not interleave across threads.
 static APPBAR_LOCK: Mutex<()> = Mutex::new(());
 
+/// Whether the bar is currently registered as an AppBar
+pub fn is_appbar_registered() -> bool {
+    APPBAR_REGISTERED.load(Ordering::SeqCst)
+}
+
 #[cfg(windows)]
 pub mod windows_appbar {
     use super::*;
@@ -28,7 +33,7 @@ pub mod windows_appbar {
     const APPBAR_CALLBACK: u32 = WM_USER + 1;
 
     fn verbose_logs_enabled() -> bool {
-        std::env::var_os("BAR_VERBOSE_LOGS").is_some()
+        crate::services::logging::verbose_logs_enabled()
     }
 
     /// Unregister helper that assumes APPBAR_LOCK is already held.
@@ -337,7 +342,7 @@ pub mod windows_appbar {
 
             ReleaseDC(HWND::default(), hdc);
 
-            if std::env::var_os("BAR_VERBOSE_LOGS").is_some() {
+            if crate::services::logging::verbose_logs_enabled() {
                 eprintln!(
                     "Screen: {}x{}, DPI: {}, Scale: {:.2}x",
                     width, height, dpi, scale
@@ -347,6 +352,63 @@ pub mod windows_appbar {
         }
     }
 
+    /// Physical rect `(x, y, width, height)` of the monitor containing a point.
+    ///
+    /// Unlike `get_primary_screen_size`, this is correct for secondary monitors with a
+    /// different scale factor.
+    pub fn get_monitor_size_for_point(x: i32, y: i32) -> Option<(i32, i32, i32, i32)> {
+        use windows::Win32::Foundation::POINT;
+        use windows::Win32::Graphics::Gdi::{MonitorFromPoint, MONITOR_DEFAULTTONULL};
+
+        unsafe {
+            let monitor = MonitorFromPoint(POINT { x, y }, MONITOR_DEFAULTTONULL);
+            if monitor.is_invalid() {
+                return None;
+            }
+            let mut info = MONITORINFO {
+                cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+                ..Default::default()
+            };
+            if !GetMonitorInfoW(monitor, &mut info).as_bool() {
+                return None;
+            }
+            let rc = info.rcMonitor;
+            Some((rc.left, rc.top, rc.right - rc.left, rc.bottom - rc.top))
+        }
+    }
+
+    /// Current cursor position in physical screen coordinates
+    pub fn cursor_position() -> Option<(i32, i32)> {
+        use windows::Win32::Foundation::POINT;
+        use windows::Win32::UI::WindowsAndMessaging::GetCursorPos;
+
+        let mut point = POINT::default();
+        unsafe { GetCursorPos(&mut point) }.ok()?;
+        Some((point.x, point.y))
+    }
+
+    /// Visibility and screen rect `(x, y, width, height)` of the native Windows taskbar
+    /// (`Shell_TrayWnd`, primary monitor). `None` if Explorer's taskbar isn't running.
+    pub fn native_taskbar_state() -> Option<(bool, (i32, i32, i32, i32))> {
+        use windows::core::w;
+        use windows::Win32::UI::WindowsAndMessaging::FindWindowW;
+
+        unsafe {
+            let hwnd = FindWindowW(w!("Shell_TrayWnd"), None).ok()?;
+            let mut rect = RECT::default();
+            GetWindowRect(hwnd, &mut rect).ok()?;
+            Some((
+                IsWindowVisible(hwnd).as_bool(),
+                (
+                    rect.left,
+                    rect.top,
+                    rect.right - rect.left,
+                    rect.bottom - rect.top,
+                ),
+            ))
+        }
+    }
+
     /// Check if the foreground window is occupying the full monitor area (fullscreen/borderless)
     /// AND is on the same monitor as the bar window.
     pub fn is_foreground_fullscreen(bar_hwnd: isize) -> bool {
@@ -497,6 +559,18 @@ pub mod windows_appbar {
     pub fn is_foreground_fullscreen(_bar_hwnd: isize) -> bool {
         false
     }
+
+    pub fn get_monitor_size_for_point(_x: i32, _y: i32) -> Option<(i32, i32, i32, i32)> {
+        None
+    }
+
+    pub fn cursor_position() -> Option<(i32, i32)> {
+        None
+    }
+
+    pub fn native_taskbar_state() -> Option<(bool, (i32, i32, i32, i32))> {
+        None
+    }
 }
 
 pub use windows_appbar::*;
@@ -1,72 +1,8 @@
 //! Audio service for Windows Core Audio API
 
 use serde::Serialize;
-use windows::{
-    core::{IUnknown, Interface, GUID, HRESULT, PCWSTR, PROPVARIANT},
-    Win32::{
-        Devices::FunctionDiscovery::PKEY_Device_FriendlyName,
-        Media::Audio::{
-            eCapture, eConsole, eRender, Endpoints::IAudioEndpointVolume, IMMDevice,
-            IMMDeviceCollection, IMMDeviceEnumerator, MMDeviceEnumerator, DEVICE_STATE_ACTIVE,
-        },
-        System::Com::{
-            CoCreateInstance, CoInitializeEx, CLSCTX_ALL, COINIT_MULTITHREADED, STGM_READ,
-        },
-        UI::Shell::PropertiesSystem::IPropertyStore,
-    },
-};
-
-// Windows Core Audio roles.
-// We set all roles so apps that query different roles update immediately.
-#[repr(i32)]
-#[allow(dead_code, non_camel_case_types)]
-enum ERole {
-    eConsole = 0,
-    eMultimedia = 1,
-    eCommunications = 2,
-}
-
-// Undocumented PolicyConfig interface used to change the default audio endpoint.
-// This is a common approach used by many Windows audio switchers.
-// Ref: IPolicyConfig / IPolicyConfigVista (varies by Windows version)
-#[repr(transparent)]
-#[derive(Clone, Debug)]
-struct IPolicyConfig(IUnknown);
-
-unsafe impl Interface for IPolicyConfig {
-    type Vtable = IPolicyConfig_Vtbl;
-    // IID for IPolicyConfig (commonly used)
-    const IID: GUID = GUID::from_u128(0xf8679f50_850a_41cf_9c72_430f290290c8);
-}
-
-#[repr(C)]
-#[allow(non_camel_case_types, non_snake_case)]
-struct IPolicyConfig_Vtbl {
-    pub base__: <IUnknown as Interface>::Vtable,
-
-    // The vtable has many methods; we only need SetDefaultEndpoint.
-    // To keep indices correct, we include placeholders up to the method.
-    // Signatures are HRESULT returning.
-    pub _unused0: unsafe extern "system" fn(*mut core::ffi::c_void) -> HRESULT,
-    pub _unused1: unsafe extern "system" fn(*mut core::ffi::c_void) -> HRESULT,
-    pub _unused2: unsafe extern "system" fn(*mut core::ffi::c_void) -> HRESULT,
-    pub _unused3: unsafe extern "system" fn(*mut core::ffi::c_void) -> HRESULT,
-    pub _unused4: unsafe extern "system" fn(*mut core::ffi::c_void) -> HRESULT,
-    pub _unused5: unsafe extern "system" fn(*mut core::ffi::c_void) -> HRESULT,
-    pub _unused6: unsafe extern "system" fn(*mut core::ffi::c_void) -> HRESULT,
-    pub _unused7: unsafe extern "system" fn(*mut core::ffi::c_void) -> HRESULT,
-    pub _unused8: unsafe extern "system" fn(*mut core::ffi::c_void) -> HRESULT,
-    pub _unused9: unsafe extern "system" fn(*mut core::ffi::c_void) -> HRESULT,
-
-    pub SetDefaultEndpoint: unsafe extern "system" fn(
-        this: *mut core::ffi::c_void,
-        device_id: PCWSTR,
-        role: ERole,
-    ) -> HRESULT,
-}
-
-// CLSID for PolicyConfigClient
-const CLSID_POLICY_CONFIG_CLIENT: GUID = GUID::from_u128(0x870af99c_171d_4f9e_af0d_e63df40c2bc9);
+use std::sync::atomic::{AtomicU8, Ordering};
+use tauri::{AppHandle, Emitter};
 
 #[derive(Serialize, Clone, Debug)]
 pub struct AudioDevice {
@@ -113,132 +49,171 @@ impl Default for AudioData {
     }
 }
 
-/// Get device friendly name from IMMDevice
-unsafe fn get_device_name(device: &IMMDevice) -> String {
-    let store: IPropertyStore = match device.OpenPropertyStore(STGM_READ) {
-        Ok(s) => s,
-        Err(_) => return "Unknown Device".to_string(),
-    };
+// Last mute state sent to the frontend: 0 = unknown, 1 = unmuted, 2 = muted.
+// Both the toggle_mute command and the endpoint callback report through here,
+// so a change made from the bar is only announced once.
+static LAST_MUTE_STATE: AtomicU8 = AtomicU8::new(0);
+
+/// Emit `audio-mute-changed` if `muted` differs from the last reported state
+pub fn emit_mute_changed(app: &AppHandle, muted: bool) {
+    let state = if muted { 2 } else { 1 };
+    if LAST_MUTE_STATE.swap(state, Ordering::SeqCst) != state {
+        let _ = app.emit("audio-mute-changed", muted);
+    }
+}
 
-    let prop: PROPVARIANT = match store.GetValue(&PKEY_Device_FriendlyName) {
-        Ok(p) => p,
-        Err(_) => return "Unknown Device".to_string(),
+#[cfg(windows)]
+mod windows_impl {
+    use super::*;
+    use std::sync::mpsc;
+    use windows::{
+        core::{implement, IUnknown, Interface, GUID, HRESULT, PCWSTR, PROPVARIANT},
+        Win32::{
+            Devices::FunctionDiscovery::PKEY_Device_FriendlyName,
+            Media::Audio::{
+                eCapture, eConsole, eRender, EDataFlow,
+                Endpoints::{
+                    IAudioEndpointVolume, IAudioEndpointVolumeCallback,
+                    IAudioEndpointVolumeCallback_Impl,
+                },
+                IMMDevice, IMMDeviceCollection, IMMDeviceEnumerator, IMMNotificationClient,
+                IMMNotificationClient_Impl, MMDeviceEnumerator, AUDIO_VOLUME_NOTIFICATION_DATA,
+                DEVICE_STATE, DEVICE_STATE_ACTIVE,
+            },
+            System::Com::{
+                CoCreateInstance, CoInitializeEx, CLSCTX_ALL, COINIT_MULTITHREADED, STGM_READ,
+            },
+            UI::Shell::PropertiesSystem::{IPropertyStore, PROPERTYKEY},
+        },
     };
 
-    // Convert PROPVARIANT to string - returns String directly via Display trait
-    let name = prop.to_string();
-    if name.is_empty() {
-        "Unknown Device".to_string()
-    } else {
-        name
+    // Windows Core Audio roles.
+    // We set all roles so apps that query different roles update immediately.
+    #[repr(i32)]
+    #[allow(dead_code, non_camel_case_types)]
+    enum ERole {
+        eConsole = 0,
+        eMultimedia = 1,
+        eCommunications = 2,
     }
-}
 
-/// Get device ID from IMMDevice
-unsafe fn get_device_id(device: &IMMDevice) -> String {
-    match device.GetId() {
-        Ok(id) => {
-            let pwstr = id.0;
-            if !pwstr.is_null() {
-                let len = (0..).take_while(|&i| *pwstr.offset(i) != 0).count();
-                let slice = std::slice::from_raw_parts(pwstr, len);
-                let result = String::from_utf16_lossy(slice);
-                windows::Win32::System::Com::CoTaskMemFree(Some(pwstr as *const _));
-                result
-            } else {
-                String::new()
-            }
-        }
-        Err(_) => String::new(),
+    // Undocumented PolicyConfig interface used to change the default audio endpoint.
+    // This is a common approach used by many Windows audio switchers.
+    // Ref: IPolicyConfig / IPolicyConfigVista (varies by Windows version)
+    #[repr(transparent)]
+    #[derive(Clone, Debug)]
+    struct IPolicyConfig(IUnknown);
+
+    unsafe impl Interface for IPolicyConfig {
+        type Vtable = IPolicyConfig_Vtbl;
+        // IID for IPolicyConfig (commonly used)
+        const IID: GUID = GUID::from_u128(0xf8679f50_850a_41cf_9c72_430f290290c8);
     }
-}
 
-/// Get volume endpoint from device
-unsafe fn get_volume_endpoint(device: &IMMDevice) -> Option<IAudioEndpointVolume> {
-    device
-        .Activate::<IAudioEndpointVolume>(CLSCTX_ALL, None)
-        .ok()
-}
+    #[repr(C)]
+    #[allow(non_camel_case_types, non_snake_case)]
+    struct IPolicyConfig_Vtbl {
+        pub base__: <IUnknown as Interface>::Vtable,
+
+        // The vtable has many methods; we only need SetDefaultEndpoint.
+        // To keep indices correct, we include placeholders up to the method.
+        // Signatures are HRESULT returning.
+        pub _unused0: unsafe extern "system" fn(*mut core::ffi::c_void) -> HRESULT,
+        pub _unused1: unsafe extern "system" fn(*mut core::ffi::c_void) -> HRESULT,
+        pub _unused2: unsafe extern "system" fn(*mut core::ffi::c_void) -> HRESULT,
+        pub _unused3: unsafe extern "system" fn(*mut core::ffi::c_void) -> HRESULT,
+        pub _unused4: unsafe extern "system" fn(*mut core::ffi::c_void) -> HRESULT,
+        pub _unused5: unsafe extern "system" fn(*mut core::ffi::c_void) -> HRESULT,
+        pub _unused6: unsafe extern "system" fn(*mut core::ffi::c_void) -> HRESULT,
+        pub _unused7: unsafe extern "system" fn(*mut core::ffi::c_void) -> HRESULT,
+        pub _unused8: unsafe extern "system" fn(*mut core::ffi::c_void) -> HRESULT,
+        pub _unused9: unsafe extern "system" fn(*mut core::ffi::c_void) -> HRESULT,
+
+        pub SetDefaultEndpoint: unsafe extern "system" fn(
+            this: *mut core::ffi::c_void,
+            device_id: PCWSTR,
+            role: ERole,
+        ) -> HRESULT,
+    }
 
-/// Get audio devices of a specific type
-unsafe fn get_devices_by_type(
-    enumerator: &IMMDeviceEnumerator,
-    data_flow: windows::Win32::Media::Audio::EDataFlow,
-    default_id: &Option<String>,
-    device_type: &str,
-) -> Vec<AudioDevice> {
-    let mut devices = Vec::new();
-
-    let collection: IMMDeviceCollection =
-        match enumerator.EnumAudioEndpoints(data_flow, DEVICE_STATE_ACTIVE) {
-            Ok(c) => c,
-            Err(_) => return devices,
+    // CLSID for PolicyConfigClient
+    const CLSID_POLICY_CONFIG_CLIENT: GUID =
+        GUID::from_u128(0x870af99c_171d_4f9e_af0d_e63df40c2bc9);
+
+    /// Get device friendly name from IMMDevice
+    unsafe fn get_device_name(device: &IMMDevice) -> String {
+        let store: IPropertyStore = match device.OpenPropertyStore(STGM_READ) {
+            Ok(s) => s,
+            Err(_) => return "Unknown Device".to_string(),
         };
 
-    let count = match collection.GetCount() {
-        Ok(c) => c,
-        Err(_) => return devices,
-    };
+        let prop: PROPVARIANT = match store.GetValue(&PKEY_Device_FriendlyName) {
+            Ok(p) => p,
+            Err(_) => return "Unknown Device".to_string(),
+        };
 
-    for i in 0..count {
-        if let Ok(device) = collection.Item(i) {
-            let id = get_device_id(&device);
-            let name = get_device_name(&device);
-            let is_default = default_id.as_ref().map_or(false, |d| d == &id);
-
-            let (volume, is_muted) = if let Some(endpoint) = get_volume_endpoint(&device) {
-                let vol = endpoint.GetMasterVolumeLevelScalar().unwrap_or(1.0);
-                let muted = endpoint
-                    .GetMute()
-                    .unwrap_or(windows::Win32::Foundation::FALSE)
-                    .as_bool();
-                ((vol * 100.0) as u32, muted)
-            } else {
-                (100, false)
-            };
+        // Convert PROPVARIANT to string - returns String directly via Display trait
+        let name = prop.to_string();
+        if name.is_empty() {
+            "Unknown Device".to_string()
+        } else {
+            name
+        }
+    }
 
-            devices.push(AudioDevice {
-                id,
-                name,
-                is_default,
-                volume,
-                is_muted,
-                device_type: device_type.to_string(),
-            });
+    /// Get device ID from IMMDevice
+    unsafe fn get_device_id(device: &IMMDevice) -> String {
+        match device.GetId() {
+            Ok(id) => {
+                let pwstr = id.0;
+                if !pwstr.is_null() {
+                    let len = (0..).take_while(|&i| *pwstr.offset(i) != 0).count();
+                    let slice = std::slice::from_raw_parts(pwstr, len);
+                    let result = String::from_utf16_lossy(slice);
+                    windows::Win32::System::Com::CoTaskMemFree(Some(pwstr as *const _));
+                    result
+                } else {
+                    String::new()
+                }
+            }
+            Err(_) => String::new(),
         }
     }
 
-    devices
-}
+    /// Get volume endpoint from device
+    unsafe fn get_volume_endpoint(device: &IMMDevice) -> Option<IAudioEndpointVolume> {
+        device
+            .Activate::<IAudioEndpointVolume>(CLSCTX_ALL, None)
+            .ok()
+    }
 
-/// Get all audio data
-pub fn get_audio_data() -> AudioData {
-    unsafe {
-        // Initialize COM
-        let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
-
-        let enumerator: IMMDeviceEnumerator =
-            match CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL) {
-                Ok(e) => e,
-                Err(_) => return AudioData::default(),
+    /// Get audio devices of a specific type
+    unsafe fn get_devices_by_type(
+        enumerator: &IMMDeviceEnumerator,
+        data_flow: windows::Win32::Media::Audio::EDataFlow,
+        default_id: &Option<String>,
+        device_type: &str,
+    ) -> Vec<AudioDevice> {
+        let mut devices = Vec::new();
+
+        let collection: IMMDeviceCollection =
+            match enumerator.EnumAudioEndpoints(data_flow, DEVICE_STATE_ACTIVE) {
+                Ok(c) => c,
+                Err(_) => return devices,
             };
 
-        // Get default output device ID
-        let default_output_id = enumerator
-            .GetDefaultAudioEndpoint(eRender, eConsole)
-            .ok()
-            .map(|d| get_device_id(&d));
+        let count = match collection.GetCount() {
+            Ok(c) => c,
+            Err(_) => return devices,
+        };
 
-        // Get default input device ID
-        let default_input_id = enumerator
-            .GetDefaultAudioEndpoint(eCapture, eConsole)
-            .ok()
-            .map(|d| get_device_id(&d));
+        for i in 0..count {
+            if let Ok(device) = collection.Item(i) {
+                let id = get_device_id(&device);
+                let name = get_device_name(&device);
+                let is_default = default_id.as_ref().map_or(false, |d| d == &id);
 
-        // Get master volume from default output
-        let (master_volume, is_muted) =
-            if let Ok(default_device) = enumerator.GetDefaultAudioEndpoint(eRender, eConsole) {
-                if let Some(endpoint) = get_volume_endpoint(&default_device) {
+                let (volume, is_muted) = if let Some(endpoint) = get_volume_endpoint(&device) {
                     let vol = endpoint.GetMasterVolumeLevelScalar().unwrap_or(1.0);
                     let muted = endpoint
                         .GetMute()
@@ -247,144 +222,359 @@ pub fn get_audio_data() -> AudioData {
                     ((vol * 100.0) as u32, muted)
                 } else {
                     (100, false)
-                }
-            } else {
-                (100, false)
-            };
+                };
+
+                devices.push(AudioDevice {
+                    id,
+                    name,
+                    is_default,
+                    volume,
+                    is_muted,
+                    device_type: device_type.to_string(),
+                });
+            }
+        }
 
-        // Get all output devices
-        let output_devices =
-            get_devices_by_type(&enumerator, eRender, &default_output_id, "output");
+        devices
+    }
+
+    /// Get all audio data
+    pub fn get_audio_data() -> AudioData {
+        unsafe {
+            // Initialize COM
+            let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+
+            let enumerator: IMMDeviceEnumerator =
+                match CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL) {
+                    Ok(e) => e,
+                    Err(_) => return AudioData::default(),
+                };
+
+            // Get default output device ID
+            let default_output_id = enumerator
+                .GetDefaultAudioEndpoint(eRender, eConsole)
+                .ok()
+                .map(|d| get_device_id(&d));
+
+            // Get default input device ID
+            let default_input_id = enumerator
+                .GetDefaultAudioEndpoint(eCapture, eConsole)
+                .ok()
+                .map(|d| get_device_id(&d));
+
+            // Get master volume from default output
+            let (master_volume, is_muted) =
+                if let Ok(default_device) = enumerator.GetDefaultAudioEndpoint(eRender, eConsole) {
+                    if let Some(endpoint) = get_volume_endpoint(&default_device) {
+                        let vol = endpoint.GetMasterVolumeLevelScalar().unwrap_or(1.0);
+                        let muted = endpoint
+                            .GetMute()
+                            .unwrap_or(windows::Win32::Foundation::FALSE)
+                            .as_bool();
+                        ((vol * 100.0) as u32, muted)
+                    } else {
+                        (100, false)
+                    }
+                } else {
+                    (100, false)
+                };
+
+            // Get all output devices
+            let output_devices =
+                get_devices_by_type(&enumerator, eRender, &default_output_id, "output");
+
+            // Get all input devices
+            let input_devices =
+                get_devices_by_type(&enumerator, eCapture, &default_input_id, "input");
+
+            AudioData {
+                output_devices,
+                input_devices,
+                default_output_id,
+                default_input_id,
+                master_volume,
+                is_muted,
+            }
+        }
+    }
+
+    /// Set the master volume (0-100)
+    pub fn set_master_volume(volume: u32) -> Result<(), String> {
+        unsafe {
+            let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+
+            let enumerator: IMMDeviceEnumerator =
+                CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL)
+                    .map_err(|e| e.to_string())?;
+
+            let device = enumerator
+                .GetDefaultAudioEndpoint(eRender, eConsole)
+                .map_err(|e| e.to_string())?;
 
-        // Get all input devices
-        let input_devices = get_devices_by_type(&enumerator, eCapture, &default_input_id, "input");
+            let endpoint: IAudioEndpointVolume = device
+                .Activate(CLSCTX_ALL, None)
+                .map_err(|e| e.to_string())?;
 
-        AudioData {
-            output_devices,
-            input_devices,
-            default_output_id,
-            default_input_id,
-            master_volume,
-            is_muted,
+            let level = (volume.min(100) as f32) / 100.0;
+            endpoint
+                .SetMasterVolumeLevelScalar(level, std::ptr::null())
+                .map_err(|e| e.to_string())?;
+
+            Ok(())
         }
     }
-}
 
-/// Set the master volume (0-100)
-pub fn set_master_volume(volume: u32) -> Result<(), String> {
-    unsafe {
-        let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+    /// Toggle mute on master volume
+    pub fn toggle_mute() -> Result<bool, String> {
+        toggle_endpoint_mute(eRender)
+    }
 
-        let enumerator: IMMDeviceEnumerator =
-            CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL).map_err(|e| e.to_string())?;
+    /// Toggle mute on the default capture device (microphone)
+    pub fn toggle_capture_mute() -> Result<bool, String> {
+        toggle_endpoint_mute(eCapture)
+    }
 
-        let device = enumerator
-            .GetDefaultAudioEndpoint(eRender, eConsole)
-            .map_err(|e| e.to_string())?;
+    fn toggle_endpoint_mute(flow: EDataFlow) -> Result<bool, String> {
+        unsafe {
+            let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
 
-        let endpoint: IAudioEndpointVolume = device
-            .Activate(CLSCTX_ALL, None)
-            .map_err(|e| e.to_string())?;
+            let enumerator: IMMDeviceEnumerator =
+                CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL)
+                    .map_err(|e| e.to_string())?;
 
-        let level = (volume.min(100) as f32) / 100.0;
-        endpoint
-            .SetMasterVolumeLevelScalar(level, std::ptr::null())
-            .map_err(|e| e.to_string())?;
+            let device = enumerator
+                .GetDefaultAudioEndpoint(flow, eConsole)
+                .map_err(|e| e.to_string())?;
 
-        Ok(())
+            let endpoint: IAudioEndpointVolume = device
+                .Activate(CLSCTX_ALL, None)
+                .map_err(|e| e.to_string())?;
+
+            let current_mute = endpoint.GetMute().map_err(|e| e.to_string())?.as_bool();
+
+            let new_mute = !current_mute;
+            endpoint
+                .SetMute(new_mute, std::ptr::null())
+                .map_err(|e| e.to_string())?;
+
+            Ok(new_mute)
+        }
     }
-}
 
-/// Toggle mute on master volume
-pub fn toggle_mute() -> Result<bool, String> {
-    unsafe {
-        let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+    /// Set volume for a specific device
+    pub fn set_device_volume(device_id: &str, volume: u32) -> Result<(), String> {
+        unsafe {
+            let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
 
-        let enumerator: IMMDeviceEnumerator =
-            CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL).map_err(|e| e.to_string())?;
+            let enumerator: IMMDeviceEnumerator =
+                CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL)
+                    .map_err(|e| e.to_string())?;
 
-        let device = enumerator
-            .GetDefaultAudioEndpoint(eRender, eConsole)
-            .map_err(|e| e.to_string())?;
+            // Convert device_id to wide string
+            let wide_id: Vec<u16> = device_id.encode_utf16().chain(std::iter::once(0)).collect();
 
-        let endpoint: IAudioEndpointVolume = device
-            .Activate(CLSCTX_ALL, None)
-            .map_err(|e| e.to_string())?;
+            let device = enumerator
+                .GetDevice(PCWSTR::from_raw(wide_id.as_ptr()))
+                .map_err(|e| e.to_string())?;
 
-        let current_mute = endpoint.GetMute().map_err(|e| e.to_string())?.as_bool();
+            let endpoint: IAudioEndpointVolume = device
+                .Activate(CLSCTX_ALL, None)
+                .map_err(|e| e.to_string())?;
 
-        let new_mute = !current_mute;
-        endpoint
-            .SetMute(new_mute, std::ptr::null())
+            let level = (volume.min(100) as f32) / 100.0;
+            endpoint
+                .SetMasterVolumeLevelScalar(level, std::ptr::null())
+                .map_err(|e| e.to_string())?;
+
+            Ok(())
+        }
+    }
+
+    /// Set the default output or input device (Windows default audio endpoint)
+    pub fn set_default_device(device_id: &str) -> Result<(), String> {
+        unsafe {
+            let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+
+            // Convert device_id to wide string
+            let wide_id: Vec<u16> = device_id.encode_utf16().chain(std::iter::once(0)).collect();
+            let device_pwstr = PCWSTR::from_raw(wide_id.as_ptr());
+
+            let policy: IPolicyConfig =
+                CoCreateInstance(&CLSID_POLICY_CONFIG_CLIENT, None, CLSCTX_ALL)
+                    .map_err(|e| e.to_string())?;
+
+            // Apply for all roles.
+            (policy.vtable().SetDefaultEndpoint)(
+                policy.as_raw() as *mut _,
+                device_pwstr,
+                ERole::eConsole,
+            )
+            .ok()
+            .map_err(|e| e.to_string())?;
+            (policy.vtable().SetDefaultEndpoint)(
+                policy.as_raw() as *mut _,
+                device_pwstr,
+                ERole::eMultimedia,
+            )
+            .ok()
+            .map_err(|e| e.to_string())?;
+            (policy.vtable().SetDefaultEndpoint)(
+                policy.as_raw() as *mut _,
+                device_pwstr,
+                ERole::eCommunications,
+            )
+            .ok()
             .map_err(|e| e.to_string())?;
 
-        Ok(new_mute)
+            Ok(())
+        }
     }
-}
 
-/// Set volume for a specific device
-pub fn set_device_volume(device_id: &str, volume: u32) -> Result<(), String> {
-    unsafe {
-        let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+    /// Receives volume/mute changes of the default output endpoint (including the keyboard mute key)
+    #[implement(IAudioEndpointVolumeCallback)]
+    struct MuteCallback {
+        app: AppHandle,
+    }
 
-        let enumerator: IMMDeviceEnumerator =
-            CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL).map_err(|e| e.to_string())?;
+    impl IAudioEndpointVolumeCallback_Impl for MuteCallback_Impl {
+        fn OnNotify(
+            &self,
+            pnotify: *mut AUDIO_VOLUME_NOTIFICATION_DATA,
+        ) -> windows::core::Result<()> {
+            if let Some(data) = unsafe { pnotify.as_ref() } {
+                emit_mute_changed(&self.app, data.bMuted.as_bool());
+            }
+            Ok(())
+        }
+    }
 
-        // Convert device_id to wide string
-        let wide_id: Vec<u16> = device_id.encode_utf16().chain(std::iter::once(0)).collect();
+    /// Signals the watcher thread when the default output device changes
+    #[implement(IMMNotificationClient)]
+    struct DefaultDeviceClient {
+        changed: mpsc::Sender<()>,
+    }
 
-        let device = enumerator
-            .GetDevice(PCWSTR::from_raw(wide_id.as_ptr()))
-            .map_err(|e| e.to_string())?;
+    impl IMMNotificationClient_Impl for DefaultDeviceClient_Impl {
+        fn OnDeviceStateChanged(&self, _: &PCWSTR, _: DEVICE_STATE) -> windows::core::Result<()> {
+            Ok(())
+        }
 
-        let endpoint: IAudioEndpointVolume = device
-            .Activate(CLSCTX_ALL, None)
-            .map_err(|e| e.to_string())?;
+        fn OnDeviceAdded(&self, _: &PCWSTR) -> windows::core::Result<()> {
+            Ok(())
+        }
 
-        let level = (volume.min(100) as f32) / 100.0;
-        endpoint
-            .SetMasterVolumeLevelScalar(level, std::ptr::null())
-            .map_err(|e| e.to_string())?;
+        fn OnDeviceRemoved(&self, _: &PCWSTR) -> windows::core::Result<()> {
+            Ok(())
+        }
+
+        fn OnDefaultDeviceChanged(
+            &self,
+            flow: EDataFlow,
+            role: windows::Win32::Media::Audio::ERole,
+            _: &PCWSTR,
+        ) -> windows::core::Result<()> {
+            // Re-registering from inside this callback is not allowed; hand it to the watcher thread.
+            if flow == eRender && role == eConsole {
+                let _ = self.changed.send(());
+            }
+            Ok(())
+        }
 
-        Ok(())
+        fn OnPropertyValueChanged(&self, _: &PCWSTR, _: &PROPERTYKEY) -> windows::core::Result<()> {
+            Ok(())
+        }
     }
-}
 
-/// Set the default output or input device (Windows default audio endpoint)
-pub fn set_default_device(device_id: &str) -> Result<(), String> {
-    unsafe {
-        let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+    /// Keep the bar's mute indicator in sync with mute changes made outside the app
+    /// (keyboard mute key, Windows volume flyout, other apps).
+    pub fn start_mute_watcher(app: AppHandle) {
+        std::thread::Builder::new()
+            .name("audio-mute-watcher".to_string())
+            .spawn(move || unsafe {
+                let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+
+                let enumerator: IMMDeviceEnumerator =
+                    match CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL) {
+                        Ok(e) => e,
+                        Err(e) => {
+                            eprintln!("Failed to create audio device enumerator: {e}");
+                            return;
+                        }
+                    };
+
+                let (tx, rx) = mpsc::channel();
+                let client: IMMNotificationClient = DefaultDeviceClient { changed: tx }.into();
+                if let Err(e) = enumerator.RegisterEndpointNotificationCallback(&client) {
+                    eprintln!("Failed to register audio endpoint notifications: {e}");
+                }
 
-        // Convert device_id to wide string
-        let wide_id: Vec<u16> = device_id.encode_utf16().chain(std::iter::once(0)).collect();
-        let device_pwstr = PCWSTR::from_raw(wide_id.as_ptr());
+                let callback: IAudioEndpointVolumeCallback =
+                    MuteCallback { app: app.clone() }.into();
+
+                loop {
+                    let endpoint = enumerator
+                        .GetDefaultAudioEndpoint(eRender, eConsole)
+                        .ok()
+                        .and_then(|device| get_volume_endpoint(&device));
+
+                    if let Some(endpoint) = endpoint.as_ref() {
+                        // A new default device may have a different mute state.
+                        if let Ok(muted) = endpoint.GetMute() {
+                            emit_mute_changed(&app, muted.as_bool());
+                        }
+                        let _ = endpoint.RegisterControlChangeNotify(&callback);
+                    }
+
+                    // Block until the default output device changes.
+                    if rx.recv().is_err() {
+                        break;
+                    }
+
+                    if let Some(endpoint) = endpoint.as_ref() {
+                        let _ = endpoint.UnregisterControlChangeNotify(&callback);
+                    }
+                }
+            })
+            .ok();
+    }
+}
 
-        let policy: IPolicyConfig = CoCreateInstance(&CLSID_POLICY_CONFIG_CLIENT, None, CLSCTX_ALL)
-            .map_err(|e| e.to_string())?;
+#[cfg(windows)]
+pub use windows_impl::*;
 
-        // Apply for all roles.
-        (policy.vtable().SetDefaultEndpoint)(
-            policy.as_raw() as *mut _,
-            device_pwstr,
-            ERole::eConsole,
-        )
-        .ok()
-        .map_err(|e| e.to_string())?;
-        (policy.vtable().SetDefaultEndpoint)(
-            policy.as_raw() as *mut _,
-            device_pwstr,
-            ERole::eMultimedia,
-        )
-        .ok()
-        .map_err(|e| e.to_string())?;
-        (policy.vtable().SetDefaultEndpoint)(
-            policy.as_raw() as *mut _,
-            device_pwstr,
-            ERole::eCommunications,
-        )
-        .ok()
-        .map_err(|e| e.to_string())?;
-
-        Ok(())
+// Non-Windows fallback
+#[cfg(not(windows))]
+pub fn get_audio_data() -> AudioData {
+    if super::demo::is_enabled() {
+        return super::demo::audio_data();
     }
+    AudioData::default()
+}
+
+#[cfg(not(windows))]
+pub fn set_master_volume(_volume: u32) -> Result<(), String> {
+    Err("Not supported on this platform".to_string())
+}
+
+#[cfg(not(windows))]
+pub fn toggle_mute() -> Result<bool, String> {
+    Err("Not supported on this platform".to_string())
 }
+
+#[cfg(not(windows))]
+pub fn toggle_capture_mute() -> Result<bool, String> {
+    Err("Not supported on this platform".to_string())
+}
+
+#[cfg(not(windows))]
+pub fn set_device_volume(_device_id: &str, _volume: u32) -> Result<(), String> {
+    Err("Not supported on this platform".to_string())
+}
+
+#[cfg(not(windows))]
+pub fn set_default_device(_device_id: &str) -> Result<(), String> {
+    Err("Not supported on this platform".to_string())
+}
+
+#[cfg(not(windows))]
+pub fn start_mute_watcher(_app: AppHandle) {}
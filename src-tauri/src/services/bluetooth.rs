@@ -0,0 +1,125 @@
+//! Paired Bluetooth devices and their battery levels
+//!
+//! Devices come from the paired association endpoints (classic and LE). Battery levels
+//! are not exposed on the endpoint itself: Windows publishes them on the device's PnP
+//! nodes (`DEVPKEY_Bluetooth_Battery`), which are matched back through the container id.
+
+use serde::Serialize;
+
+#[derive(Serialize, Clone, Debug)]
+pub struct BtDevice {
+    pub name: String,
+    pub connected: bool,
+    /// `None` when the device doesn't report a battery level to Windows
+    pub battery_percent: Option<u8>,
+}
+
+#[cfg(windows)]
+mod windows_impl {
+    use super::BtDevice;
+    use std::collections::HashMap;
+    use windows::core::{IInspectable, Interface, GUID, HSTRING};
+    use windows::Devices::Enumeration::{DeviceInformation, DeviceInformationKind};
+    use windows::Foundation::Collections::{IIterable, IMapView};
+    use windows::Foundation::IReference;
+
+    /// Paired Bluetooth classic or Bluetooth LE endpoints
+    const PAIRED_BLUETOOTH_AQS: &str = "(System.Devices.Aep.ProtocolId:=\"{e0cbf06c-cd8b-4647-bb8a-263b43f0f974}\" OR System.Devices.Aep.ProtocolId:=\"{bb7bb05e-5972-42b5-94fc-76eaa7084d49}\") AND System.Devices.Aep.IsPaired:=System.StructuredQueryType.Boolean#True";
+    const PRESENT_DEVICES_AQS: &str =
+        "System.Devices.Present:=System.StructuredQueryType.Boolean#True";
+
+    const IS_CONNECTED: &str = "System.Devices.Aep.IsConnected";
+    const AEP_CONTAINER_ID: &str = "System.Devices.Aep.ContainerId";
+    const CONTAINER_ID: &str = "System.Devices.ContainerId";
+    /// DEVPKEY_Bluetooth_Battery
+    const BATTERY: &str = "{104EA319-6EE2-4701-BD47-8DDBF425BBE5} 2";
+
+    fn property<T>(props: &IMapView<HSTRING, IInspectable>, key: &str) -> Option<T>
+    where
+        T: windows::core::RuntimeType + 'static,
+        IReference<T>: Interface,
+    {
+        props
+            .Lookup(&HSTRING::from(key))
+            .ok()?
+            .cast::<IReference<T>>()
+            .ok()?
+            .Value()
+            .ok()
+    }
+
+    fn find_all(
+        aqs: &str,
+        properties: &[&str],
+        kind: DeviceInformationKind,
+    ) -> windows::core::Result<Vec<DeviceInformation>> {
+        let properties: Vec<HSTRING> = properties.iter().map(|p| HSTRING::from(*p)).collect();
+        let properties = IIterable::<HSTRING>::try_from(properties)?;
+        let collection = DeviceInformation::FindAllAsyncWithKindAqsFilterAndAdditionalProperties(
+            &HSTRING::from(aqs),
+            &properties,
+            kind,
+        )?
+        .get()?;
+        Ok(collection.into_iter().collect())
+    }
+
+    /// Battery level per device container, from the PnP nodes that report one
+    fn battery_by_container() -> HashMap<GUID, u8> {
+        let Ok(devices) = find_all(
+            PRESENT_DEVICES_AQS,
+            &[CONTAINER_ID, BATTERY],
+            DeviceInformationKind::Device,
+        ) else {
+            return HashMap::new();
+        };
+
+        devices
+            .iter()
+            .filter_map(|device| {
+                let props = device.Properties().ok()?;
+                let battery = property::<u8>(&props, BATTERY)?;
+                let container = property::<GUID>(&props, CONTAINER_ID)?;
+                Some((container, battery.min(100)))
+            })
+            .collect()
+    }
+
+    pub fn get_bluetooth_devices() -> Result<Vec<BtDevice>, String> {
+        let endpoints = find_all(
+            PAIRED_BLUETOOTH_AQS,
+            &[IS_CONNECTED, AEP_CONTAINER_ID],
+            DeviceInformationKind::AssociationEndpoint,
+        )
+        .map_err(|e| format!("Failed to enumerate Bluetooth devices: {e}"))?;
+
+        let batteries = battery_by_container();
+
+        let mut devices: Vec<BtDevice> = endpoints
+            .iter()
+            .filter_map(|endpoint| {
+                let name = endpoint.Name().ok()?.to_string();
+                let props = endpoint.Properties().ok()?;
+                let battery_percent = property::<GUID>(&props, AEP_CONTAINER_ID)
+                    .and_then(|container| batteries.get(&container).copied());
+                Some(BtDevice {
+                    name,
+                    connected: property::<bool>(&props, IS_CONNECTED).unwrap_or(false),
+                    battery_percent,
+                })
+            })
+            .collect();
+
+        devices.sort_by(|a, b| b.connected.cmp(&a.connected).then(a.name.cmp(&b.name)));
+        Ok(devices)
+    }
+}
+
+#[cfg(windows)]
+pub use windows_impl::get_bluetooth_devices;
+
+// Non-Windows fallback
+#[cfg(not(windows))]
+pub fn get_bluetooth_devices() -> Result<Vec<BtDevice>, String> {
+    Err("Bluetooth devices are only supported on Windows".to_string())
+}
@@ -0,0 +1,143 @@
+//! Recent clipboard text entries
+//!
+//! A background thread polls the clipboard sequence number (cheap, no clipboard open) and
+//! reads the text only when it changes. The history is bounded both in entry count and in
+//! per-entry length so a huge copy can't bloat memory.
+
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+use tauri::AppHandle;
+use tauri_plugin_clipboard_manager::ClipboardExt;
+
+/// Maximum number of entries kept
+const MAX_ENTRIES: usize = 25;
+/// Longer copies are truncated to this many characters
+const MAX_ENTRY_CHARS: usize = 4_096;
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Serialize, Clone, Debug)]
+pub struct ClipboardEntry {
+    pub text: String,
+    /// RFC 3339 timestamp of when the text was copied
+    pub copied_at: String,
+    /// Whether `text` was cut at `MAX_ENTRY_CHARS`
+    pub truncated: bool,
+}
+
+static HISTORY: OnceLock<Mutex<VecDeque<ClipboardEntry>>> = OnceLock::new();
+static APP_HANDLE: OnceLock<AppHandle> = OnceLock::new();
+
+fn history() -> &'static Mutex<VecDeque<ClipboardEntry>> {
+    HISTORY.get_or_init(|| Mutex::new(VecDeque::with_capacity(MAX_ENTRIES)))
+}
+
+/// Changes every time the clipboard content changes (None where unavailable).
+#[cfg(windows)]
+fn clipboard_sequence() -> Option<u32> {
+    use windows::Win32::System::DataExchange::GetClipboardSequenceNumber;
+    match unsafe { GetClipboardSequenceNumber() } {
+        0 => None,
+        seq => Some(seq),
+    }
+}
+
+#[cfg(not(windows))]
+fn clipboard_sequence() -> Option<u32> {
+    None
+}
+
+/// Add `text` as the newest entry (moving it up if it is already in the history).
+fn record(text: String) {
+    if text.trim().is_empty() {
+        return;
+    }
+
+    let truncated = text.chars().count() > MAX_ENTRY_CHARS;
+    let text = if truncated {
+        text.chars().take(MAX_ENTRY_CHARS).collect()
+    } else {
+        text
+    };
+
+    if let Ok(mut entries) = history().lock() {
+        if entries.front().is_some_and(|e| e.text == text) {
+            return;
+        }
+        entries.retain(|e| e.text != text);
+        entries.push_front(ClipboardEntry {
+            text,
+            copied_at: chrono::Utc::now().to_rfc3339(),
+            truncated,
+        });
+        entries.truncate(MAX_ENTRIES);
+    }
+}
+
+/// Start watching the clipboard. Safe to call more than once.
+pub fn start_watcher(app: AppHandle) {
+    if APP_HANDLE.set(app.clone()).is_err() {
+        return;
+    }
+
+    std::thread::Builder::new()
+        .name("clipboard-history".to_string())
+        .spawn(move || {
+            // Don't record whatever was on the clipboard before the app started.
+            let mut last_seq = clipboard_sequence();
+            let mut last_text = app.clipboard().read_text().ok();
+
+            loop {
+                super::session::sleep_poll(POLL_INTERVAL);
+
+                let seq = clipboard_sequence();
+                if seq.is_some() && seq == last_seq {
+                    continue;
+                }
+                last_seq = seq;
+
+                // Non-text content (images, files) has no text; keep the last known text.
+                let Ok(text) = app.clipboard().read_text() else {
+                    continue;
+                };
+                if last_text.as_deref() == Some(text.as_str()) {
+                    continue;
+                }
+                last_text = Some(text.clone());
+                record(text);
+            }
+        })
+        .ok();
+}
+
+/// Recent entries, newest first
+pub fn get_history() -> Vec<ClipboardEntry> {
+    history()
+        .lock()
+        .map(|entries| entries.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Put a history entry back on the clipboard; it becomes the newest entry.
+pub fn copy_item(index: usize) -> Result<(), String> {
+    let app = APP_HANDLE.get().ok_or("Clipboard history is not running")?;
+    let text = history()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .get(index)
+        .map(|e| e.text.clone())
+        .ok_or("Clipboard history entry not found")?;
+
+    app.clipboard()
+        .write_text(text.clone())
+        .map_err(|e| format!("Failed to set clipboard: {e}"))?;
+    record(text);
+    Ok(())
+}
+
+pub fn clear_history() {
+    if let Ok(mut entries) = history().lock() {
+        entries.clear();
+    }
+}
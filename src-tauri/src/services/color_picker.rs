@@ -0,0 +1,184 @@
+//! Screen color sampling for the dev color picker
+//!
+//! `pick_color` reads the pixel under the cursor once. `run_eyedropper` installs a
+//! low-level mouse hook that emits `color-sampled` while the cursor moves and stops on
+//! the first click (left picks, right cancels) or after a timeout.
+
+use serde::Serialize;
+
+#[derive(Serialize, Clone, Debug)]
+pub struct ScreenColor {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    /// "#rrggbb"
+    pub hex: String,
+}
+
+impl ScreenColor {
+    #[cfg_attr(not(windows), allow(dead_code))]
+    fn new(r: u8, g: u8, b: u8) -> Self {
+        Self {
+            r,
+            g,
+            b,
+            hex: format!("#{r:02x}{g:02x}{b:02x}"),
+        }
+    }
+}
+
+#[cfg(windows)]
+mod windows_impl {
+    use super::ScreenColor;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Mutex;
+    use std::time::{Duration, Instant};
+    use tauri::{AppHandle, Emitter};
+    use windows::Win32::Foundation::{LPARAM, LRESULT, POINT, WPARAM};
+    use windows::Win32::Graphics::Gdi::{GetDC, GetPixel, ReleaseDC, CLR_INVALID};
+    use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+    use windows::Win32::UI::WindowsAndMessaging::{
+        CallNextHookEx, DispatchMessageW, GetCursorPos, GetMessageW, KillTimer, PostQuitMessage,
+        SetTimer, SetWindowsHookExW, TranslateMessage, UnhookWindowsHookEx, HHOOK, MSG,
+        MSLLHOOKSTRUCT, WH_MOUSE_LL, WM_LBUTTONDOWN, WM_MOUSEMOVE, WM_RBUTTONDOWN, WM_TIMER,
+    };
+
+    /// Give up if the user never clicks
+    const EYEDROPPER_TIMEOUT: Duration = Duration::from_secs(30);
+    /// Minimum time between two `color-sampled` events (GetPixel on the screen DC is slow)
+    const SAMPLE_INTERVAL: Duration = Duration::from_millis(30);
+
+    static ACTIVE: AtomicBool = AtomicBool::new(false);
+    static SESSION: Mutex<Option<Eyedropper>> = Mutex::new(None);
+
+    struct Eyedropper {
+        app: AppHandle,
+        last_sample: Option<Instant>,
+        picked: Option<ScreenColor>,
+    }
+
+    pub fn sample_at(x: i32, y: i32) -> Result<ScreenColor, String> {
+        unsafe {
+            let dc = GetDC(None);
+            if dc.is_invalid() {
+                return Err("Failed to get the screen device context".to_string());
+            }
+            let color = GetPixel(dc, x, y);
+            ReleaseDC(None, dc);
+
+            if color.0 == CLR_INVALID {
+                return Err("Failed to read the screen color".to_string());
+            }
+
+            // COLORREF is 0x00BBGGRR
+            let [r, g, b, _] = color.0.to_le_bytes();
+            Ok(ScreenColor::new(r, g, b))
+        }
+    }
+
+    pub fn pick_color() -> Result<ScreenColor, String> {
+        let mut point = POINT::default();
+        unsafe { GetCursorPos(&mut point) }.map_err(|e| e.to_string())?;
+        sample_at(point.x, point.y)
+    }
+
+    unsafe extern "system" fn mouse_hook(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+        if code >= 0 {
+            let info = &*(lparam.0 as *const MSLLHOOKSTRUCT);
+            let msg = wparam.0 as u32;
+
+            if let Ok(mut session) = SESSION.lock() {
+                if let Some(session) = session.as_mut() {
+                    match msg {
+                        WM_MOUSEMOVE => {
+                            let due = session
+                                .last_sample
+                                .map(|t| t.elapsed() >= SAMPLE_INTERVAL)
+                                .unwrap_or(true);
+                            if due {
+                                session.last_sample = Some(Instant::now());
+                                if let Ok(color) = sample_at(info.pt.x, info.pt.y) {
+                                    let _ = session.app.emit("color-sampled", &color);
+                                }
+                            }
+                        }
+                        WM_LBUTTONDOWN | WM_RBUTTONDOWN => {
+                            if msg == WM_LBUTTONDOWN {
+                                session.picked = sample_at(info.pt.x, info.pt.y).ok();
+                            }
+                            PostQuitMessage(0);
+                            // Swallow the click so it doesn't land on whatever is underneath.
+                            return LRESULT(1);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        CallNextHookEx(HHOOK::default(), code, wparam, lparam)
+    }
+
+    /// Run the eyedropper on the calling thread until a click or the timeout.
+    ///
+    /// Returns `None` when cancelled (right click) or timed out.
+    pub fn run_eyedropper(app: AppHandle) -> Result<Option<ScreenColor>, String> {
+        if ACTIVE.swap(true, Ordering::SeqCst) {
+            return Err("The eyedropper is already active".to_string());
+        }
+
+        if let Ok(mut session) = SESSION.lock() {
+            *session = Some(Eyedropper {
+                app,
+                last_sample: None,
+                picked: None,
+            });
+        }
+
+        let result = unsafe {
+            match GetModuleHandleW(None)
+                .and_then(|module| SetWindowsHookExW(WH_MOUSE_LL, Some(mouse_hook), module, 0))
+            {
+                Ok(hook) => {
+                    let timer = SetTimer(None, 0, EYEDROPPER_TIMEOUT.as_millis() as u32, None);
+
+                    let mut msg = MSG::default();
+                    while GetMessageW(&mut msg, None, 0, 0).as_bool() {
+                        if msg.message == WM_TIMER && msg.wParam.0 == timer {
+                            break;
+                        }
+                        let _ = TranslateMessage(&msg);
+                        DispatchMessageW(&msg);
+                    }
+
+                    let _ = KillTimer(None, timer);
+                    let _ = UnhookWindowsHookEx(hook);
+                    Ok(())
+                }
+                Err(e) => Err(format!("Failed to install mouse hook: {e}")),
+            }
+        };
+
+        let picked = SESSION
+            .lock()
+            .ok()
+            .and_then(|mut session| session.take())
+            .and_then(|session| session.picked);
+        ACTIVE.store(false, Ordering::SeqCst);
+
+        result.map(|_| picked)
+    }
+}
+
+#[cfg(windows)]
+pub use windows_impl::{pick_color, run_eyedropper};
+
+// Non-Windows fallback
+#[cfg(not(windows))]
+pub fn pick_color() -> Result<ScreenColor, String> {
+    Err("Screen color sampling is only supported on Windows".to_string())
+}
+
+#[cfg(not(windows))]
+pub fn run_eyedropper(_app: tauri::AppHandle) -> Result<Option<ScreenColor>, String> {
+    Err("Screen color sampling is only supported on Windows".to_string())
+}
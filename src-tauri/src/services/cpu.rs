@@ -2,6 +2,10 @@
 
 use crate::services::wmi_service::CachedSystemData;
 use serde::Serialize;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// Highest per-core clock seen since startup (MHz)
+static MAX_OBSERVED_CLOCK_MHZ: AtomicU32 = AtomicU32::new(0);
 
 #[derive(Serialize, Clone, Debug)]
 pub struct CpuData {
@@ -17,6 +21,10 @@ pub struct CpuData {
     pub physical_cores: u32,
     /// Current clock speed in MHz
     pub clock_mhz: Option<u32>,
+    /// Approximate current clock per logical core in MHz
+    pub core_clocks_mhz: Vec<u32>,
+    /// Highest per-core clock observed since the app started (turbo/boost clock)
+    pub boost_clock_mhz: Option<u32>,
 }
 
 impl Default for CpuData {
@@ -28,6 +36,8 @@ impl Default for CpuData {
             logical_cores: 0,
             physical_cores: 0,
             clock_mhz: None,
+            core_clocks_mhz: vec![],
+            boost_clock_mhz: None,
         }
     }
 }
@@ -47,12 +57,29 @@ pub fn get_cpu_info_cached(cached: &CachedSystemData) -> CpuData {
         data.physical_cores = sys_info.dwNumberOfProcessors;
     }
 
+    #[cfg(not(windows))]
+    if super::demo::is_enabled() {
+        data.logical_cores = super::demo::logical_cores();
+        data.physical_cores = data.logical_cores / 2;
+    }
+
     // Use cached WMI data
     data.name = cached.cpu_name.clone();
     data.total_usage = cached.cpu_usage;
     if cached.cpu_clock_mhz > 0 {
         data.clock_mhz = Some(cached.cpu_clock_mhz);
     }
+    data.core_clocks_mhz = cached.cpu_core_clocks_mhz.clone();
+    if let Some(peak) = data
+        .core_clocks_mhz
+        .iter()
+        .copied()
+        .max()
+        .filter(|&p| p > 0)
+    {
+        let previous = MAX_OBSERVED_CLOCK_MHZ.fetch_max(peak, Ordering::Relaxed);
+        data.boost_clock_mhz = Some(previous.max(peak));
+    }
 
     // Fallback for empty name
     if data.name.is_empty() {
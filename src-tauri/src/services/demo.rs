@@ -0,0 +1,214 @@
+//! Demo mode for non-Windows development builds
+//!
+//! Set `BAR_DEMO_MODE=1` to have the hardware, media, audio and headset services return
+//! plausible, slowly changing fake values instead of empty defaults, so the UI can be
+//! developed and screenshotted on macOS/Linux. Never compiled into Windows builds.
+
+use std::sync::OnceLock;
+use std::time::Instant;
+
+use crate::services::audio::{AudioData, AudioDevice};
+#[cfg(feature = "headset")]
+use crate::services::headset::{HeadsetData, HeadsetFeatures, HeadsetStatus};
+use crate::services::media::{MediaData, PlaybackStatus};
+use crate::services::ram::RamData;
+use crate::services::wmi_service::{
+    CachedDriveInfo, CachedNetworkData, CachedSystemData, NvidiaGpuData,
+};
+
+const DEMO_LOGICAL_CORES: u32 = 16;
+const DEMO_RAM_TOTAL_BYTES: u64 = 32 * 1024 * 1024 * 1024;
+const DEMO_TRACK_SECONDS: f64 = 215.0;
+
+static STARTED_AT: OnceLock<Instant> = OnceLock::new();
+
+/// Whether demo mode was requested through `BAR_DEMO_MODE`.
+pub fn is_enabled() -> bool {
+    static ENABLED: OnceLock<bool> = OnceLock::new();
+    *ENABLED.get_or_init(|| {
+        std::env::var("BAR_DEMO_MODE")
+            .map(|v| matches!(v.trim().to_ascii_lowercase().as_str(), "1" | "true" | "yes"))
+            .unwrap_or(false)
+    })
+}
+
+/// Seconds since demo data was first requested (drives the fake fluctuations).
+fn elapsed() -> f64 {
+    STARTED_AT.get_or_init(Instant::now).elapsed().as_secs_f64()
+}
+
+/// Smooth value oscillating between `min` and `max` with the given period.
+fn wave(min: f64, max: f64, period_secs: f64, phase: f64) -> f64 {
+    let t = (elapsed() / period_secs + phase) * std::f64::consts::TAU;
+    min + (max - min) * (t.sin() * 0.5 + 0.5)
+}
+
+pub fn logical_cores() -> u32 {
+    DEMO_LOGICAL_CORES
+}
+
+/// Fake snapshot in the shape the WMI background loop produces.
+pub fn system_data() -> CachedSystemData {
+    let cpu_max_clock_mhz = 4_600;
+    let cpu_core_clocks_mhz = (0..DEMO_LOGICAL_CORES)
+        .map(|i| wave(2_800.0, 5_200.0, 7.0 + i as f64, i as f64 * 0.13) as u32)
+        .collect();
+
+    let gpu_usage = wave(8.0, 72.0, 40.0, 0.3);
+    let fan_speeds_percent = vec![
+        wave(0.0, 55.0, 60.0, 0.5) as u32,
+        wave(0.0, 52.0, 60.0, 0.45) as u32,
+    ];
+    let nvidia_gpu = NvidiaGpuData {
+        name: "NVIDIA GeForce RTX 4070 (demo)".to_string(),
+        temperature_c: wave(42.0, 68.0, 60.0, 0.3) as u32,
+        usage_percent: gpu_usage as u32,
+        memory_used_mb: wave(1_800.0, 7_400.0, 90.0, 0.1) as u64,
+        memory_total_mb: 12_282,
+        power_draw_w: wave(25.0, 180.0, 40.0, 0.3) as u32,
+        fan_speed_percent: fan_speeds_percent.iter().copied().max().unwrap_or(0),
+        fan_speeds_percent,
+        power_limit_w: Some(200),
+        core_clock_mhz: Some(wave(210.0, 2_610.0, 40.0, 0.3) as u32),
+        memory_clock_mhz: Some(10_501),
+        perf_state: Some(if gpu_usage > 20.0 { "P0" } else { "P8" }.to_string()),
+        available: true,
+    };
+
+    let download = wave(0.0, 12_500_000.0, 25.0, 0.0) as u64;
+    let upload = wave(0.0, 900_000.0, 18.0, 0.4) as u64;
+    let seconds = elapsed();
+
+    CachedSystemData {
+        cpu_name: "AMD Ryzen 7 7800X3D (demo)".to_string(),
+        cpu_usage: wave(4.0, 48.0, 30.0, 0.0) as f32,
+        cpu_clock_mhz: wave(3_900.0, 4_900.0, 11.0, 0.0) as u32,
+        cpu_max_clock_mhz,
+        cpu_core_clocks_mhz,
+        gpu_name: nvidia_gpu.name.clone(),
+        gpu_vendor: "NVIDIA".to_string(),
+        gpu_usage_percent: gpu_usage as f32,
+        gpu_vram_mb: nvidia_gpu.memory_total_mb,
+        gpu_vram_used_mb: nvidia_gpu.memory_used_mb,
+        nvidia_gpu,
+        ram_speed_mhz: 6_000,
+        drives: vec![
+            CachedDriveInfo {
+                letter: "C:".to_string(),
+                label: "System".to_string(),
+                file_system: "NTFS".to_string(),
+                total_bytes: 1_000_204_886_016,
+                free_bytes: 412_316_860_416,
+            },
+            CachedDriveInfo {
+                letter: "D:".to_string(),
+                label: "Games".to_string(),
+                file_system: "NTFS".to_string(),
+                total_bytes: 2_000_398_934_016,
+                free_bytes: 655_704_440_832,
+            },
+        ],
+        network: CachedNetworkData {
+            interface_name: "Ethernet (demo)".to_string(),
+            download_bytes_sec: download,
+            upload_bytes_sec: upload,
+            total_received: 48_000_000_000 + (seconds * 6_000_000.0) as u64,
+            total_sent: 3_200_000_000 + (seconds * 450_000.0) as u64,
+            is_connected: true,
+        },
+        last_updated: Some(Instant::now()),
+    }
+}
+
+pub fn ram_data() -> RamData {
+    let usage_percent = wave(38.0, 64.0, 120.0, 0.2);
+    let used_bytes = (DEMO_RAM_TOTAL_BYTES as f64 * usage_percent / 100.0) as u64;
+    RamData {
+        total_bytes: DEMO_RAM_TOTAL_BYTES,
+        available_bytes: DEMO_RAM_TOTAL_BYTES - used_bytes,
+        used_bytes,
+        usage_percent: usage_percent as f32,
+        voltage_mv: None,
+        temperature_c: None,
+        speed_mhz: Some(6_000),
+    }
+}
+
+pub fn media_data() -> MediaData {
+    MediaData {
+        has_media: true,
+        title: "Midnight City".to_string(),
+        artist: "M83".to_string(),
+        album: "Hurry Up, We're Dreaming".to_string(),
+        source_app: "Spotify".to_string(),
+        status: PlaybackStatus::Playing,
+        thumbnail_base64: None,
+        position_seconds: elapsed() % DEMO_TRACK_SECONDS,
+        duration_seconds: DEMO_TRACK_SECONDS,
+        is_shuffle_active: Some(false),
+        repeat_mode: Some("none".to_string()),
+    }
+}
+
+pub fn audio_data() -> AudioData {
+    let output_devices = vec![
+        AudioDevice {
+            id: "demo-output-speakers".to_string(),
+            name: "Speakers (Realtek Audio)".to_string(),
+            is_default: true,
+            volume: 42,
+            is_muted: false,
+            device_type: "output".to_string(),
+        },
+        AudioDevice {
+            id: "demo-output-headset".to_string(),
+            name: "Headset (CORSAIR VOID ELITE)".to_string(),
+            is_default: false,
+            volume: 65,
+            is_muted: false,
+            device_type: "output".to_string(),
+        },
+    ];
+    let input_devices = vec![AudioDevice {
+        id: "demo-input-mic".to_string(),
+        name: "Microphone (CORSAIR VOID ELITE)".to_string(),
+        is_default: true,
+        volume: 80,
+        is_muted: false,
+        device_type: "input".to_string(),
+    }];
+
+    AudioData {
+        output_devices,
+        input_devices,
+        default_output_id: Some("demo-output-speakers".to_string()),
+        default_input_id: Some("demo-input-mic".to_string()),
+        master_volume: 42,
+        is_muted: false,
+    }
+}
+
+#[cfg(feature = "headset")]
+pub fn headset_data() -> HeadsetData {
+    HeadsetData {
+        name: "CORSAIR VOID RGB ELITE (demo)".to_string(),
+        device_id: "demo-headset".to_string(),
+        battery_percent: wave(35.0, 95.0, 600.0, 0.0) as u8,
+        status: HeadsetStatus::Connected,
+        is_charging: false,
+        sdk_available: true,
+        mic_enabled: true,
+        surround_sound_enabled: false,
+        sidetone_enabled: false,
+        equalizer_preset: 1,
+        led_count: 2,
+        supported_features: HeadsetFeatures {
+            has_battery: true,
+            has_mic_toggle: true,
+            has_surround_sound: true,
+            has_sidetone: true,
+            has_equalizer: true,
+            has_lighting: true,
+        },
+    }
+}
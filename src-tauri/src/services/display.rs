@@ -0,0 +1,169 @@
+//! Display mode and HDR queries
+//!
+//! Monitors are identified by their GDI device name (`\\.\DISPLAY1`), which is what Tauri
+//! reports as the monitor name on Windows.
+
+use serde::Serialize;
+#[cfg(not(windows))]
+use std::collections::HashSet;
+
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DisplayMode {
+    pub width: u32,
+    pub height: u32,
+    pub refresh_hz: u32,
+}
+
+impl std::fmt::Display for DisplayMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}x{}@{}Hz", self.width, self.height, self.refresh_hz)
+    }
+}
+
+#[cfg(windows)]
+mod windows_impl {
+    use super::DisplayMode;
+    use std::collections::HashSet;
+    use windows::core::{Interface, PCWSTR};
+    use windows::Win32::Graphics::Dxgi::Common::DXGI_COLOR_SPACE_RGB_FULL_G2084_NONE_P2020;
+    use windows::Win32::Graphics::Dxgi::{CreateDXGIFactory1, IDXGIFactory1, IDXGIOutput6};
+    use windows::Win32::Graphics::Gdi::{
+        ChangeDisplaySettingsExW, EnumDisplaySettingsW, CDS_UPDATEREGISTRY, DEVMODEW,
+        DISP_CHANGE_BADMODE, DISP_CHANGE_RESTART, DISP_CHANGE_SUCCESSFUL, DM_DISPLAYFREQUENCY,
+        DM_PELSHEIGHT, DM_PELSWIDTH, ENUM_CURRENT_SETTINGS, ENUM_DISPLAY_SETTINGS_MODE,
+    };
+
+    fn to_wide(s: &str) -> Vec<u16> {
+        s.encode_utf16().chain(std::iter::once(0)).collect()
+    }
+
+    fn from_wide(buf: &[u16]) -> String {
+        let len = buf.iter().position(|&c| c == 0).unwrap_or(buf.len());
+        String::from_utf16_lossy(&buf[..len])
+    }
+
+    /// Current refresh rate of a display device, if known.
+    pub fn refresh_rate(device_name: &str) -> Option<u32> {
+        let name = to_wide(device_name);
+        let mut mode = DEVMODEW {
+            dmSize: std::mem::size_of::<DEVMODEW>() as u16,
+            ..Default::default()
+        };
+        let ok = unsafe {
+            EnumDisplaySettingsW(PCWSTR(name.as_ptr()), ENUM_CURRENT_SETTINGS, &mut mode)
+        };
+        // 0 and 1 both mean "hardware default" rather than a real rate.
+        (ok.as_bool() && mode.dmDisplayFrequency > 1).then_some(mode.dmDisplayFrequency)
+    }
+
+    /// Every mode the display driver reports for a device (sorted, without duplicates).
+    pub fn list_modes(device_name: &str) -> Vec<DisplayMode> {
+        let name = to_wide(device_name);
+        let mut modes = Vec::new();
+        let mut index = 0;
+        loop {
+            let mut mode = DEVMODEW {
+                dmSize: std::mem::size_of::<DEVMODEW>() as u16,
+                ..Default::default()
+            };
+            let ok = unsafe {
+                EnumDisplaySettingsW(
+                    PCWSTR(name.as_ptr()),
+                    ENUM_DISPLAY_SETTINGS_MODE(index),
+                    &mut mode,
+                )
+            };
+            if !ok.as_bool() {
+                break;
+            }
+            modes.push(DisplayMode {
+                width: mode.dmPelsWidth,
+                height: mode.dmPelsHeight,
+                refresh_hz: mode.dmDisplayFrequency,
+            });
+            index += 1;
+        }
+        modes.sort_unstable_by(|a, b| b.cmp(a));
+        modes.dedup();
+        modes
+    }
+
+    /// Switch a display to `mode` and persist it in the registry.
+    pub fn set_mode(device_name: &str, mode: DisplayMode) -> Result<(), String> {
+        let name = to_wide(device_name);
+        let devmode = DEVMODEW {
+            dmSize: std::mem::size_of::<DEVMODEW>() as u16,
+            dmFields: DM_PELSWIDTH | DM_PELSHEIGHT | DM_DISPLAYFREQUENCY,
+            dmPelsWidth: mode.width,
+            dmPelsHeight: mode.height,
+            dmDisplayFrequency: mode.refresh_hz,
+            ..Default::default()
+        };
+
+        let result = unsafe {
+            ChangeDisplaySettingsExW(
+                PCWSTR(name.as_ptr()),
+                Some(&devmode),
+                None,
+                CDS_UPDATEREGISTRY,
+                None,
+            )
+        };
+        match result {
+            DISP_CHANGE_SUCCESSFUL => Ok(()),
+            DISP_CHANGE_RESTART => Err("The display mode will apply after a restart".to_string()),
+            DISP_CHANGE_BADMODE => Err(format!("Display mode {mode} is not supported")),
+            other => Err(format!("Failed to change display mode (code {})", other.0)),
+        }
+    }
+
+    /// Device names of outputs currently running in HDR (BT.2100 PQ color space).
+    pub fn hdr_outputs() -> HashSet<String> {
+        let mut outputs = HashSet::new();
+        unsafe {
+            let Ok(factory) = CreateDXGIFactory1::<IDXGIFactory1>() else {
+                return outputs;
+            };
+
+            let mut adapter_index = 0;
+            while let Ok(adapter) = factory.EnumAdapters1(adapter_index) {
+                let mut output_index = 0;
+                while let Ok(output) = adapter.EnumOutputs(output_index) {
+                    // IDXGIOutput6 needs Windows 10 1703+; older systems simply report no HDR.
+                    if let Ok(desc) = output.cast::<IDXGIOutput6>().and_then(|o| o.GetDesc1()) {
+                        if desc.ColorSpace == DXGI_COLOR_SPACE_RGB_FULL_G2084_NONE_P2020 {
+                            outputs.insert(from_wide(&desc.DeviceName));
+                        }
+                    }
+                    output_index += 1;
+                }
+                adapter_index += 1;
+            }
+        }
+        outputs
+    }
+}
+
+#[cfg(windows)]
+pub use windows_impl::{hdr_outputs, list_modes, refresh_rate, set_mode};
+
+// Non-Windows fallback
+#[cfg(not(windows))]
+pub fn refresh_rate(_device_name: &str) -> Option<u32> {
+    None
+}
+
+#[cfg(not(windows))]
+pub fn hdr_outputs() -> HashSet<String> {
+    HashSet::new()
+}
+
+#[cfg(not(windows))]
+pub fn list_modes(_device_name: &str) -> Vec<DisplayMode> {
+    Vec::new()
+}
+
+#[cfg(not(windows))]
+pub fn set_mode(_device_name: &str, _mode: DisplayMode) -> Result<(), String> {
+    Err("Changing the display mode is only supported on Windows".to_string())
+}
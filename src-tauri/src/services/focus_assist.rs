@@ -0,0 +1,77 @@
+//! Focus Assist (quiet hours) state
+//!
+//! The current profile is published through the undocumented WNF state
+//! `WNF_SHEL_QUIETHOURS_ACTIVE_PROFILE_CHANGED`, read with `NtQueryWnfStateData` looked up
+//! at runtime from ntdll. If that fails the state is reported as `Unknown`: no documented
+//! setting mirrors the quiet hours profile.
+
+use serde::Serialize;
+
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum FocusAssistState {
+    Off,
+    /// Only priority notifications are shown
+    Priority,
+    /// Only alarms are shown
+    Alarms,
+    Unknown,
+}
+
+#[cfg(windows)]
+mod windows_impl {
+    use super::FocusAssistState;
+    use windows::core::{s, w};
+    use windows::Win32::System::LibraryLoader::{GetModuleHandleW, GetProcAddress};
+
+    const WNF_SHEL_QUIETHOURS_ACTIVE_PROFILE_CHANGED: u64 = 0x0D83_063E_A3BF_1C75;
+
+    type NtQueryWnfStateData = unsafe extern "system" fn(
+        state_name: *const u64,
+        type_id: *const std::ffi::c_void,
+        explicit_scope: *const std::ffi::c_void,
+        change_stamp: *mut u32,
+        buffer: *mut std::ffi::c_void,
+        buffer_size: *mut u32,
+    ) -> i32;
+
+    fn query_wnf_profile() -> Option<u32> {
+        unsafe {
+            let ntdll = GetModuleHandleW(w!("ntdll.dll")).ok()?;
+            let proc = GetProcAddress(ntdll, s!("NtQueryWnfStateData"))?;
+            let query: NtQueryWnfStateData = std::mem::transmute(proc);
+
+            let state_name = WNF_SHEL_QUIETHOURS_ACTIVE_PROFILE_CHANGED;
+            let mut change_stamp = 0u32;
+            let mut profile = 0u32;
+            let mut size = std::mem::size_of::<u32>() as u32;
+            let status = query(
+                &state_name,
+                std::ptr::null(),
+                std::ptr::null(),
+                &mut change_stamp,
+                &mut profile as *mut u32 as *mut _,
+                &mut size,
+            );
+            (status >= 0 && size == std::mem::size_of::<u32>() as u32).then_some(profile)
+        }
+    }
+
+    pub fn get_state() -> FocusAssistState {
+        match query_wnf_profile() {
+            Some(0) => FocusAssistState::Off,
+            Some(1) => FocusAssistState::Priority,
+            Some(2) => FocusAssistState::Alarms,
+            _ => FocusAssistState::Unknown,
+        }
+    }
+}
+
+#[cfg(windows)]
+pub use windows_impl::get_state;
+
+// Non-Windows fallback
+#[cfg(not(windows))]
+pub fn get_state() -> FocusAssistState {
+    FocusAssistState::Unknown
+}
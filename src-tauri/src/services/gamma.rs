@@ -0,0 +1,121 @@
+//! Display color temperature via the gamma ramp
+//!
+//! Night Light has no public API, so warming the screen is done by scaling the primary
+//! display's gamma ramp. This needs gamma-ramp support from the GPU driver: some drivers
+//! reject the call, and Windows refuses ramps far from identity unless
+//! `GdiIcmGammaRange` is raised in the registry, in which case an error is returned.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Set while the gamma ramp differs from identity because of us, so exiting only
+/// resets a ramp we changed (and leaves e.g. calibration loaders alone otherwise)
+static ADJUSTED: AtomicBool = AtomicBool::new(false);
+
+/// Warmest supported temperature
+pub const MIN_KELVIN: u32 = 1000;
+/// Neutral daylight white (identity ramp)
+pub const MAX_KELVIN: u32 = 6500;
+
+/// Approximate RGB of a black body at `kelvin` (Tanner Helland's fit), each 0.0-255.0
+#[cfg_attr(not(windows), allow(dead_code))]
+fn kelvin_to_rgb(kelvin: u32) -> (f64, f64, f64) {
+    let t = kelvin as f64 / 100.0;
+
+    let r = if t <= 66.0 {
+        255.0
+    } else {
+        329.698727446 * (t - 60.0).powf(-0.1332047592)
+    };
+    let g = if t <= 66.0 {
+        99.4708025861 * t.ln() - 161.1195681661
+    } else {
+        288.1221695283 * (t - 60.0).powf(-0.0755148492)
+    };
+    let b = if t >= 66.0 {
+        255.0
+    } else if t <= 19.0 {
+        0.0
+    } else {
+        138.5177312231 * (t - 10.0).ln() - 305.0447927307
+    };
+
+    (
+        r.clamp(0.0, 255.0),
+        g.clamp(0.0, 255.0),
+        b.clamp(0.0, 255.0),
+    )
+}
+
+/// Per-channel multipliers, relative to `MAX_KELVIN` so 6500K is exactly the identity ramp
+#[cfg_attr(not(windows), allow(dead_code))]
+fn channel_scales(kelvin: u32) -> [f64; 3] {
+    let (r, g, b) = kelvin_to_rgb(kelvin);
+    let (r0, g0, b0) = kelvin_to_rgb(MAX_KELVIN);
+    [(r / r0).min(1.0), (g / g0).min(1.0), (b / b0).min(1.0)]
+}
+
+#[cfg(windows)]
+mod windows_impl {
+    use windows::Win32::Graphics::Gdi::{GetDC, ReleaseDC};
+    use windows::Win32::UI::ColorSystem::SetDeviceGammaRamp;
+
+    pub fn set_scales(scales: [f64; 3]) -> Result<(), String> {
+        let mut ramp = [0u16; 3 * 256];
+        for (channel, scale) in scales.iter().enumerate() {
+            for i in 0..256 {
+                ramp[channel * 256 + i] = ((i * 257) as f64 * scale).round().min(65535.0) as u16;
+            }
+        }
+
+        unsafe {
+            let hdc = GetDC(None);
+            if hdc.is_invalid() {
+                return Err("Failed to get the screen device context".to_string());
+            }
+            let ok = SetDeviceGammaRamp(hdc, ramp.as_ptr() as *const _).as_bool();
+            ReleaseDC(None, hdc);
+            if !ok {
+                return Err(
+                    "The display driver rejected the gamma ramp (gamma ramps unsupported or outside the allowed range)"
+                        .to_string(),
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Warm the display to `kelvin` (clamped to 1000-6500); returns the applied value.
+#[cfg(windows)]
+pub fn set_color_temperature(kelvin: u32) -> Result<u32, String> {
+    let kelvin = kelvin.clamp(MIN_KELVIN, MAX_KELVIN);
+    windows_impl::set_scales(channel_scales(kelvin))?;
+    ADJUSTED.store(kelvin != MAX_KELVIN, Ordering::SeqCst);
+    Ok(kelvin)
+}
+
+/// Restore the identity gamma ramp.
+#[cfg(windows)]
+pub fn reset_color_temperature() -> Result<(), String> {
+    windows_impl::set_scales([1.0; 3])?;
+    ADJUSTED.store(false, Ordering::SeqCst);
+    Ok(())
+}
+
+// Non-Windows fallback
+#[cfg(not(windows))]
+pub fn set_color_temperature(_kelvin: u32) -> Result<u32, String> {
+    Err("Color temperature is only supported on Windows".to_string())
+}
+
+#[cfg(not(windows))]
+pub fn reset_color_temperature() -> Result<(), String> {
+    Err("Color temperature is only supported on Windows".to_string())
+}
+
+/// Undo a color temperature left applied, so the screen isn't stuck warm after exit.
+pub fn reset_on_exit() {
+    if ADJUSTED.load(Ordering::SeqCst) {
+        let _ = reset_color_temperature();
+    }
+}
@@ -39,6 +39,8 @@ pub struct GpuDetailedData {
     pub fan_speed_rpm: Option<u32>,
     /// Fan speed percentage
     pub fan_speed_percent: Option<f32>,
+    /// Per-fan speed percentages (multi-fan cards)
+    pub fan_speeds_percent: Vec<u32>,
     /// GPU voltage in mV
     pub voltage_mv: Option<u32>,
     /// PCIe generation
@@ -95,15 +97,16 @@ pub fn get_gpu_info_cached(cached: &CachedSystemData) -> GpuData {
             basic,
             temperature_c: Some(nvidia.temperature_c as f32),
             power_draw_w: Some(nvidia.power_draw_w as f32),
-            power_limit_w: None,
-            core_clock_mhz: None,
-            memory_clock_mhz: None,
+            power_limit_w: nvidia.power_limit_w.map(|w| w as f32),
+            core_clock_mhz: nvidia.core_clock_mhz,
+            memory_clock_mhz: nvidia.memory_clock_mhz,
             fan_speed_rpm: None,
             fan_speed_percent: Some(nvidia.fan_speed_percent as f32),
+            fan_speeds_percent: nvidia.fan_speeds_percent.clone(),
             voltage_mv: None,
             pcie_gen: None,
             pcie_lanes: None,
-            perf_state: None,
+            perf_state: nvidia.perf_state.clone(),
         };
 
         return GpuData::Detailed(detailed);
@@ -130,6 +133,92 @@ pub fn get_gpu_info_cached(cached: &CachedSystemData) -> GpuData {
     GpuData::Basic(basic)
 }
 
+/// A process using the GPU (NVIDIA only)
+#[derive(Serialize, Clone, Debug)]
+pub struct GpuProcess {
+    pub pid: u32,
+    /// Executable file name, or "PID <pid>" when the process can't be opened
+    pub name: String,
+    /// Dedicated VRAM used by the process. Under WDDM (regular Windows drivers) the driver
+    /// doesn't report per-process memory, so this is usually `None`.
+    pub used_memory_mb: Option<u64>,
+}
+
+/// NVML handle shared by on-demand queries (`None` if NVML couldn't be loaded)
+#[cfg(all(windows, feature = "nvidia"))]
+fn nvml() -> Option<&'static nvml_wrapper::Nvml> {
+    static NVML: std::sync::OnceLock<Option<nvml_wrapper::Nvml>> = std::sync::OnceLock::new();
+    NVML.get_or_init(|| nvml_wrapper::Nvml::init().ok())
+        .as_ref()
+}
+
+/// List processes running on the first NVIDIA GPU (graphics and compute).
+///
+/// Returns an empty list when NVML is unavailable.
+#[cfg(all(windows, feature = "nvidia"))]
+pub fn get_gpu_processes() -> Vec<GpuProcess> {
+    use nvml_wrapper::enums::device::UsedGpuMemory;
+
+    let Some(device) = nvml().and_then(|n| n.device_by_index(0).ok()) else {
+        return Vec::new();
+    };
+
+    let infos = device
+        .running_graphics_processes()
+        .unwrap_or_default()
+        .into_iter()
+        .chain(device.running_compute_processes().unwrap_or_default());
+
+    let mut processes: Vec<GpuProcess> = Vec::new();
+    for info in infos {
+        let used_memory_mb = match info.used_gpu_memory {
+            UsedGpuMemory::Used(bytes) => Some(bytes / 1024 / 1024),
+            UsedGpuMemory::Unavailable => None,
+        };
+
+        // A process doing both graphics and compute is listed twice by NVML.
+        if let Some(existing) = processes.iter_mut().find(|p| p.pid == info.pid) {
+            existing.used_memory_mb = existing.used_memory_mb.max(used_memory_mb);
+            continue;
+        }
+
+        let name = crate::services::windows::get_process_path(info.pid)
+            .and_then(|path| path.file_name().map(|n| n.to_string_lossy().to_string()))
+            .unwrap_or_else(|| format!("PID {}", info.pid));
+
+        processes.push(GpuProcess {
+            pid: info.pid,
+            name,
+            used_memory_mb,
+        });
+    }
+
+    processes.sort_by(|a, b| {
+        b.used_memory_mb
+            .cmp(&a.used_memory_mb)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    processes
+}
+
+#[cfg(not(all(windows, feature = "nvidia")))]
+pub fn get_gpu_processes() -> Vec<GpuProcess> {
+    Vec::new()
+}
+
+/// Whether NVML could be loaded (always false without the `nvidia` feature)
+pub fn nvml_available() -> bool {
+    #[cfg(all(windows, feature = "nvidia"))]
+    {
+        nvml().is_some()
+    }
+
+    #[cfg(not(all(windows, feature = "nvidia")))]
+    {
+        false
+    }
+}
+
 /// Legacy sync function - returns defaults quickly
 pub fn get_gpu_info() -> Result<GpuData, String> {
     Ok(GpuData::Basic(GpuBasicData::default()))
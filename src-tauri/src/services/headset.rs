@@ -13,7 +13,7 @@ use libloading::Library;
 
 #[cfg(windows)]
 fn verbose_logs_enabled() -> bool {
-    std::env::var_os("BAR_VERBOSE_LOGS").is_some()
+    super::logging::verbose_logs_enabled()
 }
 
 /// Status of the headset connection
@@ -81,6 +81,18 @@ pub struct HeadsetData {
     pub supported_features: HeadsetFeatures,
 }
 
+/// Raw device info reported by the iCUE SDK (for diagnosing detection issues)
+#[derive(Serialize, Clone, Debug)]
+pub struct CorsairDeviceDebugInfo {
+    pub model: String,
+    pub id: String,
+    pub serial: String,
+    /// CorsairDeviceType bitmask (headset = 0x08, headset stand = 0x10)
+    pub device_type: i32,
+    pub led_count: i32,
+    pub channel_count: i32,
+}
+
 #[cfg(windows)]
 #[derive(Clone, Copy, Debug)]
 struct BatteryChargeHeuristic {
@@ -127,10 +139,12 @@ impl Default for HeadsetData {
 mod cue_sdk {
     pub const CORSAIR_STRING_SIZE_M: usize = 128;
     pub const CORSAIR_DEVICE_COUNT_MAX: usize = 64;
+    pub const CORSAIR_DEVICE_LEDCOUNT_MAX: usize = 512;
 
     // Device types (bitmask)
     pub const CDT_HEADSET: i32 = 0x0008;
     pub const CDT_HEADSET_STAND: i32 = 0x0010;
+    pub const CDT_ALL: i32 = -1;
 
     // Error codes
     pub const CE_SUCCESS: i32 = 0;
@@ -148,6 +162,7 @@ mod cue_sdk {
 
     // Property flags
     pub const CPF_CAN_READ: u32 = 0x01;
+    pub const CPF_CAN_WRITE: u32 = 0x02;
 }
 
 #[cfg(windows)]
@@ -217,6 +232,26 @@ struct CorsairProperty {
     value: CorsairDataValue,
 }
 
+#[cfg(windows)]
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+struct CorsairLedPosition {
+    id: u32,
+    cx: f64,
+    cy: f64,
+}
+
+#[cfg(windows)]
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct CorsairLedColor {
+    id: u32,
+    r: u8,
+    g: u8,
+    b: u8,
+    a: u8,
+}
+
 // Type definitions for SDK functions
 #[cfg(windows)]
 type CorsairConnectFn = unsafe extern "C" fn(
@@ -240,6 +275,29 @@ type CorsairReadDevicePropertyFn = unsafe extern "C" fn(
     property: *mut CorsairProperty,
 ) -> i32;
 
+#[cfg(windows)]
+type CorsairWriteDevicePropertyFn = unsafe extern "C" fn(
+    device_id: *const u8,
+    property_id: i32,
+    index: u32,
+    property: *const CorsairProperty,
+) -> i32;
+
+#[cfg(windows)]
+type CorsairGetLedPositionsFn = unsafe extern "C" fn(
+    device_id: *const u8,
+    size_max: i32,
+    led_positions: *mut CorsairLedPosition,
+    size: *mut i32,
+) -> i32;
+
+#[cfg(windows)]
+type CorsairSetLedColorsFn = unsafe extern "C" fn(
+    device_id: *const u8,
+    size: i32,
+    leds_colors: *const CorsairLedColor,
+) -> i32;
+
 #[cfg(windows)]
 type CorsairFreePropertyFn = unsafe extern "C" fn(property: *mut CorsairProperty) -> i32;
 
@@ -470,7 +528,7 @@ fn infer_is_charging(device_id: &str, battery_level: u8) -> bool {
     charging
 }
 
-/// Check if a property is readable
+/// Check if a property is readable / writable
 #[cfg(windows)]
 unsafe fn get_property_info(
     get_property_info_fn: &libloading::Symbol<CorsairGetDevicePropertyInfoFn>,
@@ -484,7 +542,8 @@ unsafe fn get_property_info(
 
     if result == cue_sdk::CE_SUCCESS {
         let can_read = (flags & cue_sdk::CPF_CAN_READ) != 0;
-        (can_read, false) // We no longer support write operations
+        let can_write = (flags & cue_sdk::CPF_CAN_WRITE) != 0;
+        (can_read, can_write)
     } else {
         (false, false)
     }
@@ -604,12 +663,29 @@ pub fn get_headset_data() -> HeadsetData {
             cue_sdk::CDPI_MIC_ENABLED,
         );
 
+        let (can_read_eq, can_write_eq) = get_property_info(
+            &get_property_info_fn,
+            device_id_ptr,
+            cue_sdk::CDPI_EQUALIZER_PRESET,
+        );
+
+        let (can_read_surround, can_write_surround) = get_property_info(
+            &get_property_info_fn,
+            device_id_ptr,
+            cue_sdk::CDPI_SURROUND_SOUND_ENABLED,
+        );
+        let (can_read_sidetone, can_write_sidetone) = get_property_info(
+            &get_property_info_fn,
+            device_id_ptr,
+            cue_sdk::CDPI_SIDETONE_ENABLED,
+        );
+
         let supported_features = HeadsetFeatures {
             has_battery,
             has_mic_toggle: has_mic,
-            has_surround_sound: false,
-            has_sidetone: false,
-            has_equalizer: false,
+            has_surround_sound: can_write_surround,
+            has_sidetone: can_write_sidetone,
+            has_equalizer: can_write_eq,
             has_lighting: led_count > 0,
         };
 
@@ -632,6 +708,37 @@ pub fn get_headset_data() -> HeadsetData {
         )
         .unwrap_or(false);
 
+        // Read surround sound / sidetone status
+        let surround_sound_enabled = can_read_surround
+            && read_bool_property(
+                &read_property,
+                &free_property,
+                device_id_ptr,
+                cue_sdk::CDPI_SURROUND_SOUND_ENABLED,
+            )
+            .unwrap_or(false);
+        let sidetone_enabled = can_read_sidetone
+            && read_bool_property(
+                &read_property,
+                &free_property,
+                device_id_ptr,
+                cue_sdk::CDPI_SIDETONE_ENABLED,
+            )
+            .unwrap_or(false);
+
+        // Read equalizer preset
+        let equalizer_preset = if can_read_eq {
+            read_int32_property(
+                &read_property,
+                &free_property,
+                device_id_ptr,
+                cue_sdk::CDPI_EQUALIZER_PRESET,
+            )
+            .unwrap_or(1)
+        } else {
+            1
+        };
+
         // Infer charging based on battery trend (SDK doesn't expose charging directly)
         let is_charging = if has_battery && !device_id.is_empty() {
             infer_is_charging(&device_id, battery_level)
@@ -660,15 +767,349 @@ pub fn get_headset_data() -> HeadsetData {
             is_charging,
             sdk_available: true,
             mic_enabled,
-            surround_sound_enabled: false,
-            sidetone_enabled: false,
-            equalizer_preset: 1,
+            surround_sound_enabled,
+            sidetone_enabled,
+            equalizer_preset,
             led_count,
             supported_features,
         }
     }
 }
 
+/// Write a property on the given device
+#[cfg(windows)]
+fn write_property(
+    device_id: &str,
+    property_id: i32,
+    property: CorsairProperty,
+) -> Result<(), String> {
+    if !initialize_sdk() {
+        return Err("iCUE SDK is not available".to_string());
+    }
+    let lib = SDK_LIBRARY
+        .get()
+        .ok_or_else(|| "iCUE SDK is not available".to_string())?;
+    let device_id =
+        std::ffi::CString::new(device_id).map_err(|_| "Invalid device id".to_string())?;
+
+    unsafe {
+        let write_property: libloading::Symbol<CorsairWriteDevicePropertyFn> = lib
+            .get(b"CorsairWriteDeviceProperty")
+            .map_err(|_| "Failed to get CorsairWriteDeviceProperty function".to_string())?;
+
+        let result = write_property(device_id.as_ptr() as *const u8, property_id, 0, &property);
+        if result != cue_sdk::CE_SUCCESS {
+            return Err(format!(
+                "CorsairWriteDeviceProperty failed with error: {}",
+                result
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Set the equalizer preset (1-5) on the headset
+#[cfg(windows)]
+pub fn set_equalizer_preset(device_id: &str, preset: i32) -> Result<(), String> {
+    if !(1..=5).contains(&preset) {
+        return Err(format!("Invalid equalizer preset: {}", preset));
+    }
+    write_property(
+        device_id,
+        cue_sdk::CDPI_EQUALIZER_PRESET,
+        CorsairProperty {
+            type_: cue_sdk::CT_INT32,
+            value: CorsairDataValue { int32: preset },
+        },
+    )
+}
+
+/// Write a boolean property on the headset
+#[cfg(windows)]
+fn write_bool_property(device_id: &str, property_id: i32, enabled: bool) -> Result<(), String> {
+    write_property(
+        device_id,
+        property_id,
+        CorsairProperty {
+            type_: cue_sdk::CT_BOOLEAN,
+            value: CorsairDataValue { boolean: enabled },
+        },
+    )
+}
+
+/// Enable or disable sidetone on the headset
+#[cfg(windows)]
+pub fn set_sidetone(device_id: &str, enabled: bool) -> Result<(), String> {
+    write_bool_property(device_id, cue_sdk::CDPI_SIDETONE_ENABLED, enabled)
+}
+
+/// Enable or disable surround sound (7.1) on the headset
+#[cfg(windows)]
+pub fn set_surround_sound(device_id: &str, enabled: bool) -> Result<(), String> {
+    write_bool_property(device_id, cue_sdk::CDPI_SURROUND_SOUND_ENABLED, enabled)
+}
+
+/// Read a boolean property, only if the device also allows writing it
+#[cfg(windows)]
+fn read_writable_bool(device_id: &str, property_id: i32) -> Option<bool> {
+    if !initialize_sdk() {
+        return None;
+    }
+    let lib = SDK_LIBRARY.get()?;
+    let device_id = std::ffi::CString::new(device_id).ok()?;
+    let device_id_ptr = device_id.as_ptr() as *const u8;
+
+    unsafe {
+        let get_property_info_fn: libloading::Symbol<CorsairGetDevicePropertyInfoFn> =
+            lib.get(b"CorsairGetDevicePropertyInfo").ok()?;
+        let read_property: libloading::Symbol<CorsairReadDevicePropertyFn> =
+            lib.get(b"CorsairReadDeviceProperty").ok()?;
+        let free_property: libloading::Symbol<CorsairFreePropertyFn> =
+            lib.get(b"CorsairFreeProperty").ok()?;
+
+        match get_property_info(&get_property_info_fn, device_id_ptr, property_id) {
+            (true, true) => {
+                read_bool_property(&read_property, &free_property, device_id_ptr, property_id)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Toggle the headset microphone, returning whether it is now enabled
+#[cfg(windows)]
+pub fn toggle_mic(device_id: &str) -> Result<bool, String> {
+    if let Some(enabled) = read_writable_bool(device_id, cue_sdk::CDPI_MIC_ENABLED) {
+        write_bool_property(device_id, cue_sdk::CDPI_MIC_ENABLED, !enabled)?;
+        return Ok(!enabled);
+    }
+
+    // iCUE can't switch the mic on this device, mute the default capture endpoint instead
+    super::audio::toggle_capture_mute().map(|muted| !muted)
+}
+
+/// Lighting patterns driven from Rust (iCUE only receives plain colors)
+#[cfg(windows)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+enum LightingEffect {
+    #[default]
+    Static,
+    Breathing,
+}
+
+#[cfg(windows)]
+#[derive(Default)]
+struct LightingState {
+    device_id: String,
+    color: (u8, u8, u8),
+    effect: LightingEffect,
+    /// Whether the effect thread is running
+    animating: bool,
+}
+
+#[cfg(windows)]
+static LIGHTING: OnceLock<Mutex<LightingState>> = OnceLock::new();
+
+#[cfg(windows)]
+const BREATHING_PERIOD: Duration = Duration::from_secs(3);
+#[cfg(windows)]
+const BREATHING_STEP: Duration = Duration::from_millis(50);
+
+#[cfg(windows)]
+fn lighting_state() -> std::sync::MutexGuard<'static, LightingState> {
+    let state = LIGHTING.get_or_init(Default::default);
+    match state.lock() {
+        Ok(g) => g,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+/// Set every LED of the device to the same color
+#[cfg(windows)]
+fn apply_color(device_id: &str, (r, g, b): (u8, u8, u8)) -> Result<(), String> {
+    if !initialize_sdk() {
+        return Err("iCUE SDK is not available".to_string());
+    }
+    let lib = SDK_LIBRARY
+        .get()
+        .ok_or_else(|| "iCUE SDK is not available".to_string())?;
+    let device_id =
+        std::ffi::CString::new(device_id).map_err(|_| "Invalid device id".to_string())?;
+    let device_id_ptr = device_id.as_ptr() as *const u8;
+
+    unsafe {
+        let get_led_positions: libloading::Symbol<CorsairGetLedPositionsFn> = lib
+            .get(b"CorsairGetLedPositions")
+            .map_err(|_| "Failed to get CorsairGetLedPositions function".to_string())?;
+        let set_led_colors: libloading::Symbol<CorsairSetLedColorsFn> = lib
+            .get(b"CorsairSetLedColors")
+            .map_err(|_| "Failed to get CorsairSetLedColors function".to_string())?;
+
+        let mut positions =
+            vec![CorsairLedPosition::default(); cue_sdk::CORSAIR_DEVICE_LEDCOUNT_MAX];
+        let mut led_count: i32 = 0;
+        let result = get_led_positions(
+            device_id_ptr,
+            positions.len() as i32,
+            positions.as_mut_ptr(),
+            &mut led_count,
+        );
+        if result != cue_sdk::CE_SUCCESS {
+            return Err(format!(
+                "CorsairGetLedPositions failed with error: {}",
+                result
+            ));
+        }
+        if led_count <= 0 {
+            return Err("This headset has no controllable lighting".to_string());
+        }
+
+        let colors: Vec<CorsairLedColor> = positions[..led_count as usize]
+            .iter()
+            .map(|led| CorsairLedColor {
+                id: led.id,
+                r,
+                g,
+                b,
+                a: 255,
+            })
+            .collect();
+        let result = set_led_colors(device_id_ptr, colors.len() as i32, colors.as_ptr());
+        if result != cue_sdk::CE_SUCCESS {
+            return Err(format!("CorsairSetLedColors failed with error: {}", result));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(windows)]
+fn run_breathing_effect() {
+    let start = Instant::now();
+    loop {
+        let (device_id, (r, g, b)) = {
+            let mut state = lighting_state();
+            if state.effect != LightingEffect::Breathing {
+                state.animating = false;
+                return;
+            }
+            (state.device_id.clone(), state.color)
+        };
+
+        let phase =
+            start.elapsed().as_secs_f32() / BREATHING_PERIOD.as_secs_f32() * std::f32::consts::TAU;
+        let level = 0.5 - 0.5 * phase.cos();
+        let scale = |c: u8| (c as f32 * level).round() as u8;
+
+        if let Err(e) = apply_color(&device_id, (scale(r), scale(g), scale(b))) {
+            if verbose_logs_enabled() {
+                eprintln!("Headset breathing effect stopped: {}", e);
+            }
+            let mut state = lighting_state();
+            state.effect = LightingEffect::Static;
+            state.animating = false;
+            return;
+        }
+        std::thread::sleep(BREATHING_STEP);
+    }
+}
+
+/// Set the headset lighting color (used as the base color of the active effect)
+#[cfg(windows)]
+pub fn set_color(device_id: &str, r: u8, g: u8, b: u8) -> Result<(), String> {
+    apply_color(device_id, (r, g, b))?;
+    let mut state = lighting_state();
+    state.device_id = device_id.to_string();
+    state.color = (r, g, b);
+    Ok(())
+}
+
+/// Switch the lighting effect ("static" or "breathing") for the last colored headset
+#[cfg(windows)]
+pub fn set_lighting_effect(effect: &str) -> Result<(), String> {
+    let effect = match effect.to_ascii_lowercase().as_str() {
+        "static" => LightingEffect::Static,
+        "breathing" => LightingEffect::Breathing,
+        _ => return Err(format!("Unknown lighting effect: {}", effect)),
+    };
+
+    let mut state = lighting_state();
+    if state.device_id.is_empty() {
+        return Err("Set a headset color before choosing an effect".to_string());
+    }
+    state.effect = effect;
+
+    match effect {
+        LightingEffect::Static => {
+            let (device_id, color) = (state.device_id.clone(), state.color);
+            drop(state);
+            apply_color(&device_id, color)
+        }
+        LightingEffect::Breathing => {
+            if !state.animating {
+                std::thread::Builder::new()
+                    .name("headset-lighting".to_string())
+                    .spawn(run_breathing_effect)
+                    .map_err(|e| e.to_string())?;
+                state.animating = true;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// List every device iCUE reports, not just headsets
+#[cfg(windows)]
+pub fn debug_devices() -> Result<Vec<CorsairDeviceDebugInfo>, String> {
+    if !initialize_sdk() {
+        return Err("iCUE SDK is not available".to_string());
+    }
+    let lib = SDK_LIBRARY
+        .get()
+        .ok_or_else(|| "iCUE SDK is not available".to_string())?;
+
+    unsafe {
+        let get_devices: libloading::Symbol<CorsairGetDevicesFn> = lib
+            .get(b"CorsairGetDevices")
+            .map_err(|_| "Failed to get CorsairGetDevices function".to_string())?;
+
+        let filter = CorsairDeviceFilter {
+            device_type_mask: cue_sdk::CDT_ALL,
+        };
+        let mut devices: [CorsairDeviceInfo; cue_sdk::CORSAIR_DEVICE_COUNT_MAX] =
+            std::mem::zeroed();
+        let mut device_count: i32 = 0;
+        let result = get_devices(
+            &filter,
+            cue_sdk::CORSAIR_DEVICE_COUNT_MAX as i32,
+            devices.as_mut_ptr(),
+            &mut device_count,
+        );
+        if result != cue_sdk::CE_SUCCESS {
+            return Err(format!("CorsairGetDevices failed with error: {}", result));
+        }
+
+        let c_string = |bytes: &[u8]| {
+            std::ffi::CStr::from_bytes_until_nul(bytes)
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_else(|_| String::from_utf8_lossy(bytes).to_string())
+        };
+
+        Ok(
+            devices[..device_count.clamp(0, devices.len() as i32) as usize]
+                .iter()
+                .map(|device| CorsairDeviceDebugInfo {
+                    model: c_string(&device.model),
+                    id: c_string(&device.id),
+                    serial: c_string(&device.serial),
+                    device_type: device.device_type,
+                    led_count: device.led_count,
+                    channel_count: device.channel_count,
+                })
+                .collect(),
+        )
+    }
+}
+
 /// Check if SDK is available
 #[cfg(windows)]
 pub fn is_sdk_available() -> bool {
@@ -685,9 +1126,47 @@ pub fn get_sdk_path() -> Option<String> {
 
 #[cfg(not(windows))]
 pub fn get_headset_data() -> HeadsetData {
+    if super::demo::is_enabled() {
+        return super::demo::headset_data();
+    }
     HeadsetData::default()
 }
 
+#[cfg(not(windows))]
+pub fn set_equalizer_preset(_device_id: &str, _preset: i32) -> Result<(), String> {
+    Err("iCUE SDK is only available on Windows".to_string())
+}
+
+#[cfg(not(windows))]
+pub fn set_sidetone(_device_id: &str, _enabled: bool) -> Result<(), String> {
+    Err("iCUE SDK is only available on Windows".to_string())
+}
+
+#[cfg(not(windows))]
+pub fn set_surround_sound(_device_id: &str, _enabled: bool) -> Result<(), String> {
+    Err("iCUE SDK is only available on Windows".to_string())
+}
+
+#[cfg(not(windows))]
+pub fn toggle_mic(_device_id: &str) -> Result<bool, String> {
+    Err("iCUE SDK is only available on Windows".to_string())
+}
+
+#[cfg(not(windows))]
+pub fn set_color(_device_id: &str, _r: u8, _g: u8, _b: u8) -> Result<(), String> {
+    Err("iCUE SDK is only available on Windows".to_string())
+}
+
+#[cfg(not(windows))]
+pub fn set_lighting_effect(_effect: &str) -> Result<(), String> {
+    Err("iCUE SDK is only available on Windows".to_string())
+}
+
+#[cfg(not(windows))]
+pub fn debug_devices() -> Result<Vec<CorsairDeviceDebugInfo>, String> {
+    Err("iCUE SDK is only available on Windows".to_string())
+}
+
 #[cfg(not(windows))]
 pub fn is_sdk_available() -> bool {
     false
@@ -0,0 +1,215 @@
+//! Generic HID battery reader for headsets that aren't managed by iCUE
+//!
+//! A few wireless headsets answer a vendor-specific HID request with their battery level.
+//! Devices are matched by VID/PID from the hidapi device list, and only known headsets
+//! are opened and queried. The result uses the same `HeadsetData` shape as the iCUE path
+//! with `sdk_available: false`.
+
+#[cfg(windows)]
+mod windows_impl {
+    use super::super::headset::{HeadsetData, HeadsetFeatures, HeadsetStatus};
+    use hidapi::{HidApi, HidDevice};
+
+    /// How long to wait for the headset to answer a battery request
+    const READ_TIMEOUT_MS: i32 = 500;
+    /// Largest input report we expect from any supported headset
+    const MAX_REPORT_LEN: usize = 64;
+
+    #[derive(Clone, Copy)]
+    enum Protocol {
+        /// Output report `06 18`, battery percentage in byte 2 of the answer
+        SteelSeriesArctis,
+        /// HID++ long report, answer carries the battery voltage (mV) in bytes 4-5
+        LogitechHidpp { feature: u8, function: u8 },
+        /// 90-byte feature report, command class 0x07 / id 0x80, battery (0-255) in args[1]
+        Razer,
+    }
+
+    struct KnownHeadset {
+        vendor_id: u16,
+        product_id: u16,
+        name: &'static str,
+        protocol: Protocol,
+    }
+
+    const KNOWN_HEADSETS: &[KnownHeadset] = &[
+        KnownHeadset {
+            vendor_id: 0x1038,
+            product_id: 0x1260,
+            name: "SteelSeries Arctis 7",
+            protocol: Protocol::SteelSeriesArctis,
+        },
+        KnownHeadset {
+            vendor_id: 0x1038,
+            product_id: 0x12ad,
+            name: "SteelSeries Arctis 7 (2019)",
+            protocol: Protocol::SteelSeriesArctis,
+        },
+        KnownHeadset {
+            vendor_id: 0x1038,
+            product_id: 0x1252,
+            name: "SteelSeries Arctis Pro Wireless",
+            protocol: Protocol::SteelSeriesArctis,
+        },
+        KnownHeadset {
+            vendor_id: 0x046d,
+            product_id: 0x0a66,
+            name: "Logitech G533",
+            protocol: Protocol::LogitechHidpp {
+                feature: 0x07,
+                function: 0x01,
+            },
+        },
+        KnownHeadset {
+            vendor_id: 0x046d,
+            product_id: 0x0a5b,
+            name: "Logitech G933",
+            protocol: Protocol::LogitechHidpp {
+                feature: 0x08,
+                function: 0x0a,
+            },
+        },
+        KnownHeadset {
+            vendor_id: 0x046d,
+            product_id: 0x0a87,
+            name: "Logitech G935",
+            protocol: Protocol::LogitechHidpp {
+                feature: 0x08,
+                function: 0x0a,
+            },
+        },
+        KnownHeadset {
+            vendor_id: 0x1532,
+            product_id: 0x0528,
+            name: "Razer BlackShark V2 Pro",
+            protocol: Protocol::Razer,
+        },
+    ];
+
+    /// Read input reports until one matches `is_answer` or the device stops sending
+    fn read_answer(device: &HidDevice, is_answer: impl Fn(&[u8]) -> bool) -> Option<Vec<u8>> {
+        // Skip unrelated reports (volume wheel, mic mute button, ...) sent in between
+        for _ in 0..8 {
+            let mut buffer = vec![0u8; MAX_REPORT_LEN];
+            let read = device.read_timeout(&mut buffer, READ_TIMEOUT_MS).ok()?;
+            if read == 0 {
+                return None;
+            }
+            buffer.truncate(read);
+            if is_answer(&buffer) {
+                return Some(buffer);
+            }
+        }
+        None
+    }
+
+    /// Rough Li-ion discharge curve for the voltage reported by Logitech headsets
+    fn voltage_to_percent(millivolts: u16) -> u8 {
+        const CURVE: [(u16, u8); 6] = [
+            (4100, 100),
+            (3900, 75),
+            (3800, 50),
+            (3700, 25),
+            (3500, 5),
+            (3300, 0),
+        ];
+        if millivolts >= CURVE[0].0 {
+            return 100;
+        }
+        for pair in CURVE.windows(2) {
+            let ((high_mv, high_pct), (low_mv, low_pct)) = (pair[0], pair[1]);
+            if millivolts >= low_mv {
+                let span = (high_mv - low_mv) as u32;
+                let offset = (millivolts - low_mv) as u32;
+                return low_pct + ((high_pct - low_pct) as u32 * offset / span) as u8;
+            }
+        }
+        0
+    }
+
+    fn read_razer_battery(device: &HidDevice) -> Option<u8> {
+        const REPORT_LEN: usize = 91;
+
+        // Report id 0, then the 90-byte Razer message
+        let mut request = [0u8; REPORT_LEN];
+        request[2] = 0x1f; // transaction id
+        request[6] = 0x02; // data size
+        request[7] = 0x07; // command class: power
+        request[8] = 0x80; // command id: get battery level
+        request[89] = request[3..89].iter().fold(0, |crc, b| crc ^ b);
+
+        device.send_feature_report(&request).ok()?;
+        std::thread::sleep(std::time::Duration::from_millis(50));
+
+        let mut answer = [0u8; REPORT_LEN];
+        let read = device.get_feature_report(&mut answer).ok()?;
+        // Status 0x02 = success
+        (read > 10 && answer[1] == 0x02 && answer[7] == 0x07 && answer[8] == 0x80)
+            .then(|| (answer[10] as u32 * 100 / 255) as u8)
+    }
+
+    fn read_battery(device: &HidDevice, protocol: Protocol) -> Option<u8> {
+        match protocol {
+            Protocol::SteelSeriesArctis => {
+                device.write(&[0x06, 0x18]).ok()?;
+                let answer = read_answer(device, |r| r.len() > 2 && r[0] == 0x06 && r[1] == 0x18)?;
+                Some(answer[2].min(100))
+            }
+            Protocol::LogitechHidpp { feature, function } => {
+                let mut request = [0u8; 20];
+                request[..4].copy_from_slice(&[0x11, 0xff, feature, function]);
+                device.write(&request).ok()?;
+                let answer = read_answer(device, |r| {
+                    r.len() > 5 && r[0] == 0x11 && r[2] == feature && r[3] == function
+                })?;
+                let millivolts = u16::from_be_bytes([answer[4], answer[5]]);
+                Some(voltage_to_percent(millivolts))
+            }
+            Protocol::Razer => read_razer_battery(device),
+        }
+    }
+
+    pub fn get_headset_data() -> Option<HeadsetData> {
+        let api = HidApi::new().ok()?;
+        for info in api.device_list() {
+            let Some(known) = KNOWN_HEADSETS
+                .iter()
+                .find(|h| h.vendor_id == info.vendor_id() && h.product_id == info.product_id())
+            else {
+                continue;
+            };
+            let Ok(device) = api.open_path(info.path()) else {
+                continue;
+            };
+
+            // Headsets expose several HID collections; only one answers battery requests.
+            let Some(battery_percent) = read_battery(&device, known.protocol) else {
+                continue;
+            };
+
+            // Anything that answered is connected, even with an empty battery.
+            return Some(HeadsetData {
+                name: known.name.to_string(),
+                device_id: format!("hid:{:04x}:{:04x}", known.vendor_id, known.product_id),
+                battery_percent,
+                status: HeadsetStatus::Connected,
+                sdk_available: false,
+                supported_features: HeadsetFeatures {
+                    has_battery: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            });
+        }
+        None
+    }
+}
+
+#[cfg(windows)]
+pub use windows_impl::get_headset_data;
+
+// Non-Windows fallback
+#[cfg(not(windows))]
+pub fn get_headset_data() -> Option<super::headset::HeadsetData> {
+    None
+}
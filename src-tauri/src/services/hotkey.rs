@@ -0,0 +1,148 @@
+//! Global hotkey for the task switcher popup
+//!
+//! The accelerator (default ``Alt+` ``) is registered through `tauri-plugin-global-shortcut`,
+//! together with a Shift variant. The first press emits `taskswitcher-open`; every press
+//! (including the first) emits `taskswitcher-navigate` with `"next"`, or `"prev"` for the
+//! Shift variant. Once the accelerator's modifiers are released, `taskswitcher-release` is
+//! emitted so the popup can focus its highlighted window.
+//!
+//! The plugin only reports the main key going up, so on Windows the modifiers are polled
+//! with `GetAsyncKeyState` to detect the end of the switch.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Modifiers, Shortcut, ShortcutState};
+
+pub const DEFAULT_TASK_SWITCHER_HOTKEY: &str = "Alt+`";
+
+/// Shortcuts registered for the switcher, so they can be removed when the hotkey changes
+static REGISTERED: Mutex<Vec<Shortcut>> = Mutex::new(Vec::new());
+/// Set between the first press and the release of the accelerator's modifiers
+static SWITCHING: AtomicBool = AtomicBool::new(false);
+
+fn parse_accelerator(accelerator: &str) -> Result<Shortcut, String> {
+    // The plugin spells the Windows key "Super"; keep accepting the names the UI offers.
+    let normalized = accelerator
+        .split('+')
+        .map(|part| {
+            let part = part.trim();
+            match part.to_ascii_lowercase().as_str() {
+                "win" | "meta" => "Super",
+                _ => part,
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("+");
+
+    let shortcut: Shortcut = normalized
+        .parse()
+        .map_err(|_| format!("Invalid hotkey: {accelerator}"))?;
+    if shortcut.mods.is_empty() {
+        return Err(format!(
+            "Hotkey needs at least one modifier (Alt, Ctrl or Win): {accelerator}"
+        ));
+    }
+    Ok(shortcut)
+}
+
+fn handle_event(app: &AppHandle, state: ShortcutState, modifiers: Modifiers, direction: &str) {
+    match state {
+        ShortcutState::Pressed => {
+            if !SWITCHING.swap(true, Ordering::SeqCst) {
+                let _ = app.emit("taskswitcher-open", ());
+                watch_release(app.clone(), modifiers);
+            }
+            let _ = app.emit("taskswitcher-navigate", direction);
+        }
+        // Without modifier polling, the main key going up is the best signal we get.
+        #[cfg(not(windows))]
+        ShortcutState::Released => {
+            if SWITCHING.swap(false, Ordering::SeqCst) {
+                let _ = app.emit("taskswitcher-release", ());
+            }
+        }
+        #[cfg(windows)]
+        ShortcutState::Released => {}
+    }
+}
+
+/// Emit `taskswitcher-release` once every modifier of the accelerator is up.
+#[cfg(windows)]
+fn watch_release(app: AppHandle, modifiers: Modifiers) {
+    use std::time::Duration;
+    use windows::Win32::UI::Input::KeyboardAndMouse::{
+        GetAsyncKeyState, VIRTUAL_KEY, VK_CONTROL, VK_LWIN, VK_MENU, VK_RWIN, VK_SHIFT,
+    };
+
+    /// How often modifier release is checked while the switcher is open
+    const RELEASE_POLL: Duration = Duration::from_millis(50);
+
+    let is_down = |key: VIRTUAL_KEY| (unsafe { GetAsyncKeyState(key.0 as i32) }) < 0;
+    let any_down = move || {
+        (modifiers.contains(Modifiers::ALT) && is_down(VK_MENU))
+            || (modifiers.contains(Modifiers::CONTROL) && is_down(VK_CONTROL))
+            || (modifiers.contains(Modifiers::SHIFT) && is_down(VK_SHIFT))
+            || (modifiers.contains(Modifiers::SUPER) && (is_down(VK_LWIN) || is_down(VK_RWIN)))
+    };
+
+    let spawned = std::thread::Builder::new()
+        .name("task-switcher-release".to_string())
+        .spawn(move || {
+            while any_down() {
+                std::thread::sleep(RELEASE_POLL);
+            }
+            SWITCHING.store(false, Ordering::SeqCst);
+            let _ = app.emit("taskswitcher-release", ());
+        });
+    if spawned.is_err() {
+        SWITCHING.store(false, Ordering::SeqCst);
+    }
+}
+
+#[cfg(not(windows))]
+fn watch_release(_app: AppHandle, _modifiers: Modifiers) {}
+
+/// Register (or replace) the task switcher hotkey, e.g. ``"Alt+`"`` or `"Ctrl+Alt+Tab"`.
+pub fn register(app: AppHandle, accelerator: &str) -> Result<(), String> {
+    let next = parse_accelerator(accelerator)?;
+    unregister(&app);
+
+    // Releasing the accelerator's own modifiers ends the switch, for both directions.
+    let modifiers = next.mods;
+    let shortcuts = app.global_shortcut();
+    shortcuts
+        .on_shortcut(next, move |app, _, event| {
+            handle_event(app, event.state, modifiers, "next")
+        })
+        .map_err(|e| format!("Failed to register hotkey: {e}"))?;
+    let mut registered = vec![next];
+
+    // Shift+accelerator moves backwards (unless Shift is already part of it).
+    if !modifiers.contains(Modifiers::SHIFT) {
+        let prev = Shortcut::new(Some(modifiers | Modifiers::SHIFT), next.key);
+        let prev_registered = shortcuts.on_shortcut(prev, move |app, _, event| {
+            handle_event(app, event.state, modifiers, "prev")
+        });
+        if prev_registered.is_ok() {
+            registered.push(prev);
+        }
+    }
+
+    if let Ok(mut current) = REGISTERED.lock() {
+        *current = registered;
+    }
+    Ok(())
+}
+
+/// Remove the task switcher hotkey (no-op if none is registered).
+pub fn unregister(app: &AppHandle) {
+    let registered = REGISTERED
+        .lock()
+        .map(|mut current| std::mem::take(&mut *current))
+        .unwrap_or_default();
+    for shortcut in registered {
+        let _ = app.global_shortcut().unregister(shortcut);
+    }
+    SWITCHING.store(false, Ordering::SeqCst);
+}
@@ -0,0 +1,48 @@
+//! HTTP helpers shared by the services that call public web APIs (weather, public IP)
+
+use std::time::Duration;
+
+/// Build an agent with connect/read timeouts so a hung request can't stall a widget
+pub fn build_agent(connect_timeout: Duration, read_timeout: Duration) -> ureq::Agent {
+    ureq::Agent::config_builder()
+        .timeout_connect(Some(connect_timeout))
+        .timeout_recv_response(Some(read_timeout))
+        .timeout_recv_body(Some(read_timeout))
+        .build()
+        .into()
+}
+
+/// Network hiccups, timeouts and server-side errors are worth one more try; 4xx aren't.
+pub fn is_transient(error: &ureq::Error) -> bool {
+    match error {
+        ureq::Error::StatusCode(code) => *code == 429 || *code >= 500,
+        ureq::Error::Io(_)
+        | ureq::Error::Timeout(_)
+        | ureq::Error::HostNotFound
+        | ureq::Error::ConnectionFailed => true,
+        _ => false,
+    }
+}
+
+/// GET `url`, retrying once on transient failures.
+pub fn get_with_retry(
+    agent: &ureq::Agent,
+    url: &str,
+) -> Result<ureq::http::Response<ureq::Body>, ureq::Error> {
+    match agent.get(url).call() {
+        Err(e) if is_transient(&e) => agent.get(url).call(),
+        result => result,
+    }
+}
+
+/// GET `url` and parse the JSON body, retrying once on transient failures.
+pub fn get_json<T: serde::de::DeserializeOwned>(
+    agent: &ureq::Agent,
+    url: &str,
+) -> Result<T, String> {
+    get_with_retry(agent, url)
+        .map_err(|e| format!("request failed: {e}"))?
+        .into_body()
+        .read_json::<T>()
+        .map_err(|e| format!("invalid response: {e}"))
+}
@@ -0,0 +1,28 @@
+//! Time since the last user input
+//!
+//! A single `GetLastInputInfo` call, cheap enough to poll every second for the "away"
+//! indicator.
+
+#[cfg(windows)]
+pub fn get_idle_seconds() -> u64 {
+    use windows::Win32::System::SystemInformation::GetTickCount;
+    use windows::Win32::UI::Input::KeyboardAndMouse::{GetLastInputInfo, LASTINPUTINFO};
+
+    let mut info = LASTINPUTINFO {
+        cbSize: std::mem::size_of::<LASTINPUTINFO>() as u32,
+        dwTime: 0,
+    };
+    if !unsafe { GetLastInputInfo(&mut info) }.as_bool() {
+        return 0;
+    }
+
+    // Both values are 32-bit tick counts; wrapping_sub handles the ~49.7 day rollover.
+    let idle_ms = unsafe { GetTickCount() }.wrapping_sub(info.dwTime);
+    u64::from(idle_ms) / 1000
+}
+
+// Non-Windows fallback
+#[cfg(not(windows))]
+pub fn get_idle_seconds() -> u64 {
+    0
+}
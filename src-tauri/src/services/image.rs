@@ -0,0 +1,18 @@
+//! Image encoding shared by process icons and screenshots
+
+/// Encode 8-bit RGBA pixels as a `data:image/png;base64,...` URL.
+pub fn encode_rgba_png_data_url(width: u32, height: u32, rgba: &[u8]) -> Result<String, String> {
+    let mut png_data: Vec<u8> = Vec::new();
+    {
+        let mut encoder = png::Encoder::new(&mut png_data, width, height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header().map_err(|e| e.to_string())?;
+        writer.write_image_data(rgba).map_err(|e| e.to_string())?;
+    }
+
+    use base64::Engine;
+    let base64_str = base64::engine::general_purpose::STANDARD.encode(&png_data);
+    Ok(format!("data:image/png;base64,{}", base64_str))
+}
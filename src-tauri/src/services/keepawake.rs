@@ -0,0 +1,94 @@
+//! Keep-awake ("caffeine") toggle
+//!
+//! `SetThreadExecutionState` requests are tied to the calling thread, so a dedicated thread
+//! holds the request for as long as keep-awake is enabled and clears it before exiting.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static KEEP_AWAKE: AtomicBool = AtomicBool::new(false);
+
+pub fn is_enabled() -> bool {
+    KEEP_AWAKE.load(Ordering::SeqCst)
+}
+
+#[cfg(windows)]
+mod windows_impl {
+    use super::KEEP_AWAKE;
+    use std::sync::atomic::Ordering;
+    use std::sync::{mpsc, Mutex};
+    use std::thread::JoinHandle;
+    use windows::Win32::System::Power::{
+        SetThreadExecutionState, ES_CONTINUOUS, ES_DISPLAY_REQUIRED, ES_SYSTEM_REQUIRED,
+    };
+
+    /// Thread holding the execution state request
+    static WORKER: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
+
+    fn run(ready: mpsc::Sender<Result<(), String>>) {
+        let previous = unsafe {
+            SetThreadExecutionState(ES_CONTINUOUS | ES_SYSTEM_REQUIRED | ES_DISPLAY_REQUIRED)
+        };
+        if previous.0 == 0 {
+            let _ = ready.send(Err("Failed to set the thread execution state".to_string()));
+            return;
+        }
+        let _ = ready.send(Ok(()));
+
+        while KEEP_AWAKE.load(Ordering::SeqCst) {
+            std::thread::park();
+        }
+
+        unsafe {
+            SetThreadExecutionState(ES_CONTINUOUS);
+        }
+    }
+
+    pub fn set_keep_awake(enabled: bool) -> Result<(), String> {
+        let mut worker = WORKER.lock().map_err(|e| e.to_string())?;
+
+        if !enabled {
+            KEEP_AWAKE.store(false, Ordering::SeqCst);
+            if let Some(handle) = worker.take() {
+                handle.thread().unpark();
+                let _ = handle.join();
+            }
+            return Ok(());
+        }
+
+        if worker.is_some() {
+            return Ok(());
+        }
+
+        KEEP_AWAKE.store(true, Ordering::SeqCst);
+        let (tx, rx) = mpsc::channel();
+        let handle = std::thread::Builder::new()
+            .name("keep-awake".to_string())
+            .spawn(move || run(tx))
+            .map_err(|e| e.to_string())?;
+
+        let started = rx
+            .recv()
+            .map_err(|_| "Keep-awake thread exited unexpectedly".to_string())
+            .and_then(|r| r);
+        match started {
+            Ok(()) => {
+                *worker = Some(handle);
+                Ok(())
+            }
+            Err(e) => {
+                KEEP_AWAKE.store(false, Ordering::SeqCst);
+                let _ = handle.join();
+                Err(e)
+            }
+        }
+    }
+}
+
+#[cfg(windows)]
+pub use windows_impl::set_keep_awake;
+
+// Non-Windows fallback
+#[cfg(not(windows))]
+pub fn set_keep_awake(_enabled: bool) -> Result<(), String> {
+    Err("Keep-awake is only supported on Windows".to_string())
+}
@@ -0,0 +1,21 @@
+//! Verbose diagnostic logging switch
+//!
+//! Starts from the `BAR_VERBOSE_LOGS` environment variable and can be flipped at runtime
+//! (e.g. from the settings UI before reproducing a bug).
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+
+static VERBOSE: OnceLock<AtomicBool> = OnceLock::new();
+
+fn flag() -> &'static AtomicBool {
+    VERBOSE.get_or_init(|| AtomicBool::new(std::env::var_os("BAR_VERBOSE_LOGS").is_some()))
+}
+
+pub fn verbose_logs_enabled() -> bool {
+    flag().load(Ordering::Relaxed)
+}
+
+pub fn set_verbose_logs(enabled: bool) {
+    flag().store(enabled, Ordering::Relaxed);
+}
@@ -38,6 +38,38 @@ pub struct MediaData {
     pub position_seconds: f64,
     /// Total duration in seconds
     pub duration_seconds: f64,
+    /// Whether shuffle is on (None if the source app doesn't report it)
+    pub is_shuffle_active: Option<bool>,
+    /// Repeat mode: "none" | "track" | "list" (None if the source app doesn't report it)
+    pub repeat_mode: Option<String>,
+}
+
+/// Full-resolution artwork of the current track
+#[derive(Serialize, Clone, Debug)]
+pub struct MediaArtwork {
+    /// Image bytes, base64 encoded
+    pub data_base64: String,
+    /// Sniffed from the magic bytes ("image/png", "image/jpeg", ...)
+    pub mime_type: String,
+    pub size_bytes: usize,
+}
+
+/// Detect the image type from its magic bytes
+#[cfg(windows)]
+fn sniff_image_mime(bytes: &[u8]) -> &'static str {
+    if bytes.starts_with(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]) {
+        "image/png"
+    } else if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        "image/jpeg"
+    } else if bytes.starts_with(b"GIF8") {
+        "image/gif"
+    } else if bytes.len() >= 12 && &bytes[0..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+        "image/webp"
+    } else if bytes.starts_with(b"BM") {
+        "image/bmp"
+    } else {
+        "application/octet-stream"
+    }
 }
 
 impl Default for MediaData {
@@ -52,6 +84,8 @@ impl Default for MediaData {
             thumbnail_base64: None,
             position_seconds: 0.0,
             duration_seconds: 0.0,
+            is_shuffle_active: None,
+            repeat_mode: None,
         }
     }
 }
@@ -66,6 +100,7 @@ mod windows_impl {
         GlobalSystemMediaTransportControlsSessionManager,
         GlobalSystemMediaTransportControlsSessionPlaybackStatus,
     };
+    use windows::Media::MediaPlaybackAutoRepeatMode;
     use windows::Storage::Streams::DataReader;
     use windows::Win32::System::Com::{CoInitializeEx, COINIT_MULTITHREADED};
 
@@ -224,7 +259,7 @@ mod windows_impl {
                     }
 
                     // Poll at 1s - interpolation handles smooth timeline in between
-                    std::thread::sleep(Duration::from_millis(1000));
+                    crate::services::session::sleep_poll(Duration::from_millis(1000));
                 }
             })
             .ok();
@@ -281,6 +316,13 @@ mod windows_impl {
             Err(_) => PlaybackStatus::Unknown,
         };
 
+        let is_shuffle_active = playback_info.IsShuffleActive().and_then(|v| v.Value()).ok();
+        let repeat_mode = playback_info
+            .AutoRepeatMode()
+            .and_then(|v| v.Value())
+            .ok()
+            .map(|mode| repeat_mode_name(mode).to_string());
+
         // Get media properties
         let (title, artist, album) = match session.TryGetMediaPropertiesAsync() {
             Ok(op) => match op.get() {
@@ -374,19 +416,36 @@ mod windows_impl {
             thumbnail_base64,
             position_seconds,
             duration_seconds,
+            is_shuffle_active,
+            repeat_mode,
+        }
+    }
+
+    fn repeat_mode_name(mode: MediaPlaybackAutoRepeatMode) -> &'static str {
+        match mode {
+            MediaPlaybackAutoRepeatMode::Track => "track",
+            MediaPlaybackAutoRepeatMode::List => "list",
+            _ => "none",
         }
     }
 
     fn get_thumbnail(
         session: &windows::Media::Control::GlobalSystemMediaTransportControlsSession,
     ) -> Option<String> {
+        // Keep the polled path light: skip thumbnails > 1MB
+        read_thumbnail_bytes(session, 1024 * 1024).map(|buffer| BASE64.encode(&buffer))
+    }
+
+    fn read_thumbnail_bytes(
+        session: &windows::Media::Control::GlobalSystemMediaTransportControlsSession,
+        max_bytes: usize,
+    ) -> Option<Vec<u8>> {
         let props = session.TryGetMediaPropertiesAsync().ok()?.get().ok()?;
         let thumbnail_ref = props.Thumbnail().ok()?;
         let stream = thumbnail_ref.OpenReadAsync().ok()?.get().ok()?;
 
         let size = stream.Size().ok()? as usize;
-        if size == 0 || size > 1024 * 1024 {
-            // Skip if empty or > 1MB
+        if size == 0 || size > max_bytes || size > u32::MAX as usize {
             return None;
         }
 
@@ -396,7 +455,25 @@ mod windows_impl {
         let mut buffer = vec![0u8; size];
         reader.ReadBytes(&mut buffer).ok()?;
 
-        Some(BASE64.encode(&buffer))
+        Some(buffer)
+    }
+
+    /// Artwork of the current session regardless of source app, up to `max_bytes`
+    pub fn get_artwork(max_bytes: usize) -> Result<Option<MediaArtwork>, String> {
+        let manager = GlobalSystemMediaTransportControlsSessionManager::RequestAsync()
+            .map_err(|e| e.to_string())?
+            .get()
+            .map_err(|e| e.to_string())?;
+
+        let session = manager.GetCurrentSession().map_err(|e| e.to_string())?;
+
+        Ok(
+            read_thumbnail_bytes(&session, max_bytes).map(|bytes| MediaArtwork {
+                mime_type: sniff_image_mime(&bytes).to_string(),
+                size_bytes: bytes.len(),
+                data_base64: BASE64.encode(&bytes),
+            }),
+        )
     }
 
     fn extract_app_name(app_id: &str) -> String {
@@ -510,6 +587,128 @@ mod windows_impl {
         }
         Ok(())
     }
+
+    pub fn toggle_shuffle() -> Result<(), String> {
+        let manager = GlobalSystemMediaTransportControlsSessionManager::RequestAsync()
+            .map_err(|e| e.to_string())?
+            .get()
+            .map_err(|e| e.to_string())?;
+
+        let session = manager.GetCurrentSession().map_err(|e| e.to_string())?;
+
+        let current = session
+            .GetPlaybackInfo()
+            .and_then(|info| info.IsShuffleActive())
+            .and_then(|v| v.Value())
+            .unwrap_or(false);
+
+        let changed = session
+            .TryChangeShuffleActiveAsync(!current)
+            .map_err(|e| e.to_string())?
+            .get()
+            .map_err(|e| e.to_string())?;
+        if !changed {
+            return Err("The media app does not support shuffle".to_string());
+        }
+
+        if let Ok(mut cache) = get_state().lock() {
+            cache.media.is_shuffle_active = Some(!current);
+        }
+        Ok(())
+    }
+
+    pub fn set_repeat(mode: &str) -> Result<(), String> {
+        let requested = match mode {
+            "none" => MediaPlaybackAutoRepeatMode::None,
+            "track" => MediaPlaybackAutoRepeatMode::Track,
+            "list" => MediaPlaybackAutoRepeatMode::List,
+            other => {
+                return Err(format!(
+                    "Invalid repeat mode '{other}' (expected \"none\", \"track\" or \"list\")"
+                ))
+            }
+        };
+
+        let manager = GlobalSystemMediaTransportControlsSessionManager::RequestAsync()
+            .map_err(|e| e.to_string())?
+            .get()
+            .map_err(|e| e.to_string())?;
+
+        let session = manager.GetCurrentSession().map_err(|e| e.to_string())?;
+
+        let changed = session
+            .TryChangeAutoRepeatModeAsync(requested)
+            .map_err(|e| e.to_string())?
+            .get()
+            .map_err(|e| e.to_string())?;
+        if !changed {
+            return Err("The media app does not support repeat".to_string());
+        }
+
+        if let Ok(mut cache) = get_state().lock() {
+            cache.media.repeat_mode = Some(repeat_mode_name(requested).to_string());
+        }
+        Ok(())
+    }
+
+    pub fn skip_by(offset_seconds: f64) -> Result<(), String> {
+        let (current, duration) = match get_state().lock() {
+            Ok(cache) if cache.media.has_media => (estimated_position(&cache), cache.duration),
+            _ => return Err("No active media session".to_string()),
+        };
+
+        let mut target = (current + offset_seconds).max(0.0);
+        if duration > 0.0 {
+            target = target.min(duration);
+        }
+
+        seek_to_position(target)
+    }
+
+    /// Synthesize a hardware media key press (for apps that ignore SMTC session controls).
+    pub fn send_media_key(key: &str) -> Result<(), String> {
+        use windows::Win32::UI::Input::KeyboardAndMouse::{
+            SendInput, INPUT, INPUT_0, INPUT_KEYBOARD, KEYBDINPUT, KEYEVENTF_EXTENDEDKEY,
+            KEYEVENTF_KEYUP, VK_MEDIA_NEXT_TRACK, VK_MEDIA_PLAY_PAUSE, VK_MEDIA_PREV_TRACK,
+            VK_MEDIA_STOP,
+        };
+
+        let vk = match key {
+            "playpause" => VK_MEDIA_PLAY_PAUSE,
+            "next" => VK_MEDIA_NEXT_TRACK,
+            "prev" => VK_MEDIA_PREV_TRACK,
+            "stop" => VK_MEDIA_STOP,
+            other => {
+                return Err(format!(
+                    "Invalid media key '{other}' (expected \"playpause\", \"next\", \"prev\" or \"stop\")"
+                ))
+            }
+        };
+
+        let key_input = |up: bool| INPUT {
+            r#type: INPUT_KEYBOARD,
+            Anonymous: INPUT_0 {
+                ki: KEYBDINPUT {
+                    wVk: vk,
+                    wScan: 0,
+                    dwFlags: if up {
+                        KEYEVENTF_EXTENDEDKEY | KEYEVENTF_KEYUP
+                    } else {
+                        KEYEVENTF_EXTENDEDKEY
+                    },
+                    time: 0,
+                    dwExtraInfo: 0,
+                },
+            },
+        };
+
+        let inputs = [key_input(false), key_input(true)];
+        let sent = unsafe { SendInput(&inputs, std::mem::size_of::<INPUT>() as i32) };
+        if sent != inputs.len() as u32 {
+            return Err(format!("SendInput sent {sent}/{} events", inputs.len()));
+        }
+        Ok(())
+    }
 }
 
 #[cfg(windows)]
@@ -518,6 +717,9 @@ pub use windows_impl::*;
 // Non-Windows fallback
 #[cfg(not(windows))]
 pub fn get_media_data() -> MediaData {
+    if super::demo::is_enabled() {
+        return super::demo::media_data();
+    }
     MediaData::default()
 }
 
@@ -540,3 +742,28 @@ pub fn previous_track() -> Result<(), String> {
 pub fn seek_to_position(_position_seconds: f64) -> Result<(), String> {
     Err("Not supported on this platform".to_string())
 }
+
+#[cfg(not(windows))]
+pub fn skip_by(_offset_seconds: f64) -> Result<(), String> {
+    Err("Not supported on this platform".to_string())
+}
+
+#[cfg(not(windows))]
+pub fn toggle_shuffle() -> Result<(), String> {
+    Err("Not supported on this platform".to_string())
+}
+
+#[cfg(not(windows))]
+pub fn set_repeat(_mode: &str) -> Result<(), String> {
+    Err("Not supported on this platform".to_string())
+}
+
+#[cfg(not(windows))]
+pub fn get_artwork(_max_bytes: usize) -> Result<Option<MediaArtwork>, String> {
+    Err("Not supported on this platform".to_string())
+}
+
+#[cfg(not(windows))]
+pub fn send_media_key(_key: &str) -> Result<(), String> {
+    Err("Not supported on this platform".to_string())
+}
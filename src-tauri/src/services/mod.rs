@@ -1,15 +1,50 @@
 pub mod appbar;
 pub mod audio;
+pub mod bluetooth;
+pub mod clipboard_history;
+pub mod color_picker;
 pub mod cpu;
+#[cfg(not(windows))]
+pub mod demo;
+pub mod display;
+pub mod focus_assist;
+pub mod gamma;
 pub mod gpu;
+#[cfg(feature = "headset")]
 pub mod headset;
+#[cfg(feature = "headset")]
+pub mod hid_headset;
+pub mod hotkey;
+pub mod http;
+pub mod idle;
+#[cfg(windows)]
+pub mod image;
+pub mod keepawake;
+pub mod logging;
 pub mod media;
 pub mod network;
+pub mod network_usage;
+pub mod paths;
 pub mod pdh;
+pub mod processes;
+pub mod public_ip;
 pub mod ram;
+pub mod recycle_bin;
+#[cfg(windows)]
+pub mod registry;
+pub mod screenshot;
+pub mod session;
+pub mod startup_programs;
 pub mod storage;
+pub mod telemetry_log;
+pub mod theme;
+pub mod timer;
+pub mod vdesktop;
 pub mod weather;
+pub mod wifi;
+pub mod window_effects;
 pub mod windows;
+pub mod windows_update;
 pub mod wmi_service;
 
 pub use appbar::{
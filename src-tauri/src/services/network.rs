@@ -43,3 +43,135 @@ pub fn get_network_info_cached(cached: &CachedNetworkData) -> NetworkData {
         is_connected: cached.is_connected,
     }
 }
+
+/// Latency probe result (ping_host)
+#[derive(Serialize, Clone, Debug)]
+pub struct PingResult {
+    pub min_ms: f64,
+    pub avg_ms: f64,
+    pub max_ms: f64,
+    pub loss_percent: f64,
+}
+
+const PING_COUNT: usize = 4;
+const PING_TIMEOUT_MS: u32 = 1000;
+const PING_FALLBACK_PORT: u16 = 443;
+
+/// Ping a host with a few ICMP echoes, falling back to TCP connect time on port 443
+/// when ICMP is unavailable or blocked. Blocking: call from a worker thread.
+pub fn ping_host(host: &str) -> Result<PingResult, String> {
+    use std::net::{SocketAddr, ToSocketAddrs};
+
+    let host = host.trim();
+    if host.is_empty() {
+        return Err("Host is empty".to_string());
+    }
+
+    let addrs: Vec<SocketAddr> = (host, PING_FALLBACK_PORT)
+        .to_socket_addrs()
+        .map_err(|e| format!("Failed to resolve {}: {}", host, e))?
+        .collect();
+    let addr = addrs
+        .iter()
+        .find(|a| a.is_ipv4())
+        .or_else(|| addrs.first())
+        .copied()
+        .ok_or_else(|| format!("No address found for {}", host))?;
+
+    // A firewall dropping every echo looks the same as the host being down, so only
+    // trust ICMP when at least one reply came back
+    let samples = match icmp_samples(addr) {
+        Some(samples) if samples.iter().any(Option::is_some) => samples,
+        _ => tcp_samples(addr),
+    };
+
+    Ok(summarize(&samples))
+}
+
+fn tcp_samples(addr: std::net::SocketAddr) -> Vec<Option<f64>> {
+    use std::net::TcpStream;
+    use std::time::{Duration, Instant};
+
+    let timeout = Duration::from_millis(PING_TIMEOUT_MS as u64);
+    (0..PING_COUNT)
+        .map(|_| {
+            let start = Instant::now();
+            TcpStream::connect_timeout(&addr, timeout)
+                .ok()
+                .map(|_| start.elapsed().as_secs_f64() * 1000.0)
+        })
+        .collect()
+}
+
+fn summarize(samples: &[Option<f64>]) -> PingResult {
+    let received: Vec<f64> = samples.iter().flatten().copied().collect();
+    let loss_percent = if samples.is_empty() {
+        100.0
+    } else {
+        (samples.len() - received.len()) as f64 * 100.0 / samples.len() as f64
+    };
+
+    if received.is_empty() {
+        return PingResult {
+            min_ms: 0.0,
+            avg_ms: 0.0,
+            max_ms: 0.0,
+            loss_percent,
+        };
+    }
+
+    PingResult {
+        min_ms: received.iter().copied().fold(f64::INFINITY, f64::min),
+        avg_ms: received.iter().sum::<f64>() / received.len() as f64,
+        max_ms: received.iter().copied().fold(0.0, f64::max),
+        loss_percent,
+    }
+}
+
+#[cfg(windows)]
+fn icmp_samples(addr: std::net::SocketAddr) -> Option<Vec<Option<f64>>> {
+    use std::net::IpAddr;
+    use windows::Win32::NetworkManagement::IpHelper::{
+        IcmpCloseHandle, IcmpCreateFile, IcmpSendEcho, ICMP_ECHO_REPLY,
+    };
+
+    // IcmpSendEcho is IPv4 only
+    let IpAddr::V4(ip) = addr.ip() else {
+        return None;
+    };
+
+    const IP_SUCCESS: u32 = 0;
+    let payload = [0u8; 32];
+    let mut reply = vec![0u8; std::mem::size_of::<ICMP_ECHO_REPLY>() + payload.len() + 8];
+
+    unsafe {
+        let handle = IcmpCreateFile().ok()?;
+        let samples = (0..PING_COUNT)
+            .map(|_| {
+                let count = IcmpSendEcho(
+                    handle,
+                    u32::from_ne_bytes(ip.octets()),
+                    payload.as_ptr() as *const _,
+                    payload.len() as u16,
+                    None,
+                    reply.as_mut_ptr() as *mut _,
+                    reply.len() as u32,
+                    PING_TIMEOUT_MS,
+                );
+                if count == 0 {
+                    return None;
+                }
+                let echo = std::ptr::read_unaligned(reply.as_ptr() as *const ICMP_ECHO_REPLY);
+                (echo.Status == IP_SUCCESS).then_some(echo.RoundTripTime as f64)
+            })
+            .collect();
+        let _ = IcmpCloseHandle(handle);
+        Some(samples)
+    }
+}
+
+// Non-Windows fallback: no ICMP without raw sockets, always use TCP timing
+#[cfg(not(windows))]
+fn icmp_samples(_addr: std::net::SocketAddr) -> Option<Vec<Option<f64>>> {
+    None
+}
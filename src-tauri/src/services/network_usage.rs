@@ -0,0 +1,336 @@
+//! Daily/monthly network usage tracker persisted to the app data dir
+//!
+//! The WMI background loop feeds per-second rates into `record_sample`, which converts
+//! them into bytes using a monotonic clock (so wall-clock changes never inflate totals)
+//! and accumulates them into the current local day and month buckets.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager};
+
+const USAGE_FILENAME: &str = "network_usage.json";
+/// How often accumulated usage is flushed to disk
+const SAVE_INTERVAL_SECS: u64 = 60;
+/// Gaps longer than this (sleep/hibernate, stalled loop) are not counted as traffic.
+/// Must stay above the locked poll interval, or every sample taken while locked is dropped.
+const MAX_SAMPLE_GAP_SECS: f64 = (super::session::LOCKED_POLL_INTERVAL.as_secs() * 2) as f64;
+/// Number of daily buckets kept on disk; monthly buckets are kept forever
+const MAX_DAILY_BUCKETS: usize = 400;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default)]
+pub struct UsageBucket {
+    pub received_bytes: u64,
+    pub sent_bytes: u64,
+}
+
+/// A single entry of the usage history
+#[derive(Serialize, Clone, Debug)]
+pub struct NetworkUsageEntry {
+    /// "YYYY-MM-DD" for daily entries, "YYYY-MM" for monthly entries
+    pub period: String,
+    pub received_bytes: u64,
+    pub sent_bytes: u64,
+}
+
+/// Payload of the `data-cap-warning` event
+#[derive(Serialize, Clone, Debug)]
+pub struct DataCapWarning {
+    /// Threshold that was crossed, in percent of the cap
+    pub threshold_percent: u32,
+    /// Current monthly usage in percent of the cap
+    pub used_percent: f64,
+    pub used_bytes: u64,
+    pub cap_bytes: u64,
+}
+
+/// Thresholds that already fired for a given month
+#[derive(Serialize, Deserialize, Debug, Default)]
+struct FiredThresholds {
+    month: String,
+    thresholds: Vec<u32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+struct UsageFile {
+    #[serde(default)]
+    days: BTreeMap<String, UsageBucket>,
+    #[serde(default)]
+    months: BTreeMap<String, UsageBucket>,
+    #[serde(default)]
+    fired_thresholds: FiredThresholds,
+}
+
+#[derive(Clone, Debug, Default)]
+struct DataCap {
+    cap_bytes: u64,
+    warning_percents: Vec<u32>,
+}
+
+struct UsageTracker {
+    app: Option<AppHandle>,
+    path: Option<PathBuf>,
+    data_cap: Option<DataCap>,
+    file: UsageFile,
+    last_sample: Option<Instant>,
+    last_save: Instant,
+    current_day: String,
+    dirty: bool,
+}
+
+impl Default for UsageTracker {
+    fn default() -> Self {
+        Self {
+            app: None,
+            path: None,
+            data_cap: None,
+            file: UsageFile::default(),
+            last_sample: None,
+            last_save: Instant::now(),
+            current_day: String::new(),
+            dirty: false,
+        }
+    }
+}
+
+static USAGE_TRACKER: OnceLock<Mutex<UsageTracker>> = OnceLock::new();
+
+fn get_tracker() -> &'static Mutex<UsageTracker> {
+    USAGE_TRACKER.get_or_init(|| Mutex::new(UsageTracker::default()))
+}
+
+fn day_key(now: &chrono::DateTime<chrono::Local>) -> String {
+    now.format("%Y-%m-%d").to_string()
+}
+
+fn month_key(now: &chrono::DateTime<chrono::Local>) -> String {
+    now.format("%Y-%m").to_string()
+}
+
+impl UsageTracker {
+    fn save(&mut self) {
+        let Some(path) = self.path.as_ref() else {
+            return;
+        };
+
+        // Drop the oldest daily buckets so the file stays small.
+        while self.file.days.len() > MAX_DAILY_BUCKETS {
+            let oldest = self.file.days.keys().next().cloned();
+            match oldest {
+                Some(key) => {
+                    self.file.days.remove(&key);
+                }
+                None => break,
+            }
+        }
+
+        let content = match serde_json::to_string_pretty(&self.file) {
+            Ok(c) => c,
+            Err(e) => {
+                eprintln!("Failed to serialize network usage: {e}");
+                return;
+            }
+        };
+
+        let tmp = path.with_extension("json.tmp");
+        let result = fs::write(&tmp, content).and_then(|_| {
+            let _ = fs::remove_file(path);
+            fs::rename(&tmp, path)
+        });
+        if let Err(e) = result {
+            eprintln!("Failed to save network usage: {e}");
+            return;
+        }
+
+        self.last_save = Instant::now();
+        self.dirty = false;
+    }
+
+    /// Emit `data-cap-warning` for every threshold crossed this month that hasn't fired yet.
+    fn check_data_cap(&mut self, month: &str) {
+        let Some(cap) = self.data_cap.as_ref() else {
+            return;
+        };
+        if cap.cap_bytes == 0 {
+            return;
+        }
+
+        // Month rollover: thresholds start over.
+        if self.file.fired_thresholds.month != month {
+            self.file.fired_thresholds = FiredThresholds {
+                month: month.to_string(),
+                thresholds: Vec::new(),
+            };
+            self.dirty = true;
+        }
+
+        let used = self.file.months.get(month).copied().unwrap_or_default();
+        let used_bytes = used.received_bytes.saturating_add(used.sent_bytes);
+        let used_percent = used_bytes as f64 / cap.cap_bytes as f64 * 100.0;
+
+        let crossed: Vec<u32> = cap
+            .warning_percents
+            .iter()
+            .copied()
+            .filter(|p| used_percent >= *p as f64)
+            .filter(|p| !self.file.fired_thresholds.thresholds.contains(p))
+            .collect();
+        if crossed.is_empty() {
+            return;
+        }
+
+        let cap_bytes = cap.cap_bytes;
+        for threshold_percent in crossed {
+            self.file
+                .fired_thresholds
+                .thresholds
+                .push(threshold_percent);
+            if let Some(app) = self.app.as_ref() {
+                let _ = app.emit(
+                    "data-cap-warning",
+                    DataCapWarning {
+                        threshold_percent,
+                        used_percent,
+                        used_bytes,
+                        cap_bytes,
+                    },
+                );
+            }
+        }
+
+        // Persist right away so a restart doesn't repeat the warning.
+        self.save();
+    }
+}
+
+/// Load persisted usage from the app data dir and start tracking.
+pub fn init(app: &AppHandle) {
+    let Ok(app_data_dir) = app.path().app_data_dir() else {
+        return;
+    };
+    let _ = fs::create_dir_all(&app_data_dir);
+    let path = app_data_dir.join(USAGE_FILENAME);
+
+    let file = fs::read_to_string(&path)
+        .ok()
+        .and_then(|content| serde_json::from_str::<UsageFile>(&content).ok())
+        .unwrap_or_default();
+
+    if let Ok(mut tracker) = get_tracker().lock() {
+        tracker.app = Some(app.clone());
+        tracker.path = Some(path);
+        tracker.file = file;
+        tracker.current_day = day_key(&chrono::Local::now());
+    }
+}
+
+/// Accumulate one sample of per-second rates into the current day/month buckets.
+pub fn record_sample(download_bytes_sec: u64, upload_bytes_sec: u64) {
+    let Ok(mut tracker) = get_tracker().lock() else {
+        return;
+    };
+    if tracker.path.is_none() {
+        return;
+    }
+
+    let now_instant = Instant::now();
+    let elapsed = tracker
+        .last_sample
+        .map(|t| now_instant.duration_since(t).as_secs_f64())
+        .unwrap_or(0.0);
+    tracker.last_sample = Some(now_instant);
+
+    let now = chrono::Local::now();
+    let day = day_key(&now);
+    let month = month_key(&now);
+
+    // Day rollover (or a manual clock change): persist what we have before switching buckets.
+    if tracker.current_day != day {
+        tracker.current_day = day.clone();
+        if tracker.dirty {
+            tracker.save();
+        }
+    }
+
+    if elapsed > 0.0 && elapsed <= MAX_SAMPLE_GAP_SECS {
+        let received = (download_bytes_sec as f64 * elapsed) as u64;
+        let sent = (upload_bytes_sec as f64 * elapsed) as u64;
+
+        if received > 0 || sent > 0 {
+            let file = &mut tracker.file;
+            for bucket in [
+                file.days.entry(day).or_default(),
+                file.months.entry(month.clone()).or_default(),
+            ] {
+                bucket.received_bytes = bucket.received_bytes.saturating_add(received);
+                bucket.sent_bytes = bucket.sent_bytes.saturating_add(sent);
+            }
+            tracker.dirty = true;
+        }
+    }
+
+    tracker.check_data_cap(&month);
+
+    if tracker.dirty && tracker.last_save.elapsed() >= Duration::from_secs(SAVE_INTERVAL_SECS) {
+        tracker.save();
+    }
+}
+
+/// Configure the monthly data cap. A cap of 0 (or less) disables the warnings.
+pub fn set_data_cap(monthly_cap_gb: f64, warning_percents: &[u32]) {
+    let Ok(mut tracker) = get_tracker().lock() else {
+        return;
+    };
+
+    if monthly_cap_gb <= 0.0 {
+        tracker.data_cap = None;
+        return;
+    }
+
+    let mut warning_percents = warning_percents.to_vec();
+    warning_percents.sort_unstable();
+    warning_percents.dedup();
+
+    tracker.data_cap = Some(DataCap {
+        cap_bytes: (monthly_cap_gb * 1024.0 * 1024.0 * 1024.0) as u64,
+        warning_percents,
+    });
+}
+
+/// Persist any pending usage (called on shutdown).
+pub fn flush() {
+    if let Ok(mut tracker) = get_tracker().lock() {
+        if tracker.dirty {
+            tracker.save();
+        }
+    }
+}
+
+/// Get usage history.
+///
+/// `range` is "daily" (last 31 days) or "monthly" (every tracked month).
+pub fn get_usage_history(range: &str) -> Result<Vec<NetworkUsageEntry>, String> {
+    let tracker = get_tracker()
+        .lock()
+        .map_err(|_| "Network usage lock poisoned".to_string())?;
+
+    let to_entry = |(period, bucket): (&String, &UsageBucket)| NetworkUsageEntry {
+        period: period.clone(),
+        received_bytes: bucket.received_bytes,
+        sent_bytes: bucket.sent_bytes,
+    };
+
+    match range {
+        "daily" => {
+            let cutoff = day_key(&(chrono::Local::now() - chrono::Duration::days(30)));
+            Ok(tracker.file.days.range(cutoff..).map(to_entry).collect())
+        }
+        "monthly" => Ok(tracker.file.months.iter().map(to_entry).collect()),
+        other => Err(format!(
+            "Unknown usage range '{other}' (expected \"daily\" or \"monthly\")"
+        )),
+    }
+}
@@ -0,0 +1,156 @@
+//! Path resolution shared by folder shortcuts and anything else that opens user paths
+//!
+//! Configs get shared between machines, so stored paths may use `%APPDATA%`, `${HOME}` or
+//! a leading `~` instead of a hard-coded user directory.
+
+/// Expand `%VAR%` and `${VAR}` references and a leading `~` (home dir).
+///
+/// Unknown variables are left untouched so the caller can report the original text.
+pub fn expand_path(input: &str) -> String {
+    let expanded = expand_env_vars(input);
+    expand_home(&expanded)
+}
+
+fn expand_home(input: &str) -> String {
+    let rest = match input.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with(['/', '\\']) => rest,
+        // "~user" style paths aren't supported; leave them alone.
+        _ => return input.to_string(),
+    };
+
+    match dirs::home_dir() {
+        Some(home) => format!("{}{rest}", home.to_string_lossy()),
+        None => input.to_string(),
+    }
+}
+
+fn expand_env_vars(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(start) = rest.find(['%', '$']) {
+        out.push_str(&rest[..start]);
+        let marker = &rest[start..];
+
+        // (text before the name, closing delimiter)
+        let (open, close) = if marker.starts_with("${") {
+            ("${", '}')
+        } else if marker.starts_with('%') {
+            ("%", '%')
+        } else {
+            // A lone `$` is literal text.
+            out.push('$');
+            rest = &marker[1..];
+            continue;
+        };
+
+        let after = &marker[open.len()..];
+        match after.find(close) {
+            Some(end) if end > 0 => {
+                let name = &after[..end];
+                match std::env::var(name) {
+                    Ok(value) => out.push_str(&value),
+                    Err(_) => out.push_str(&marker[..open.len() + end + 1]),
+                }
+                rest = &after[end + 1..];
+            }
+            _ => {
+                out.push_str(open);
+                rest = after;
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Each test uses its own variable name so parallel tests don't race on the environment.
+
+    #[test]
+    fn expands_percent_vars() {
+        std::env::set_var("BMT_TEST_PERCENT", r"C:\Users\me");
+        assert_eq!(
+            expand_env_vars(r"%BMT_TEST_PERCENT%\Desktop"),
+            r"C:\Users\me\Desktop"
+        );
+    }
+
+    #[test]
+    fn expands_braced_dollar_vars() {
+        std::env::set_var("BMT_TEST_BRACED", "/home/me");
+        assert_eq!(expand_env_vars("${BMT_TEST_BRACED}/docs"), "/home/me/docs");
+    }
+
+    #[test]
+    fn leaves_bare_dollar_vars_verbatim() {
+        std::env::set_var("BMT_TEST_BARE", "/home/me");
+        assert_eq!(
+            expand_env_vars("$BMT_TEST_BARE/docs"),
+            "$BMT_TEST_BARE/docs"
+        );
+    }
+
+    #[test]
+    fn leaves_unknown_vars_verbatim() {
+        std::env::remove_var("BMT_TEST_MISSING");
+        assert_eq!(
+            expand_env_vars(r"%BMT_TEST_MISSING%\x"),
+            r"%BMT_TEST_MISSING%\x"
+        );
+        assert_eq!(
+            expand_env_vars("${BMT_TEST_MISSING}/x"),
+            "${BMT_TEST_MISSING}/x"
+        );
+    }
+
+    #[test]
+    fn lone_markers_are_literal() {
+        assert_eq!(expand_env_vars("$"), "$");
+        assert_eq!(expand_env_vars("%"), "%");
+        assert_eq!(expand_env_vars("100% sure"), "100% sure");
+        assert_eq!(expand_env_vars("%%"), "%%");
+        assert_eq!(expand_env_vars("${unclosed"), "${unclosed");
+        assert_eq!(expand_env_vars("cost: $5"), "cost: $5");
+    }
+
+    #[test]
+    fn empty_input_stays_empty() {
+        assert_eq!(expand_env_vars(""), "");
+        assert_eq!(expand_home(""), "");
+        assert_eq!(expand_path(""), "");
+    }
+
+    #[test]
+    fn expands_leading_tilde() {
+        let Some(home) = dirs::home_dir() else {
+            return;
+        };
+        let home = home.to_string_lossy().into_owned();
+        assert_eq!(expand_home("~"), home);
+        assert_eq!(expand_home(r"~\sub"), format!(r"{home}\sub"));
+        assert_eq!(expand_home("~/sub"), format!("{home}/sub"));
+    }
+
+    #[test]
+    fn leaves_other_tildes_alone() {
+        assert_eq!(expand_home("~user/docs"), "~user/docs");
+        assert_eq!(expand_home("docs/~"), "docs/~");
+    }
+
+    #[test]
+    fn expand_path_combines_vars_and_home() {
+        let Some(home) = dirs::home_dir() else {
+            return;
+        };
+        std::env::set_var("BMT_TEST_COMBINED", "notes");
+        assert_eq!(
+            expand_path("~/%BMT_TEST_COMBINED%"),
+            format!("{}/notes", home.to_string_lossy())
+        );
+    }
+}
@@ -0,0 +1,246 @@
+//! Per-process CPU and memory usage for the mini task manager
+//!
+//! CPU usage is the change in each process's kernel+user time between two samples,
+//! divided by the elapsed wall time and the number of logical processors. The previous
+//! sample is cached so consecutive calls measure the time between them.
+
+use serde::Serialize;
+
+#[derive(Serialize, Clone, Debug)]
+pub struct ProcessUsage {
+    pub pid: u32,
+    pub name: String,
+    /// Share of total CPU time since the previous sample (0-100)
+    pub cpu_percent: f32,
+    /// Working set in MB
+    pub memory_mb: f64,
+}
+
+#[cfg(windows)]
+mod windows_impl {
+    use super::ProcessUsage;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use std::time::{Duration, Instant};
+    use windows::Win32::Foundation::{CloseHandle, E_ACCESSDENIED, FILETIME};
+    use windows::Win32::System::Diagnostics::ToolHelp::{
+        CreateToolhelp32Snapshot, Process32FirstW, Process32NextW, PROCESSENTRY32W,
+        TH32CS_SNAPPROCESS,
+    };
+    use windows::Win32::System::ProcessStatus::{GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS};
+    use windows::Win32::System::Threading::{
+        GetProcessTimes, OpenProcess, TerminateProcess, PROCESS_QUERY_LIMITED_INFORMATION,
+        PROCESS_TERMINATE,
+    };
+
+    /// Samples closer together than this are too noisy; take a fresh pair instead
+    const MIN_SAMPLE_GAP: Duration = Duration::from_millis(200);
+    /// Gap used when there is no usable previous sample
+    const FIRST_SAMPLE_GAP: Duration = Duration::from_millis(500);
+
+    struct Sample {
+        at: Instant,
+        /// Total CPU time (100ns units) per pid
+        cpu_times: HashMap<u32, u64>,
+    }
+
+    struct ProcessInfo {
+        pid: u32,
+        name: String,
+        cpu_time: Option<u64>,
+        working_set: u64,
+    }
+
+    static LAST_SAMPLE: Mutex<Option<Sample>> = Mutex::new(None);
+
+    fn filetime_to_u64(ft: FILETIME) -> u64 {
+        ((ft.dwHighDateTime as u64) << 32) | ft.dwLowDateTime as u64
+    }
+
+    fn list_processes() -> Result<Vec<ProcessInfo>, String> {
+        let mut processes = Vec::new();
+        unsafe {
+            let snapshot = CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0)
+                .map_err(|e| format!("Failed to snapshot processes: {e}"))?;
+
+            let mut entry = PROCESSENTRY32W {
+                dwSize: std::mem::size_of::<PROCESSENTRY32W>() as u32,
+                ..Default::default()
+            };
+            let mut ok = Process32FirstW(snapshot, &mut entry).is_ok();
+            while ok {
+                let pid = entry.th32ProcessID;
+                // pid 0 is the idle process, its "usage" is idle time
+                if pid != 0 {
+                    let len = entry
+                        .szExeFile
+                        .iter()
+                        .position(|&c| c == 0)
+                        .unwrap_or(entry.szExeFile.len());
+                    let mut info = ProcessInfo {
+                        pid,
+                        name: String::from_utf16_lossy(&entry.szExeFile[..len]),
+                        cpu_time: None,
+                        working_set: 0,
+                    };
+
+                    if let Ok(handle) = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid) {
+                        let (mut created, mut exited, mut kernel, mut user) = Default::default();
+                        if GetProcessTimes(
+                            handle,
+                            &mut created,
+                            &mut exited,
+                            &mut kernel,
+                            &mut user,
+                        )
+                        .is_ok()
+                        {
+                            info.cpu_time = Some(filetime_to_u64(kernel) + filetime_to_u64(user));
+                        }
+
+                        let mut counters = PROCESS_MEMORY_COUNTERS {
+                            cb: std::mem::size_of::<PROCESS_MEMORY_COUNTERS>() as u32,
+                            ..Default::default()
+                        };
+                        if GetProcessMemoryInfo(handle, &mut counters, counters.cb).is_ok() {
+                            info.working_set = counters.WorkingSetSize as u64;
+                        }
+                        let _ = CloseHandle(handle);
+                    }
+
+                    processes.push(info);
+                }
+                ok = Process32NextW(snapshot, &mut entry).is_ok();
+            }
+
+            let _ = CloseHandle(snapshot);
+        }
+        Ok(processes)
+    }
+
+    fn cpu_times(processes: &[ProcessInfo]) -> HashMap<u32, u64> {
+        processes
+            .iter()
+            .filter_map(|p| p.cpu_time.map(|t| (p.pid, t)))
+            .collect()
+    }
+
+    pub fn get_top_processes(by: &str, limit: usize) -> Result<Vec<ProcessUsage>, String> {
+        let by_memory = match by.to_ascii_lowercase().as_str() {
+            "cpu" => false,
+            "memory" | "ram" => true,
+            _ => {
+                return Err(format!(
+                    "Unknown sort key: {by} (expected \"cpu\" or \"memory\")"
+                ))
+            }
+        };
+
+        let mut last = LAST_SAMPLE.lock().map_err(|e| e.to_string())?;
+        let previous = match last
+            .take()
+            .filter(|sample| sample.at.elapsed() >= MIN_SAMPLE_GAP)
+        {
+            Some(sample) => sample,
+            None => {
+                let sample = Sample {
+                    at: Instant::now(),
+                    cpu_times: cpu_times(&list_processes()?),
+                };
+                std::thread::sleep(FIRST_SAMPLE_GAP);
+                sample
+            }
+        };
+
+        let processes = list_processes()?;
+        let now = Instant::now();
+        let cpu_count = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1) as f64;
+        // FILETIME ticks are 100ns
+        let elapsed_ticks = now.duration_since(previous.at).as_nanos() as f64 / 100.0;
+
+        let mut usage: Vec<ProcessUsage> = processes
+            .iter()
+            .map(|p| {
+                let cpu_percent = match (p.cpu_time, previous.cpu_times.get(&p.pid)) {
+                    (Some(current), Some(&before)) if elapsed_ticks > 0.0 => {
+                        (current.saturating_sub(before) as f64 / elapsed_ticks / cpu_count * 100.0)
+                            .min(100.0) as f32
+                    }
+                    _ => 0.0,
+                };
+                ProcessUsage {
+                    pid: p.pid,
+                    name: p.name.clone(),
+                    cpu_percent,
+                    memory_mb: p.working_set as f64 / (1024.0 * 1024.0),
+                }
+            })
+            .collect();
+
+        *last = Some(Sample {
+            at: now,
+            cpu_times: cpu_times(&processes),
+        });
+        drop(last);
+
+        if by_memory {
+            usage.sort_by(|a, b| b.memory_mb.total_cmp(&a.memory_mb));
+        } else {
+            usage.sort_by(|a, b| {
+                b.cpu_percent
+                    .total_cmp(&a.cpu_percent)
+                    .then(b.memory_mb.total_cmp(&a.memory_mb))
+            });
+        }
+        usage.truncate(limit);
+        Ok(usage)
+    }
+
+    pub fn kill_process(pid: u32) -> Result<(), String> {
+        // 0 = System Idle Process, 4 = System
+        if pid == 0 || pid == 4 {
+            return Err(format!("Refusing to end critical system process {pid}"));
+        }
+        if pid == std::process::id() {
+            return Err("Refusing to end this application's own process".to_string());
+        }
+
+        let access_denied = || {
+            format!("Access denied ending process {pid}; protected processes require running as administrator")
+        };
+        unsafe {
+            let handle = OpenProcess(PROCESS_TERMINATE, false, pid).map_err(|e| {
+                if e.code() == E_ACCESSDENIED {
+                    access_denied()
+                } else {
+                    format!("Failed to open process {pid}: {e}")
+                }
+            })?;
+            let result = TerminateProcess(handle, 1);
+            let _ = CloseHandle(handle);
+            result.map_err(|e| {
+                if e.code() == E_ACCESSDENIED {
+                    access_denied()
+                } else {
+                    format!("Failed to end process {pid}: {e}")
+                }
+            })
+        }
+    }
+}
+
+#[cfg(windows)]
+pub use windows_impl::{get_top_processes, kill_process};
+
+// Non-Windows fallback
+#[cfg(not(windows))]
+pub fn get_top_processes(_by: &str, _limit: usize) -> Result<Vec<ProcessUsage>, String> {
+    Err("Process list is only supported on Windows".to_string())
+}
+
+#[cfg(not(windows))]
+pub fn kill_process(_pid: u32) -> Result<(), String> {
+    Err("Ending processes is only supported on Windows".to_string())
+}
@@ -0,0 +1,64 @@
+//! Public IP lookup, cached so the network popup doesn't hit the endpoint on every open
+//!
+//! Kept apart from the local interface stats: a slow or failing lookup only affects this
+//! value.
+
+use serde::{Deserialize, Serialize};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+const PUBLIC_IP_URL: &str = "https://api.ipify.org?format=json";
+const CACHE_DURATION: Duration = Duration::from_secs(5 * 60);
+const HTTP_CONNECT_TIMEOUT: Duration = Duration::from_secs(2);
+const HTTP_READ_TIMEOUT: Duration = Duration::from_secs(3);
+
+#[derive(Serialize, Clone, Debug)]
+pub struct PublicIp {
+    pub ip: String,
+    /// Served from the cache instead of a fresh lookup
+    pub cached: bool,
+}
+
+#[derive(Deserialize)]
+struct IpifyResponse {
+    ip: String,
+}
+
+static CACHE: Mutex<Option<(String, Instant)>> = Mutex::new(None);
+
+fn http_agent() -> &'static ureq::Agent {
+    static AGENT: OnceLock<ureq::Agent> = OnceLock::new();
+    AGENT.get_or_init(|| super::http::build_agent(HTTP_CONNECT_TIMEOUT, HTTP_READ_TIMEOUT))
+}
+
+fn fetch_public_ip() -> Result<String, String> {
+    let body: IpifyResponse = super::http::get_json(http_agent(), PUBLIC_IP_URL)?;
+    Ok(body.ip)
+}
+
+/// Public IP address, refreshed at most every 5 minutes.
+///
+/// If the lookup fails, the last known address is returned (marked `cached`).
+pub fn get_public_ip() -> Result<PublicIp, String> {
+    let cached = CACHE.lock().ok().and_then(|cache| cache.clone());
+    if let Some((ip, fetched_at)) = &cached {
+        if fetched_at.elapsed() < CACHE_DURATION {
+            return Ok(PublicIp {
+                ip: ip.clone(),
+                cached: true,
+            });
+        }
+    }
+
+    match fetch_public_ip() {
+        Ok(ip) => {
+            if let Ok(mut cache) = CACHE.lock() {
+                *cache = Some((ip.clone(), Instant::now()));
+            }
+            Ok(PublicIp { ip, cached: false })
+        }
+        Err(e) => cached
+            .map(|(ip, _)| PublicIp { ip, cached: true })
+            .ok_or_else(|| format!("Failed to get public IP: {e}")),
+    }
+}
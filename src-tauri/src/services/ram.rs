@@ -56,6 +56,11 @@ pub fn get_ram_info_cached(cached: &CachedSystemData) -> RamData {
         }
     }
 
+    #[cfg(not(windows))]
+    if super::demo::is_enabled() {
+        data = super::demo::ram_data();
+    }
+
     // Use cached RAM speed from WMI
     if cached.ram_speed_mhz > 0 {
         data.speed_mhz = Some(cached.ram_speed_mhz);
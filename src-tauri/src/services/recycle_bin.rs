@@ -0,0 +1,67 @@
+//! Recycle Bin size and emptying (all drives)
+
+use serde::Serialize;
+
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct RecycleBinInfo {
+    pub item_count: u64,
+    pub size_bytes: u64,
+}
+
+#[cfg(windows)]
+mod windows_impl {
+    use super::RecycleBinInfo;
+    use windows::core::PCWSTR;
+    use windows::Win32::UI::Shell::{
+        SHEmptyRecycleBinW, SHQueryRecycleBinW, SHERB_NOCONFIRMATION, SHERB_NOPROGRESSUI,
+        SHERB_NOSOUND, SHQUERYRBINFO,
+    };
+
+    pub fn get_info() -> Result<RecycleBinInfo, String> {
+        let mut info = SHQUERYRBINFO {
+            cbSize: std::mem::size_of::<SHQUERYRBINFO>() as u32,
+            ..Default::default()
+        };
+        // A null root path queries the Recycle Bins of every drive.
+        unsafe { SHQueryRecycleBinW(PCWSTR::null(), &mut info) }
+            .map_err(|e| format!("Failed to query the Recycle Bin: {e}"))?;
+
+        Ok(RecycleBinInfo {
+            item_count: info.i64NumItems.max(0) as u64,
+            size_bytes: info.i64Size.max(0) as u64,
+        })
+    }
+
+    /// Empty the Recycle Bin. With `confirm`, Windows shows its own confirmation dialog,
+    /// progress UI and sound; declining it returns an error.
+    pub fn empty(confirm: bool) -> Result<RecycleBinInfo, String> {
+        // SHEmptyRecycleBinW fails on an already-empty bin.
+        if get_info()?.item_count == 0 {
+            return Ok(RecycleBinInfo::default());
+        }
+
+        let flags = if confirm {
+            0
+        } else {
+            SHERB_NOCONFIRMATION | SHERB_NOPROGRESSUI | SHERB_NOSOUND
+        };
+        unsafe { SHEmptyRecycleBinW(None, PCWSTR::null(), flags) }
+            .map_err(|e| format!("Failed to empty the Recycle Bin: {e}"))?;
+
+        get_info()
+    }
+}
+
+#[cfg(windows)]
+pub use windows_impl::{empty, get_info};
+
+// Non-Windows fallback
+#[cfg(not(windows))]
+pub fn get_info() -> Result<RecycleBinInfo, String> {
+    Err("The Recycle Bin is only supported on Windows".to_string())
+}
+
+#[cfg(not(windows))]
+pub fn empty(_confirm: bool) -> Result<RecycleBinInfo, String> {
+    Err("The Recycle Bin is only supported on Windows".to_string())
+}
@@ -0,0 +1,186 @@
+//! Small helpers over the Win32 registry API
+
+use windows::core::{HSTRING, PWSTR};
+use windows::Win32::Foundation::{ERROR_ACCESS_DENIED, ERROR_NO_MORE_ITEMS};
+use windows::Win32::System::Registry::{
+    RegCloseKey, RegEnumValueW, RegGetValueW, RegOpenKeyExW, RegQueryInfoKeyW, RegSetKeyValueW,
+    HKEY, KEY_READ, REG_BINARY, REG_EXPAND_SZ, REG_SZ, RRF_RT_REG_BINARY, RRF_RT_REG_DWORD,
+};
+
+/// Open `subkey` for reading; the caller must close the returned key.
+fn open_read(root: HKEY, subkey: &str) -> Option<HKEY> {
+    unsafe {
+        let mut hkey = HKEY::default();
+        RegOpenKeyExW(root, &HSTRING::from(subkey), 0, KEY_READ, &mut hkey)
+            .ok()
+            .ok()?;
+        Some(hkey)
+    }
+}
+
+/// Whether `subkey` exists under `root`
+pub fn key_exists(root: HKEY, subkey: &str) -> bool {
+    match open_read(root, subkey) {
+        Some(hkey) => {
+            unsafe {
+                let _ = RegCloseKey(hkey);
+            }
+            true
+        }
+        None => false,
+    }
+}
+
+/// Number of values stored directly under `subkey`
+pub fn value_count(root: HKEY, subkey: &str) -> Option<u32> {
+    let hkey = open_read(root, subkey)?;
+    let mut count = 0u32;
+    let status = unsafe {
+        let status = RegQueryInfoKeyW(
+            hkey,
+            windows::core::PWSTR::null(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(&mut count),
+            None,
+            None,
+            None,
+            None,
+        );
+        let _ = RegCloseKey(hkey);
+        status
+    };
+    status.is_ok().then_some(count)
+}
+
+/// Read a REG_DWORD value
+pub fn read_dword(root: HKEY, subkey: &str, value: &str) -> Option<u32> {
+    let mut data = 0u32;
+    let mut size = std::mem::size_of::<u32>() as u32;
+    let status = unsafe {
+        RegGetValueW(
+            root,
+            &HSTRING::from(subkey),
+            &HSTRING::from(value),
+            RRF_RT_REG_DWORD,
+            None,
+            Some(&mut data as *mut u32 as *mut _),
+            Some(&mut size),
+        )
+    };
+    status.is_ok().then_some(data)
+}
+
+/// Read a REG_BINARY value
+pub fn read_binary(root: HKEY, subkey: &str, value: &str) -> Option<Vec<u8>> {
+    let subkey = HSTRING::from(subkey);
+    let value = HSTRING::from(value);
+    unsafe {
+        let mut size = 0u32;
+        RegGetValueW(
+            root,
+            &subkey,
+            &value,
+            RRF_RT_REG_BINARY,
+            None,
+            None,
+            Some(&mut size),
+        )
+        .ok()
+        .ok()?;
+
+        let mut data = vec![0u8; size as usize];
+        RegGetValueW(
+            root,
+            &subkey,
+            &value,
+            RRF_RT_REG_BINARY,
+            None,
+            Some(data.as_mut_ptr() as *mut _),
+            Some(&mut size),
+        )
+        .ok()
+        .ok()?;
+        data.truncate(size as usize);
+        Some(data)
+    }
+}
+
+/// Write a REG_BINARY value, creating `subkey` if needed
+pub fn write_binary(root: HKEY, subkey: &str, value: &str, data: &[u8]) -> Result<(), String> {
+    let status = unsafe {
+        RegSetKeyValueW(
+            root,
+            &HSTRING::from(subkey),
+            &HSTRING::from(value),
+            REG_BINARY.0,
+            Some(data.as_ptr() as *const _),
+            data.len() as u32,
+        )
+    };
+    if status == ERROR_ACCESS_DENIED {
+        return Err(format!(
+            "Access denied writing {subkey}\\{value} (administrator rights required)"
+        ));
+    }
+    status
+        .ok()
+        .map_err(|e| format!("Failed to write {subkey}\\{value}: {e}"))
+}
+
+/// All REG_SZ / REG_EXPAND_SZ values directly under `subkey`, as (name, data) pairs
+pub fn string_values(root: HKEY, subkey: &str) -> Vec<(String, String)> {
+    let Some(hkey) = open_read(root, subkey) else {
+        return Vec::new();
+    };
+
+    let mut values = Vec::new();
+    let mut index = 0;
+    loop {
+        // Value names are limited to 16383 chars; startup commands are far below 32 KB.
+        let mut name = vec![0u16; 16_384];
+        let mut name_len = name.len() as u32;
+        let mut data = vec![0u8; 32_768];
+        let mut data_len = data.len() as u32;
+        let mut kind = 0u32;
+
+        let status = unsafe {
+            RegEnumValueW(
+                hkey,
+                index,
+                PWSTR(name.as_mut_ptr()),
+                &mut name_len,
+                None,
+                Some(&mut kind),
+                Some(data.as_mut_ptr()),
+                Some(&mut data_len),
+            )
+        };
+        index += 1;
+
+        if status == ERROR_NO_MORE_ITEMS {
+            break;
+        }
+        if status.is_err() || (kind != REG_SZ.0 && kind != REG_EXPAND_SZ.0) {
+            continue;
+        }
+
+        let wide: Vec<u16> = data[..data_len as usize]
+            .chunks_exact(2)
+            .map(|c| u16::from_le_bytes([c[0], c[1]]))
+            .take_while(|&c| c != 0)
+            .collect();
+        values.push((
+            String::from_utf16_lossy(&name[..name_len as usize]),
+            String::from_utf16_lossy(&wide),
+        ));
+    }
+
+    unsafe {
+        let _ = RegCloseKey(hkey);
+    }
+    values
+}
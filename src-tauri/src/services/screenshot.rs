@@ -0,0 +1,125 @@
+//! Screen capture for the screenshot commands
+//!
+//! Captures a rectangle of the virtual desktop (physical pixels, the same coordinates
+//! `list_monitors` reports) with GDI `BitBlt` into a 32-bit top-down DIB.
+
+/// RGBA pixels of a captured screen area
+pub struct Capture {
+    pub width: u32,
+    pub height: u32,
+    pub rgba: Vec<u8>,
+}
+
+#[cfg(windows)]
+mod windows_impl {
+    use super::Capture;
+    use windows::Win32::Graphics::Gdi::{
+        BitBlt, CreateCompatibleBitmap, CreateCompatibleDC, DeleteDC, DeleteObject, GetDC,
+        GetDIBits, ReleaseDC, SelectObject, BITMAPINFO, BITMAPINFOHEADER, BI_RGB, CAPTUREBLT,
+        DIB_RGB_COLORS, RGBQUAD, SRCCOPY,
+    };
+
+    /// Encode a capture as a `data:image/png;base64,...` URL.
+    pub fn to_png_data_url(capture: &Capture) -> Result<String, String> {
+        crate::services::image::encode_rgba_png_data_url(
+            capture.width,
+            capture.height,
+            &capture.rgba,
+        )
+    }
+
+    /// Capture a screen rectangle given in virtual-desktop coordinates.
+    pub fn capture_rect(x: i32, y: i32, width: u32, height: u32) -> Result<Capture, String> {
+        if width == 0 || height == 0 {
+            return Err("Capture area is empty".to_string());
+        }
+
+        unsafe {
+            let screen_dc = GetDC(None);
+            if screen_dc.is_invalid() {
+                return Err("Failed to get the screen device context".to_string());
+            }
+
+            let mem_dc = CreateCompatibleDC(screen_dc);
+            let bitmap = CreateCompatibleBitmap(screen_dc, width as i32, height as i32);
+            let old_bitmap = SelectObject(mem_dc, bitmap);
+
+            // CAPTUREBLT includes layered windows (popups, tooltips) in the capture.
+            let blit = BitBlt(
+                mem_dc,
+                0,
+                0,
+                width as i32,
+                height as i32,
+                screen_dc,
+                x,
+                y,
+                SRCCOPY | CAPTUREBLT,
+            );
+
+            let mut bmi = BITMAPINFO {
+                bmiHeader: BITMAPINFOHEADER {
+                    biSize: std::mem::size_of::<BITMAPINFOHEADER>() as u32,
+                    biWidth: width as i32,
+                    biHeight: -(height as i32), // Top-down
+                    biPlanes: 1,
+                    biBitCount: 32,
+                    biCompression: BI_RGB.0,
+                    ..Default::default()
+                },
+                bmiColors: [RGBQUAD::default()],
+            };
+
+            let mut pixels: Vec<u8> = vec![0; (width * height * 4) as usize];
+            let _ = SelectObject(mem_dc, old_bitmap);
+            let lines = if blit.is_ok() {
+                GetDIBits(
+                    mem_dc,
+                    bitmap,
+                    0,
+                    height,
+                    Some(pixels.as_mut_ptr() as *mut _),
+                    &mut bmi,
+                    DIB_RGB_COLORS,
+                )
+            } else {
+                0
+            };
+
+            let _ = DeleteObject(bitmap);
+            let _ = DeleteDC(mem_dc);
+            let _ = ReleaseDC(None, screen_dc);
+
+            blit.map_err(|e| format!("Failed to capture the screen: {e}"))?;
+            if lines == 0 {
+                return Err("Failed to read the captured bitmap".to_string());
+            }
+
+            // BGRX -> RGBA (the screen has no alpha channel)
+            for chunk in pixels.chunks_exact_mut(4) {
+                chunk.swap(0, 2);
+                chunk[3] = 255;
+            }
+
+            Ok(Capture {
+                width,
+                height,
+                rgba: pixels,
+            })
+        }
+    }
+}
+
+#[cfg(windows)]
+pub use windows_impl::{capture_rect, to_png_data_url};
+
+// Non-Windows fallback
+#[cfg(not(windows))]
+pub fn capture_rect(_x: i32, _y: i32, _width: u32, _height: u32) -> Result<Capture, String> {
+    Err("Screenshots are only supported on Windows".to_string())
+}
+
+#[cfg(not(windows))]
+pub fn to_png_data_url(_capture: &Capture) -> Result<String, String> {
+    Err("Screenshots are only supported on Windows".to_string())
+}
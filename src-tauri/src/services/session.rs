@@ -0,0 +1,166 @@
+//! Workstation lock detection
+//!
+//! A hidden message-only window receives `WM_WTSSESSION_CHANGE` notifications. Lock and
+//! unlock are forwarded to the frontend as `session-locked` / `session-unlocked` events,
+//! and the background polling loops use `sleep_poll` to back off while locked.
+
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+#[cfg(windows)]
+use std::sync::OnceLock;
+use tauri::AppHandle;
+#[cfg(windows)]
+use tauri::Emitter;
+
+/// Polling interval used by background loops while the workstation is locked
+pub const LOCKED_POLL_INTERVAL: Duration = Duration::from_secs(30);
+/// How often a locked sleep checks whether the session was unlocked
+const UNLOCK_CHECK_INTERVAL: Duration = Duration::from_millis(500);
+
+static LOCKED: AtomicBool = AtomicBool::new(false);
+
+#[cfg(windows)]
+static APP_HANDLE: OnceLock<AppHandle> = OnceLock::new();
+
+#[derive(Serialize, Clone, Debug)]
+pub struct SessionState {
+    pub locked: bool,
+}
+
+pub fn is_locked() -> bool {
+    LOCKED.load(Ordering::SeqCst)
+}
+
+pub fn get_state() -> SessionState {
+    SessionState {
+        locked: is_locked(),
+    }
+}
+
+/// Sleep between two polls: `normal` while unlocked, `LOCKED_POLL_INTERVAL` while locked.
+/// A locked sleep ends early as soon as the session is unlocked.
+pub fn sleep_poll(normal: Duration) {
+    if !is_locked() {
+        std::thread::sleep(normal);
+        return;
+    }
+
+    let started = Instant::now();
+    while is_locked() && started.elapsed() < LOCKED_POLL_INTERVAL {
+        std::thread::sleep(UNLOCK_CHECK_INTERVAL);
+    }
+}
+
+#[cfg(windows)]
+fn set_locked(locked: bool) {
+    if LOCKED.swap(locked, Ordering::SeqCst) == locked {
+        return;
+    }
+    if let Some(app) = APP_HANDLE.get() {
+        let event = if locked {
+            "session-locked"
+        } else {
+            "session-unlocked"
+        };
+        let _ = app.emit(event, get_state());
+    }
+}
+
+#[cfg(windows)]
+mod windows_impl {
+    use super::*;
+    use windows::core::w;
+    use windows::Win32::Foundation::{HWND, LPARAM, LRESULT, WPARAM};
+    use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+    use windows::Win32::System::RemoteDesktop::{
+        WTSRegisterSessionNotification, NOTIFY_FOR_THIS_SESSION,
+    };
+    use windows::Win32::UI::WindowsAndMessaging::{
+        CreateWindowExW, DefWindowProcW, DispatchMessageW, GetMessageW, RegisterClassW,
+        TranslateMessage, HWND_MESSAGE, MSG, WINDOW_EX_STYLE, WINDOW_STYLE, WM_WTSSESSION_CHANGE,
+        WNDCLASSW, WTS_SESSION_LOCK, WTS_SESSION_UNLOCK,
+    };
+
+    unsafe extern "system" fn wnd_proc(
+        hwnd: HWND,
+        msg: u32,
+        wparam: WPARAM,
+        lparam: LPARAM,
+    ) -> LRESULT {
+        if msg == WM_WTSSESSION_CHANGE {
+            match wparam.0 as u32 {
+                WTS_SESSION_LOCK => set_locked(true),
+                WTS_SESSION_UNLOCK => set_locked(false),
+                _ => {}
+            }
+            return LRESULT(0);
+        }
+        DefWindowProcW(hwnd, msg, wparam, lparam)
+    }
+
+    /// Create the message-only window and pump its messages (blocks the calling thread).
+    pub fn run_message_loop() -> Result<(), String> {
+        unsafe {
+            let instance = GetModuleHandleW(None).map_err(|e| e.to_string())?;
+            let class_name = w!("BarMinimalSessionWatcher");
+
+            let class = WNDCLASSW {
+                lpfnWndProc: Some(wnd_proc),
+                hInstance: instance.into(),
+                lpszClassName: class_name,
+                ..Default::default()
+            };
+            if RegisterClassW(&class) == 0 {
+                return Err("Failed to register session watcher window class".to_string());
+            }
+
+            let hwnd = CreateWindowExW(
+                WINDOW_EX_STYLE(0),
+                class_name,
+                w!(""),
+                WINDOW_STYLE(0),
+                0,
+                0,
+                0,
+                0,
+                HWND_MESSAGE,
+                None,
+                instance,
+                None,
+            )
+            .map_err(|e| e.to_string())?;
+
+            WTSRegisterSessionNotification(hwnd, NOTIFY_FOR_THIS_SESSION)
+                .map_err(|e| e.to_string())?;
+
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, None, 0, 0).as_bool() {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Start listening for lock/unlock notifications.
+#[cfg(windows)]
+pub fn start_watcher(app: AppHandle) {
+    if APP_HANDLE.set(app).is_err() {
+        return;
+    }
+
+    std::thread::Builder::new()
+        .name("session-watcher".to_string())
+        .spawn(|| {
+            if let Err(e) = windows_impl::run_message_loop() {
+                eprintln!("Session watcher failed: {e}");
+            }
+        })
+        .ok();
+}
+
+#[cfg(not(windows))]
+pub fn start_watcher(_app: AppHandle) {}
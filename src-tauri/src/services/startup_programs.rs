@@ -0,0 +1,156 @@
+//! Programs launched at sign-in (Task Manager's Startup tab)
+//!
+//! Entries come from the `Run` keys and the user's Startup folder. Like Task Manager,
+//! enabling/disabling doesn't remove anything: it writes the matching `StartupApproved`
+//! value, whose first byte is even when the entry is enabled and odd when disabled.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StartupLocation {
+    /// HKCU\Software\Microsoft\Windows\CurrentVersion\Run
+    HkcuRun,
+    /// HKLM\Software\Microsoft\Windows\CurrentVersion\Run (changes need admin rights)
+    HklmRun,
+    /// %APPDATA%\Microsoft\Windows\Start Menu\Programs\Startup
+    StartupFolder,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct StartupEntry {
+    pub name: String,
+    /// Command line (Run keys) or file path (Startup folder)
+    pub command: String,
+    pub location: StartupLocation,
+    pub enabled: bool,
+}
+
+#[cfg(windows)]
+mod windows_impl {
+    use super::{StartupEntry, StartupLocation};
+    use crate::services::registry;
+    use std::path::PathBuf;
+    use windows::Win32::System::Registry::{HKEY, HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE};
+
+    const RUN_KEY: &str = r"Software\Microsoft\Windows\CurrentVersion\Run";
+    const APPROVED_KEY: &str =
+        r"Software\Microsoft\Windows\CurrentVersion\Explorer\StartupApproved";
+
+    /// Seconds between 1601-01-01 (FILETIME epoch) and 1970-01-01
+    const FILETIME_UNIX_OFFSET_SECS: i64 = 11_644_473_600;
+
+    impl StartupLocation {
+        fn root(self) -> HKEY {
+            match self {
+                StartupLocation::HklmRun => HKEY_LOCAL_MACHINE,
+                StartupLocation::HkcuRun | StartupLocation::StartupFolder => HKEY_CURRENT_USER,
+            }
+        }
+
+        fn approved_key(self) -> String {
+            match self {
+                StartupLocation::HkcuRun | StartupLocation::HklmRun => {
+                    format!(r"{APPROVED_KEY}\Run")
+                }
+                StartupLocation::StartupFolder => format!(r"{APPROVED_KEY}\StartupFolder"),
+            }
+        }
+    }
+
+    fn startup_folder() -> Option<PathBuf> {
+        dirs::config_dir().map(|appdata| {
+            appdata
+                .join("Microsoft")
+                .join("Windows")
+                .join("Start Menu")
+                .join("Programs")
+                .join("Startup")
+        })
+    }
+
+    fn is_enabled(location: StartupLocation, name: &str) -> bool {
+        // No StartupApproved value means the entry was never toggled: enabled.
+        registry::read_binary(location.root(), &location.approved_key(), name)
+            .and_then(|data| data.first().copied())
+            .map(|flag| flag % 2 == 0)
+            .unwrap_or(true)
+    }
+
+    fn run_entries(location: StartupLocation) -> Vec<StartupEntry> {
+        registry::string_values(location.root(), RUN_KEY)
+            .into_iter()
+            .map(|(name, command)| StartupEntry {
+                enabled: is_enabled(location, &name),
+                name,
+                command,
+                location,
+            })
+            .collect()
+    }
+
+    fn folder_entries() -> Vec<StartupEntry> {
+        let Some(folder) = startup_folder() else {
+            return Vec::new();
+        };
+        let Ok(dir) = std::fs::read_dir(&folder) else {
+            return Vec::new();
+        };
+
+        dir.flatten()
+            .filter(|e| e.path().is_file())
+            .filter_map(|e| {
+                let name = e.file_name().to_string_lossy().to_string();
+                (!name.eq_ignore_ascii_case("desktop.ini")).then(|| StartupEntry {
+                    enabled: is_enabled(StartupLocation::StartupFolder, &name),
+                    command: e.path().to_string_lossy().to_string(),
+                    location: StartupLocation::StartupFolder,
+                    name,
+                })
+            })
+            .collect()
+    }
+
+    pub fn list_startup_programs() -> Vec<StartupEntry> {
+        let mut entries = run_entries(StartupLocation::HkcuRun);
+        entries.extend(run_entries(StartupLocation::HklmRun));
+        entries.extend(folder_entries());
+        entries
+    }
+
+    pub fn set_enabled(name: &str, location: StartupLocation, enabled: bool) -> Result<(), String> {
+        let exists = list_startup_programs()
+            .iter()
+            .any(|e| e.location == location && e.name == name);
+        if !exists {
+            return Err(format!("Startup entry not found: {name}"));
+        }
+
+        // 12 bytes: state flag, 3 reserved bytes, then the FILETIME it was disabled at.
+        let mut data = [0u8; 12];
+        if enabled {
+            data[0] = 0x02;
+        } else {
+            data[0] = 0x03;
+            let filetime =
+                (chrono::Utc::now().timestamp() + FILETIME_UNIX_OFFSET_SECS) as u64 * 10_000_000;
+            data[4..].copy_from_slice(&filetime.to_le_bytes());
+        }
+
+        registry::write_binary(location.root(), &location.approved_key(), name, &data)
+    }
+}
+
+#[cfg(windows)]
+pub use windows_impl::{list_startup_programs, set_enabled};
+
+// Non-Windows fallback
+#[cfg(not(windows))]
+pub fn list_startup_programs() -> Vec<StartupEntry> {
+    Vec::new()
+}
+
+#[cfg(not(windows))]
+pub fn set_enabled(_name: &str, _location: StartupLocation, _enabled: bool) -> Result<(), String> {
+    Err("Startup programs are only supported on Windows".to_string())
+}
@@ -0,0 +1,119 @@
+//! CSV telemetry logging for benchmarking
+//!
+//! Appends one row per interval with the metrics the WMI service already caches, so
+//! logging never triggers extra hardware queries of its own.
+
+use super::wmi_service::WmiService;
+use std::fs::OpenOptions;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+const CSV_HEADER: &str = "timestamp,cpu_usage_percent,cpu_clock_mhz,ram_usage_percent,ram_used_mb,gpu_usage_percent,gpu_temp_c,gpu_vram_used_mb,download_bytes_sec,upload_bytes_sec";
+
+/// Shortest accepted logging interval
+const MIN_INTERVAL: Duration = Duration::from_millis(100);
+/// How often the logger thread checks for a stop request while waiting
+const STOP_POLL: Duration = Duration::from_millis(100);
+
+/// Running logger (stop flag + thread)
+static LOGGER: Mutex<Option<(Arc<AtomicBool>, JoinHandle<()>)>> = Mutex::new(None);
+
+fn csv_row(service: &WmiService) -> String {
+    let cached = service.get_cached_data();
+    let ram = super::ram::get_ram_info_cached(&cached);
+    let nvidia = &cached.nvidia_gpu;
+    let gpu_temp = if nvidia.available {
+        nvidia.temperature_c.to_string()
+    } else {
+        String::new()
+    };
+
+    format!(
+        "{},{:.1},{},{:.1},{},{:.1},{},{},{},{}",
+        chrono::Local::now().to_rfc3339(),
+        cached.cpu_usage,
+        cached.cpu_clock_mhz,
+        ram.usage_percent,
+        ram.used_bytes / (1024 * 1024),
+        cached.gpu_usage_percent,
+        gpu_temp,
+        cached.gpu_vram_used_mb,
+        cached.network.download_bytes_sec,
+        cached.network.upload_bytes_sec,
+    )
+}
+
+fn run(
+    service: Arc<WmiService>,
+    mut writer: BufWriter<std::fs::File>,
+    interval: Duration,
+    stop: Arc<AtomicBool>,
+) {
+    while !stop.load(Ordering::SeqCst) {
+        let next = Instant::now() + interval;
+        if let Err(e) = writeln!(writer, "{}", csv_row(&service)) {
+            eprintln!("Telemetry logging stopped: {}", e);
+            break;
+        }
+
+        while !stop.load(Ordering::SeqCst) {
+            let remaining = next.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                break;
+            }
+            std::thread::sleep(remaining.min(STOP_POLL));
+        }
+    }
+    let _ = writer.flush();
+}
+
+/// Start appending telemetry rows to `path` (replaces any running logger).
+pub fn start(service: Arc<WmiService>, path: &str, interval_ms: u64) -> Result<(), String> {
+    let path = PathBuf::from(super::paths::expand_path(path.trim()));
+    if path.as_os_str().is_empty() {
+        return Err("Log file path is empty".to_string());
+    }
+    let interval = Duration::from_millis(interval_ms).max(MIN_INTERVAL);
+
+    stop();
+
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    let is_empty = file.metadata().map(|m| m.len() == 0).unwrap_or(true);
+    let mut writer = BufWriter::new(file);
+    if is_empty {
+        writeln!(writer, "{}", CSV_HEADER).map_err(|e| e.to_string())?;
+    }
+
+    let stop_flag = Arc::new(AtomicBool::new(false));
+    let thread_stop = Arc::clone(&stop_flag);
+    let handle = std::thread::Builder::new()
+        .name("telemetry-log".to_string())
+        .spawn(move || run(service, writer, interval, thread_stop))
+        .map_err(|e| e.to_string())?;
+
+    if let Ok(mut logger) = LOGGER.lock() {
+        *logger = Some((stop_flag, handle));
+    }
+    Ok(())
+}
+
+/// Stop the running logger and wait for it to flush. Returns whether one was running.
+pub fn stop() -> bool {
+    let running = LOGGER.lock().ok().and_then(|mut logger| logger.take());
+    match running {
+        Some((stop_flag, handle)) => {
+            stop_flag.store(true, Ordering::SeqCst);
+            let _ = handle.join();
+            true
+        }
+        None => false,
+    }
+}
@@ -0,0 +1,133 @@
+//! Windows accent color and light/dark app theme, read from the registry
+
+use serde::Serialize;
+
+#[derive(Serialize, Clone, Debug, Default, PartialEq)]
+pub struct AccentColor {
+    /// "#rrggbb"
+    pub hex: String,
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    /// Apps use the dark theme (`AppsUseLightTheme` = 0)
+    pub is_dark_theme: bool,
+}
+
+#[cfg(windows)]
+const DWM_KEY: &str = r"Software\Microsoft\Windows\DWM";
+#[cfg(windows)]
+const PERSONALIZE_KEY: &str = r"Software\Microsoft\Windows\CurrentVersion\Themes\Personalize";
+
+#[cfg(windows)]
+pub fn get_accent_color() -> Result<AccentColor, String> {
+    use super::registry;
+    use windows::Win32::Foundation::BOOL;
+    use windows::Win32::Graphics::Dwm::DwmGetColorizationColor;
+    use windows::Win32::System::Registry::HKEY_CURRENT_USER;
+
+    // AccentColor is 0xAABBGGRR; the DWM colorization color (0xAARRGGBB) is the fallback.
+    let (r, g, b) = match registry::read_dword(HKEY_CURRENT_USER, DWM_KEY, "AccentColor") {
+        Some(abgr) => (abgr as u8, (abgr >> 8) as u8, (abgr >> 16) as u8),
+        None => {
+            let mut argb = 0u32;
+            let mut opaque = BOOL::default();
+            unsafe { DwmGetColorizationColor(&mut argb, &mut opaque) }
+                .map_err(|e| format!("Failed to read the accent color: {e}"))?;
+            ((argb >> 16) as u8, (argb >> 8) as u8, argb as u8)
+        }
+    };
+
+    // Missing value means the default (light) theme.
+    let is_dark_theme =
+        registry::read_dword(HKEY_CURRENT_USER, PERSONALIZE_KEY, "AppsUseLightTheme") == Some(0);
+
+    Ok(AccentColor {
+        hex: format!("#{r:02x}{g:02x}{b:02x}"),
+        r,
+        g,
+        b,
+        is_dark_theme,
+    })
+}
+
+#[cfg(not(windows))]
+pub fn get_accent_color() -> Result<AccentColor, String> {
+    Err("Accent color is only supported on Windows".to_string())
+}
+
+/// Watch the DWM and Personalize keys and emit `system-theme-changed` when the accent color
+/// or light/dark theme changes.
+#[cfg(windows)]
+pub fn start_watcher(app: tauri::AppHandle) {
+    use std::time::Duration;
+    use tauri::Emitter;
+    use windows::core::{HSTRING, PCWSTR};
+    use windows::Win32::Foundation::{CloseHandle, HANDLE, WAIT_OBJECT_0};
+    use windows::Win32::System::Registry::{
+        RegNotifyChangeKeyValue, RegOpenKeyExW, HKEY, HKEY_CURRENT_USER, KEY_NOTIFY,
+        REG_NOTIFY_CHANGE_LAST_SET,
+    };
+    use windows::Win32::System::Threading::{CreateEventW, WaitForMultipleObjects, INFINITE};
+
+    fn arm(key: HKEY, event: HANDLE) -> bool {
+        unsafe {
+            RegNotifyChangeKeyValue(key, false, REG_NOTIFY_CHANGE_LAST_SET, event, true).is_ok()
+        }
+    }
+
+    std::thread::Builder::new()
+        .name("theme-watcher".to_string())
+        .spawn(move || unsafe {
+            let mut keys = Vec::new();
+            let mut events = Vec::new();
+            for subkey in [DWM_KEY, PERSONALIZE_KEY] {
+                let mut key = HKEY::default();
+                if RegOpenKeyExW(
+                    HKEY_CURRENT_USER,
+                    &HSTRING::from(subkey),
+                    0,
+                    KEY_NOTIFY,
+                    &mut key,
+                )
+                .is_err()
+                {
+                    continue;
+                }
+                let Ok(event) = CreateEventW(None, false, false, PCWSTR::null()) else {
+                    continue;
+                };
+                if arm(key, event) {
+                    keys.push(key);
+                    events.push(event);
+                } else {
+                    let _ = CloseHandle(event);
+                }
+            }
+            if events.is_empty() {
+                return;
+            }
+
+            let mut last = get_accent_color().ok();
+            loop {
+                let signaled = WaitForMultipleObjects(&events, false, INFINITE);
+                let index = signaled.0.wrapping_sub(WAIT_OBJECT_0.0) as usize;
+                if index >= events.len() || !arm(keys[index], events[index]) {
+                    break;
+                }
+
+                // Theme switches write several values in a row; read once they settle.
+                std::thread::sleep(Duration::from_millis(250));
+                let current = get_accent_color().ok();
+                if current != last {
+                    if let Some(color) = &current {
+                        let _ = app.emit("system-theme-changed", color.clone());
+                    }
+                    last = current;
+                }
+            }
+        })
+        .ok();
+}
+
+#[cfg(not(windows))]
+pub fn start_watcher(_app: tauri::AppHandle) {}
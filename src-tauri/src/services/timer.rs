@@ -0,0 +1,146 @@
+//! Countdown timers (Pomodoro and friends)
+//!
+//! Timers live in memory only. A single background thread, started with the first timer,
+//! emits `timer-tick` (all active timers) every second and `timer-finished` when one ends.
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+
+const TICK_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Serialize, Clone, Debug)]
+pub struct TimerState {
+    pub id: String,
+    pub label: String,
+    pub duration_seconds: u64,
+    pub remaining_seconds: u64,
+    /// RFC 3339 timestamp of when the timer was started
+    pub started_at: String,
+}
+
+struct Timer {
+    label: String,
+    duration: Duration,
+    started_at: String,
+    ends_at: Instant,
+}
+
+impl Timer {
+    fn state(&self, id: &str) -> TimerState {
+        let remaining = self.ends_at.saturating_duration_since(Instant::now());
+        TimerState {
+            id: id.to_string(),
+            label: self.label.clone(),
+            duration_seconds: self.duration.as_secs(),
+            // Round up so a timer shows 1s until it actually finishes
+            remaining_seconds: remaining.as_millis().div_ceil(1000) as u64,
+            started_at: self.started_at.clone(),
+        }
+    }
+}
+
+static TIMERS: OnceLock<Mutex<HashMap<String, Timer>>> = OnceLock::new();
+static TICKER: OnceLock<()> = OnceLock::new();
+
+fn timers() -> &'static Mutex<HashMap<String, Timer>> {
+    TIMERS.get_or_init(Default::default)
+}
+
+fn generate_timer_id(existing: &HashMap<String, Timer>) -> String {
+    let base = chrono::Utc::now().timestamp_millis();
+    let mut suffix: u32 = 0;
+    loop {
+        let id = if suffix == 0 {
+            format!("timer_{base}")
+        } else {
+            format!("timer_{base}_{suffix}")
+        };
+        if !existing.contains_key(&id) {
+            return id;
+        }
+        suffix = suffix.saturating_add(1);
+    }
+}
+
+fn start_ticker(app: AppHandle) {
+    TICKER.get_or_init(|| {
+        std::thread::Builder::new()
+            .name("timers".to_string())
+            .spawn(move || loop {
+                std::thread::sleep(TICK_INTERVAL);
+
+                let (active, finished) = {
+                    let Ok(mut timers) = timers().lock() else {
+                        continue;
+                    };
+                    let now = Instant::now();
+                    let done: Vec<String> = timers
+                        .iter()
+                        .filter(|(_, t)| t.ends_at <= now)
+                        .map(|(id, _)| id.clone())
+                        .collect();
+                    let finished: Vec<TimerState> = done
+                        .iter()
+                        .filter_map(|id| timers.remove(id).map(|t| t.state(id)))
+                        .collect();
+                    let active: Vec<TimerState> =
+                        timers.iter().map(|(id, t)| t.state(id)).collect();
+                    (active, finished)
+                };
+
+                for state in &finished {
+                    let _ = app.emit("timer-finished", state);
+                }
+                if !active.is_empty() {
+                    let _ = app.emit("timer-tick", &active);
+                }
+            })
+            .ok();
+    });
+}
+
+/// Start a countdown of `seconds` and return its state.
+pub fn start_timer(app: AppHandle, seconds: u64, label: String) -> Result<TimerState, String> {
+    if seconds == 0 {
+        return Err("Timer duration must be at least 1 second".to_string());
+    }
+
+    let mut timers = timers().lock().map_err(|e| e.to_string())?;
+    let id = generate_timer_id(&timers);
+    let duration = Duration::from_secs(seconds);
+    let timer = Timer {
+        label,
+        duration,
+        started_at: chrono::Utc::now().to_rfc3339(),
+        ends_at: Instant::now() + duration,
+    };
+    let state = timer.state(&id);
+    timers.insert(id, timer);
+    drop(timers);
+
+    start_ticker(app);
+    Ok(state)
+}
+
+/// Stop a timer without emitting `timer-finished`.
+pub fn cancel_timer(id: &str) -> Result<(), String> {
+    timers()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .remove(id)
+        .map(|_| ())
+        .ok_or_else(|| "Timer not found".to_string())
+}
+
+/// Active timers, soonest to finish first
+pub fn list_timers() -> Vec<TimerState> {
+    let mut list: Vec<TimerState> = timers()
+        .lock()
+        .map(|timers| timers.iter().map(|(id, t)| t.state(id)).collect())
+        .unwrap_or_default();
+    list.sort_by_key(|t| t.remaining_seconds);
+    list
+}
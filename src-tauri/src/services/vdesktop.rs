@@ -0,0 +1,328 @@
+//! Virtual desktop listing and switching
+//!
+//! Windows has no public API for this, so it goes through the undocumented
+//! `IVirtualDesktopManagerInternal` interface exposed by Explorer's immersive shell.
+//! Its IID and vtable layout change between Windows builds, so the layout is picked
+//! from the build number and anything unknown reports "unsupported" instead of
+//! calling into a vtable we don't understand.
+
+use serde::Serialize;
+
+#[derive(Serialize, Clone, Debug)]
+pub struct VirtualDesktop {
+    /// Position in the task view order (0-based)
+    pub index: usize,
+    /// Desktop GUID, e.g. "{3F07F4BE-...}"
+    pub id: String,
+    /// User-assigned name, or "Desktop N" when unnamed
+    pub name: String,
+    pub is_current: bool,
+}
+
+#[cfg(windows)]
+mod windows_impl {
+    use super::VirtualDesktop;
+    use crate::services::registry;
+    use std::ffi::c_void;
+    use windows::core::{IUnknown, Interface, GUID, HRESULT};
+    use windows::Win32::System::Com::{
+        CoCreateInstance, CoInitializeEx, IServiceProvider, CLSCTX_LOCAL_SERVER,
+        COINIT_MULTITHREADED,
+    };
+    use windows::Win32::System::Registry::{HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE};
+    use windows::Win32::UI::Shell::Common::IObjectArray;
+
+    const CLSID_IMMERSIVE_SHELL: GUID = GUID::from_u128(0xC2F03A33_21F5_47FA_B4BB_156362A2F239);
+    const CLSID_VIRTUAL_DESKTOP_MANAGER_INTERNAL: GUID =
+        GUID::from_u128(0xC5E0CDCA_7B6E_41B2_9FC4_D93975CC467B);
+
+    const DESKTOPS_KEY: &str =
+        r"Software\Microsoft\Windows\CurrentVersion\Explorer\VirtualDesktops\Desktops";
+
+    // Vtable slots shared by every known layout (after the 3 IUnknown methods)
+    const SLOT_GET_CURRENT_DESKTOP: usize = 6;
+    const SLOT_DESKTOP_GET_ID: usize = 4;
+
+    /// Build-specific shape of `IVirtualDesktopManagerInternal`
+    struct Layout {
+        manager_iid: GUID,
+        desktop_iid: GUID,
+        /// Windows 11 21H2 takes an extra HMONITOR first argument (null = all monitors)
+        takes_monitor: bool,
+        slot_get_desktops: usize,
+        slot_switch_desktop: usize,
+    }
+
+    fn windows_build() -> Option<(u32, u32)> {
+        const KEY: &str = r"SOFTWARE\Microsoft\Windows NT\CurrentVersion";
+        let build = registry::string_values(HKEY_LOCAL_MACHINE, KEY)
+            .into_iter()
+            .find(|(name, _)| name == "CurrentBuildNumber")
+            .and_then(|(_, value)| value.trim().parse().ok())?;
+        let revision = registry::read_dword(HKEY_LOCAL_MACHINE, KEY, "UBR").unwrap_or(0);
+        Some((build, revision))
+    }
+
+    fn layout_for_build(build: u32, revision: u32) -> Option<Layout> {
+        const WIN10_DESKTOP: GUID = GUID::from_u128(0xFF72FFDD_BE7E_43FC_9C03_AD81681E88E4);
+        const WIN11_21H2_DESKTOP: GUID = GUID::from_u128(0x536D3495_B208_4CC9_AE26_DE8111275BF8);
+        const WIN11_DESKTOP: GUID = GUID::from_u128(0x3F07F4BE_B107_441A_AF0F_39D82529072C);
+
+        match build {
+            // Windows 10 1809 .. 22H2
+            17763..=19045 => Some(Layout {
+                manager_iid: GUID::from_u128(0xF31574D6_B682_4CDC_BD56_1827860ABEC6),
+                desktop_iid: WIN10_DESKTOP,
+                takes_monitor: false,
+                slot_get_desktops: 7,
+                slot_switch_desktop: 9,
+            }),
+            // Windows 11 21H2
+            22000..=22620 => Some(Layout {
+                manager_iid: GUID::from_u128(0xB2F925B9_5A0F_4D2E_9F4D_2B1507593C10),
+                desktop_iid: WIN11_21H2_DESKTOP,
+                takes_monitor: true,
+                slot_get_desktops: 8,
+                slot_switch_desktop: 10,
+            }),
+            // Windows 11 22H2 / 23H2 before the January 2024 update
+            22621..=22631 if revision < 3085 => Some(Layout {
+                manager_iid: GUID::from_u128(0xA3175F2D_239C_4BD2_8AA0_EEBA8B0B138E),
+                desktop_iid: WIN11_DESKTOP,
+                takes_monitor: false,
+                slot_get_desktops: 7,
+                slot_switch_desktop: 9,
+            }),
+            // Windows 11 22H2 / 23H2 (3085+) and 24H2
+            22621.. => Some(Layout {
+                manager_iid: GUID::from_u128(0x53F5CA0B_158F_4124_900C_057158060B27),
+                desktop_iid: WIN11_DESKTOP,
+                takes_monitor: false,
+                slot_get_desktops: 7,
+                slot_switch_desktop: 9,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Raw method pointer from a COM object's vtable
+    unsafe fn vtable_slot(object: &IUnknown, slot: usize) -> *const c_void {
+        let vtable = *(object.as_raw() as *const *const *const c_void);
+        *vtable.add(slot)
+    }
+
+    struct Manager {
+        layout: Layout,
+        object: IUnknown,
+    }
+
+    impl Manager {
+        fn new() -> Result<Self, String> {
+            let (build, revision) =
+                windows_build().ok_or("Could not determine the Windows build")?;
+            let unsupported =
+                || format!("Virtual desktops are unsupported on this build ({build}.{revision})");
+            let layout = layout_for_build(build, revision).ok_or_else(unsupported)?;
+
+            unsafe {
+                let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+
+                let shell: IServiceProvider =
+                    CoCreateInstance(&CLSID_IMMERSIVE_SHELL, None, CLSCTX_LOCAL_SERVER)
+                        .map_err(|e| format!("Immersive shell unavailable: {e}"))?;
+
+                // QueryService only succeeds when the IID matches what Explorer implements,
+                // so a wrong guess fails here instead of in a vtable call
+                let mut raw = std::ptr::null_mut();
+                (Interface::vtable(&shell).QueryService)(
+                    shell.as_raw(),
+                    &CLSID_VIRTUAL_DESKTOP_MANAGER_INTERNAL,
+                    &layout.manager_iid,
+                    &mut raw,
+                )
+                .ok()
+                .map_err(|_| unsupported())?;
+                if raw.is_null() {
+                    return Err(unsupported());
+                }
+
+                Ok(Self {
+                    layout,
+                    object: IUnknown::from_raw(raw),
+                })
+            }
+        }
+
+        fn current_desktop(&self) -> Result<IUnknown, String> {
+            let mut raw = std::ptr::null_mut();
+            let hr = unsafe {
+                let slot = vtable_slot(&self.object, SLOT_GET_CURRENT_DESKTOP);
+                if self.layout.takes_monitor {
+                    let f: unsafe extern "system" fn(
+                        *mut c_void,
+                        *mut c_void,
+                        *mut *mut c_void,
+                    ) -> HRESULT = std::mem::transmute(slot);
+                    f(self.object.as_raw(), std::ptr::null_mut(), &mut raw)
+                } else {
+                    let f: unsafe extern "system" fn(*mut c_void, *mut *mut c_void) -> HRESULT =
+                        std::mem::transmute(slot);
+                    f(self.object.as_raw(), &mut raw)
+                }
+            };
+            hr.ok()
+                .map_err(|e| format!("Failed to get current desktop: {e}"))?;
+            if raw.is_null() {
+                return Err("Failed to get current desktop".to_string());
+            }
+            Ok(unsafe { IUnknown::from_raw(raw) })
+        }
+
+        fn desktops(&self) -> Result<Vec<IUnknown>, String> {
+            let mut raw = std::ptr::null_mut();
+            let hr = unsafe {
+                let slot = vtable_slot(&self.object, self.layout.slot_get_desktops);
+                if self.layout.takes_monitor {
+                    let f: unsafe extern "system" fn(
+                        *mut c_void,
+                        *mut c_void,
+                        *mut *mut c_void,
+                    ) -> HRESULT = std::mem::transmute(slot);
+                    f(self.object.as_raw(), std::ptr::null_mut(), &mut raw)
+                } else {
+                    let f: unsafe extern "system" fn(*mut c_void, *mut *mut c_void) -> HRESULT =
+                        std::mem::transmute(slot);
+                    f(self.object.as_raw(), &mut raw)
+                }
+            };
+            hr.ok()
+                .map_err(|e| format!("Failed to list desktops: {e}"))?;
+            if raw.is_null() {
+                return Err("Failed to list desktops".to_string());
+            }
+
+            unsafe {
+                let array = IObjectArray::from_raw(raw);
+                let count = array.GetCount().map_err(|e| e.to_string())?;
+                let mut desktops = Vec::with_capacity(count as usize);
+                for i in 0..count {
+                    let mut item = std::ptr::null_mut();
+                    (Interface::vtable(&array).GetAt)(
+                        array.as_raw(),
+                        i,
+                        &self.layout.desktop_iid,
+                        &mut item,
+                    )
+                    .ok()
+                    .map_err(|e| format!("Failed to read desktop {i}: {e}"))?;
+                    if !item.is_null() {
+                        desktops.push(IUnknown::from_raw(item));
+                    }
+                }
+                Ok(desktops)
+            }
+        }
+
+        fn switch_to(&self, desktop: &IUnknown) -> Result<(), String> {
+            let hr = unsafe {
+                let slot = vtable_slot(&self.object, self.layout.slot_switch_desktop);
+                if self.layout.takes_monitor {
+                    let f: unsafe extern "system" fn(
+                        *mut c_void,
+                        *mut c_void,
+                        *mut c_void,
+                    ) -> HRESULT = std::mem::transmute(slot);
+                    f(self.object.as_raw(), std::ptr::null_mut(), desktop.as_raw())
+                } else {
+                    let f: unsafe extern "system" fn(*mut c_void, *mut c_void) -> HRESULT =
+                        std::mem::transmute(slot);
+                    f(self.object.as_raw(), desktop.as_raw())
+                }
+            };
+            hr.ok()
+                .map_err(|e| format!("Failed to switch desktop: {e}"))
+        }
+    }
+
+    fn desktop_id(desktop: &IUnknown) -> Option<GUID> {
+        let mut id = GUID::zeroed();
+        let hr = unsafe {
+            let f: unsafe extern "system" fn(*mut c_void, *mut GUID) -> HRESULT =
+                std::mem::transmute(vtable_slot(desktop, SLOT_DESKTOP_GET_ID));
+            f(desktop.as_raw(), &mut id)
+        };
+        hr.is_ok().then_some(id)
+    }
+
+    /// Names live in the registry on every build, which avoids yet another
+    /// version-specific `GetName` slot
+    fn desktop_name(id: &str, index: usize) -> String {
+        registry::string_values(HKEY_CURRENT_USER, &format!(r"{DESKTOPS_KEY}\{id}"))
+            .into_iter()
+            .find(|(name, value)| name == "Name" && !value.trim().is_empty())
+            .map(|(_, value)| value)
+            .unwrap_or_else(|| format!("Desktop {}", index + 1))
+    }
+
+    fn describe(desktops: &[IUnknown], current: Option<GUID>) -> Vec<VirtualDesktop> {
+        desktops
+            .iter()
+            .enumerate()
+            .map(|(index, desktop)| {
+                let guid = desktop_id(desktop);
+                let id = guid.map(|g| format!("{{{g:?}}}")).unwrap_or_default();
+                VirtualDesktop {
+                    index,
+                    name: desktop_name(&id, index),
+                    id,
+                    is_current: guid.is_some() && guid == current,
+                }
+            })
+            .collect()
+    }
+
+    pub fn list_virtual_desktops() -> Result<Vec<VirtualDesktop>, String> {
+        let manager = Manager::new()?;
+        let current = desktop_id(&manager.current_desktop()?);
+        Ok(describe(&manager.desktops()?, current))
+    }
+
+    pub fn get_current_desktop() -> Result<VirtualDesktop, String> {
+        list_virtual_desktops()?
+            .into_iter()
+            .find(|d| d.is_current)
+            .ok_or_else(|| "Current desktop not found".to_string())
+    }
+
+    pub fn switch_virtual_desktop(index: usize) -> Result<(), String> {
+        let manager = Manager::new()?;
+        let desktops = manager.desktops()?;
+        let desktop = desktops.get(index).ok_or_else(|| {
+            format!(
+                "Desktop index {index} out of range ({} desktops)",
+                desktops.len()
+            )
+        })?;
+        manager.switch_to(desktop)
+    }
+}
+
+#[cfg(windows)]
+pub use windows_impl::{get_current_desktop, list_virtual_desktops, switch_virtual_desktop};
+
+// Non-Windows fallback
+
+#[cfg(not(windows))]
+pub fn list_virtual_desktops() -> Result<Vec<VirtualDesktop>, String> {
+    Err("Virtual desktops are only supported on Windows".to_string())
+}
+
+#[cfg(not(windows))]
+pub fn get_current_desktop() -> Result<VirtualDesktop, String> {
+    Err("Virtual desktops are only supported on Windows".to_string())
+}
+
+#[cfg(not(windows))]
+pub fn switch_virtual_desktop(_index: usize) -> Result<(), String> {
+    Err("Virtual desktops are only supported on Windows".to_string())
+}
@@ -5,6 +5,23 @@ use std::sync::{Mutex, OnceLock};
 use std::time::{Duration, Instant};
 
 const CACHE_DURATION_SECS: u64 = 600; // 10 minutes
+const AIR_QUALITY_CACHE_DURATION_SECS: u64 = 1800; // 30 minutes
+const HTTP_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+const HTTP_READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+fn verbose_logs_enabled() -> bool {
+    super::logging::verbose_logs_enabled()
+}
+
+/// Shared HTTP agent for all weather requests
+fn http_agent() -> &'static ureq::Agent {
+    static AGENT: OnceLock<ureq::Agent> = OnceLock::new();
+    AGENT.get_or_init(|| super::http::build_agent(HTTP_CONNECT_TIMEOUT, HTTP_READ_TIMEOUT))
+}
+
+fn get_json<T: serde::de::DeserializeOwned>(url: &str) -> Result<T, String> {
+    super::http::get_json(http_agent(), url)
+}
 
 #[derive(Serialize, Clone, Debug, Default)]
 pub struct WeatherData {
@@ -38,6 +55,55 @@ pub struct LocationData {
     pub success: bool,
 }
 
+/// Current air quality from the Open-Meteo air-quality API
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct AirQualityData {
+    pub loaded: bool,
+    /// Fine particulate matter (µg/m³)
+    pub pm2_5: f64,
+    /// Coarse particulate matter (µg/m³)
+    pub pm10: f64,
+    /// Ozone (µg/m³)
+    pub ozone: f64,
+    /// European AQI (0-100+, lower is better)
+    pub european_aqi: u32,
+    /// US AQI (0-500, lower is better)
+    pub us_aqi: u32,
+}
+
+#[derive(Deserialize, Debug)]
+struct OpenMeteoAirQualityResponse {
+    current: Option<OpenMeteoAirQualityCurrent>,
+}
+
+#[derive(Deserialize, Debug)]
+struct OpenMeteoAirQualityCurrent {
+    pm2_5: Option<f64>,
+    pm10: Option<f64>,
+    ozone: Option<f64>,
+    european_aqi: Option<f64>,
+    us_aqi: Option<f64>,
+}
+
+/// A place returned by the Open-Meteo geocoding API
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct GeoResult {
+    pub name: String,
+    #[serde(default)]
+    pub country: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    /// First-level administrative area (state/region)
+    #[serde(default)]
+    pub admin1: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct GeocodingResponse {
+    #[serde(default)]
+    results: Vec<GeoResult>,
+}
+
 // Open-Meteo API response structures
 #[derive(Deserialize, Debug)]
 struct OpenMeteoResponse {
@@ -96,16 +162,36 @@ impl Default for WeatherCache {
     }
 }
 
+impl WeatherCache {
+    /// Whether the cached data was fetched for (roughly) these coordinates
+    fn is_same_location(&self, lat: f64, lon: f64) -> bool {
+        (self.last_lat - lat).abs() < 0.01 && (self.last_lon - lon).abs() < 0.01
+    }
+}
+
 fn get_cache() -> &'static Mutex<WeatherCache> {
     WEATHER_CACHE.get_or_init(|| Mutex::new(WeatherCache::default()))
 }
 
+// Cache for air quality, keyed by coordinates rounded to 2 decimals (~1km)
+static AIR_QUALITY_CACHE: OnceLock<Mutex<AirQualityCache>> = OnceLock::new();
+
+#[derive(Default)]
+struct AirQualityCache {
+    data: AirQualityData,
+    last_update: Option<Instant>,
+    key: (i64, i64),
+}
+
+fn get_air_quality_cache() -> &'static Mutex<AirQualityCache> {
+    AIR_QUALITY_CACHE.get_or_init(|| Mutex::new(AirQualityCache::default()))
+}
+
 pub fn get_weather(lat: f64, lon: f64) -> WeatherData {
     // Check cache
     {
         if let Ok(guard) = get_cache().lock() {
-            let same_location =
-                (guard.last_lat - lat).abs() < 0.01 && (guard.last_lon - lon).abs() < 0.01;
+            let same_location = guard.is_same_location(lat, lon);
             let cache_valid = guard
                 .last_update
                 .map(|t| t.elapsed() < Duration::from_secs(CACHE_DURATION_SECS))
@@ -116,10 +202,30 @@ pub fn get_weather(lat: f64, lon: f64) -> WeatherData {
         }
     }
 
-    // Fetch new data
-    let data = fetch_weather_blocking(lat, lon);
+    refresh_weather(lat, lon)
+}
+
+/// Fetch weather now, ignoring the cache, and store the result for `get_weather`.
+///
+/// If the request fails, the last successfully fetched data for the same location (if any)
+/// is returned instead of an empty placeholder, and the cache stays stale so the next poll
+/// tries again.
+pub fn refresh_weather(lat: f64, lon: f64) -> WeatherData {
+    let data = match fetch_weather_blocking(lat, lon) {
+        Ok(data) => data,
+        Err(e) => {
+            if verbose_logs_enabled() {
+                eprintln!("Failed to fetch weather: {e}");
+            }
+            return get_cache()
+                .lock()
+                .ok()
+                .filter(|guard| guard.data.loaded && guard.is_same_location(lat, lon))
+                .map(|guard| guard.data.clone())
+                .unwrap_or_default();
+        }
+    };
 
-    // Update cache
     if let Ok(mut guard) = get_cache().lock() {
         guard.data = data.clone();
         guard.last_update = Some(Instant::now());
@@ -130,78 +236,117 @@ pub fn get_weather(lat: f64, lon: f64) -> WeatherData {
     data
 }
 
-fn fetch_weather_blocking(lat: f64, lon: f64) -> WeatherData {
+fn fetch_weather_blocking(lat: f64, lon: f64) -> Result<WeatherData, String> {
     // Use Open-Meteo API (free, no API key required)
     let url = format!(
         "https://api.open-meteo.com/v1/forecast?latitude={}&longitude={}&current=temperature_2m,apparent_temperature,relative_humidity_2m,surface_pressure,wind_speed_10m,wind_direction_10m,cloud_cover,weather_code,is_day&daily=temperature_2m_max,temperature_2m_min,sunrise,sunset&timezone=auto",
         lat, lon
     );
 
-    match ureq::get(&url).call() {
-        Ok(response) => match response.into_body().read_json::<OpenMeteoResponse>() {
-            Ok(data) => {
-                let current = data.current.unwrap_or(OpenMeteoCurrent {
-                    temperature_2m: None,
-                    apparent_temperature: None,
-                    relative_humidity_2m: None,
-                    surface_pressure: None,
-                    wind_speed_10m: None,
-                    wind_direction_10m: None,
-                    cloud_cover: None,
-                    weather_code: None,
-                    is_day: None,
-                });
-                let daily = data.daily.unwrap_or(OpenMeteoDaily {
-                    temperature_2m_max: None,
-                    temperature_2m_min: None,
-                    sunrise: None,
-                    sunset: None,
-                });
-
-                let weather_code = current.weather_code.unwrap_or(0);
-                let is_day = current.is_day.unwrap_or(1) == 1;
-                let (description, icon) = weather_code_to_description(weather_code, is_day);
-
-                WeatherData {
-                    loaded: true,
-                    city: String::new(), // Will be filled from location
-                    country: String::new(),
-                    temperature: current.temperature_2m.unwrap_or(0.0),
-                    feels_like: current.apparent_temperature.unwrap_or(0.0),
-                    temp_min: daily
-                        .temperature_2m_min
-                        .as_ref()
-                        .and_then(|v: &Vec<f64>| v.first().copied())
-                        .unwrap_or(0.0),
-                    temp_max: daily
-                        .temperature_2m_max
-                        .as_ref()
-                        .and_then(|v: &Vec<f64>| v.first().copied())
-                        .unwrap_or(0.0),
-                    humidity: current.relative_humidity_2m.unwrap_or(0),
-                    pressure: current.surface_pressure.unwrap_or(0.0) as u32,
-                    description,
-                    icon,
-                    wind_speed: current.wind_speed_10m.unwrap_or(0.0) / 3.6, // km/h to m/s
-                    wind_deg: current.wind_direction_10m.unwrap_or(0),
-                    clouds: current.cloud_cover.unwrap_or(0),
-                    visibility: 10000,
-                    sunrise: parse_iso_time(
-                        daily.sunrise.as_ref().and_then(|v: &Vec<String>| v.first()),
-                    ),
-                    sunset: parse_iso_time(
-                        daily.sunset.as_ref().and_then(|v: &Vec<String>| v.first()),
-                    ),
-                }
-            }
-            Err(e) => {
-                eprintln!("Failed to parse weather data: {}", e);
-                WeatherData::default()
+    let data: OpenMeteoResponse = get_json(&url)?;
+    let current = data.current.unwrap_or(OpenMeteoCurrent {
+        temperature_2m: None,
+        apparent_temperature: None,
+        relative_humidity_2m: None,
+        surface_pressure: None,
+        wind_speed_10m: None,
+        wind_direction_10m: None,
+        cloud_cover: None,
+        weather_code: None,
+        is_day: None,
+    });
+    let daily = data.daily.unwrap_or(OpenMeteoDaily {
+        temperature_2m_max: None,
+        temperature_2m_min: None,
+        sunrise: None,
+        sunset: None,
+    });
+
+    let weather_code = current.weather_code.unwrap_or(0);
+    let is_day = current.is_day.unwrap_or(1) == 1;
+    let (description, icon) = weather_code_to_description(weather_code, is_day);
+
+    Ok(WeatherData {
+        loaded: true,
+        city: String::new(), // Will be filled from location
+        country: String::new(),
+        temperature: current.temperature_2m.unwrap_or(0.0),
+        feels_like: current.apparent_temperature.unwrap_or(0.0),
+        temp_min: daily
+            .temperature_2m_min
+            .as_ref()
+            .and_then(|v: &Vec<f64>| v.first().copied())
+            .unwrap_or(0.0),
+        temp_max: daily
+            .temperature_2m_max
+            .as_ref()
+            .and_then(|v: &Vec<f64>| v.first().copied())
+            .unwrap_or(0.0),
+        humidity: current.relative_humidity_2m.unwrap_or(0),
+        pressure: current.surface_pressure.unwrap_or(0.0) as u32,
+        description,
+        icon,
+        wind_speed: current.wind_speed_10m.unwrap_or(0.0) / 3.6, // km/h to m/s
+        wind_deg: current.wind_direction_10m.unwrap_or(0),
+        clouds: current.cloud_cover.unwrap_or(0),
+        visibility: 10000,
+        sunrise: parse_iso_time(daily.sunrise.as_ref().and_then(|v: &Vec<String>| v.first())),
+        sunset: parse_iso_time(daily.sunset.as_ref().and_then(|v: &Vec<String>| v.first())),
+    })
+}
+
+pub fn get_air_quality(lat: f64, lon: f64) -> AirQualityData {
+    let key = ((lat * 100.0).round() as i64, (lon * 100.0).round() as i64);
+
+    // Check cache
+    {
+        if let Ok(guard) = get_air_quality_cache().lock() {
+            let cache_valid = guard
+                .last_update
+                .map(|t| t.elapsed() < Duration::from_secs(AIR_QUALITY_CACHE_DURATION_SECS))
+                .unwrap_or(false);
+            if guard.data.loaded && guard.key == key && cache_valid {
+                return guard.data.clone();
             }
+        }
+    }
+
+    // Fetch new data
+    let data = fetch_air_quality_blocking(lat, lon);
+
+    // Update cache
+    if let Ok(mut guard) = get_air_quality_cache().lock() {
+        guard.data = data.clone();
+        guard.last_update = Some(Instant::now());
+        guard.key = key;
+    }
+
+    data
+}
+
+fn fetch_air_quality_blocking(lat: f64, lon: f64) -> AirQualityData {
+    let url = format!(
+        "https://air-quality-api.open-meteo.com/v1/air-quality?latitude={}&longitude={}&current=pm2_5,pm10,ozone,european_aqi,us_aqi",
+        lat, lon
+    );
+
+    match get_json::<OpenMeteoAirQualityResponse>(&url) {
+        Ok(data) => match data.current {
+            Some(current) => AirQualityData {
+                loaded: true,
+                pm2_5: current.pm2_5.unwrap_or(0.0),
+                pm10: current.pm10.unwrap_or(0.0),
+                ozone: current.ozone.unwrap_or(0.0),
+                european_aqi: current.european_aqi.unwrap_or(0.0).round() as u32,
+                us_aqi: current.us_aqi.unwrap_or(0.0).round() as u32,
+            },
+            None => AirQualityData::default(),
         },
         Err(e) => {
-            eprintln!("Failed to fetch weather: {}", e);
-            WeatherData::default()
+            if verbose_logs_enabled() {
+                eprintln!("Failed to fetch air quality: {e}");
+            }
+            AirQualityData::default()
         }
     }
 }
@@ -250,43 +395,62 @@ pub fn get_current_location() -> LocationData {
     // Use ipinfo.io (more reliable, free tier)
     let url = "https://ipinfo.io/json";
 
-    match ureq::get(url).call() {
-        Ok(response) => match response.into_body().read_json::<IpInfoResponse>() {
-            Ok(data) => {
-                // Parse "lat,lon" format
-                let (lat, lon) = data
-                    .loc
-                    .as_ref()
-                    .and_then(|loc: &String| {
-                        let parts: Vec<&str> = loc.split(',').collect();
-                        if parts.len() == 2 {
-                            Some((
-                                parts[0].parse::<f64>().unwrap_or(0.0),
-                                parts[1].parse::<f64>().unwrap_or(0.0),
-                            ))
-                        } else {
-                            None
-                        }
-                    })
-                    .unwrap_or((0.0, 0.0));
-
-                LocationData {
-                    latitude: lat,
-                    longitude: lon,
-                    city: data.city.unwrap_or_default(),
-                    region: data.region.unwrap_or_default(),
-                    country: data.country.unwrap_or_default(),
-                    success: lat != 0.0 && lon != 0.0,
-                }
-            }
-            Err(e) => {
-                eprintln!("Failed to parse location data: {}", e);
-                LocationData::default()
-            }
-        },
+    let data: IpInfoResponse = match get_json(url) {
+        Ok(data) => data,
         Err(e) => {
-            eprintln!("Failed to fetch location: {}", e);
-            LocationData::default()
+            if verbose_logs_enabled() {
+                eprintln!("Failed to fetch location: {e}");
+            }
+            return LocationData::default();
         }
+    };
+
+    // Parse "lat,lon" format
+    let (lat, lon) = data
+        .loc
+        .as_ref()
+        .and_then(|loc: &String| {
+            let parts: Vec<&str> = loc.split(',').collect();
+            if parts.len() == 2 {
+                Some((
+                    parts[0].parse::<f64>().unwrap_or(0.0),
+                    parts[1].parse::<f64>().unwrap_or(0.0),
+                ))
+            } else {
+                None
+            }
+        })
+        .unwrap_or((0.0, 0.0));
+
+    LocationData {
+        latitude: lat,
+        longitude: lon,
+        city: data.city.unwrap_or_default(),
+        region: data.region.unwrap_or_default(),
+        country: data.country.unwrap_or_default(),
+        success: lat != 0.0 && lon != 0.0,
+    }
+}
+
+/// Search places by name via Open-Meteo geocoding
+pub fn geocode_city(query: &str) -> Result<Vec<GeoResult>, String> {
+    let query = query.trim();
+    if query.len() < 2 {
+        return Ok(Vec::new());
     }
+
+    let response = http_agent()
+        .get("https://geocoding-api.open-meteo.com/v1/search")
+        .query("name", query)
+        .query("count", "10")
+        .query("format", "json")
+        .call()
+        .map_err(|e| format!("Failed to search city: {e}"))?;
+
+    let data = response
+        .into_body()
+        .read_json::<GeocodingResponse>()
+        .map_err(|e| format!("Failed to parse geocoding data: {e}"))?;
+
+    Ok(data.results)
 }
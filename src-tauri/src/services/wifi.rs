@@ -0,0 +1,190 @@
+//! Wi-Fi connection details (SSID, signal, band) via the WLAN API
+
+use serde::Serialize;
+
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct WifiInfo {
+    /// Whether any wireless adapter is present
+    pub has_adapter: bool,
+    pub is_connected: bool,
+    /// Empty when not connected
+    pub ssid: String,
+    /// Signal quality (0-100)
+    pub signal_percent: u8,
+    /// "2.4 GHz", "5 GHz", "6 GHz" or empty when unknown
+    pub band: String,
+}
+
+#[cfg(windows)]
+mod windows_impl {
+    use super::WifiInfo;
+    use windows::core::GUID;
+    use windows::Win32::Foundation::{ERROR_SUCCESS, HANDLE};
+    use windows::Win32::NetworkManagement::WiFi::{
+        wlan_interface_state_connected, wlan_intf_opcode_channel_number,
+        wlan_intf_opcode_current_connection, WlanCloseHandle, WlanEnumInterfaces, WlanFreeMemory,
+        WlanGetNetworkBssList, WlanOpenHandle, WlanQueryInterface, WLAN_BSS_ENTRY, WLAN_BSS_LIST,
+        WLAN_CONNECTION_ATTRIBUTES, WLAN_INTERFACE_INFO, WLAN_INTERFACE_INFO_LIST,
+    };
+
+    /// Client version 2 = Windows Vista and later
+    const WLAN_CLIENT_VERSION: u32 = 2;
+
+    fn band_from_frequency_khz(khz: u32) -> Option<&'static str> {
+        match khz {
+            2_400_000..=2_500_000 => Some("2.4 GHz"),
+            5_150_000..=5_895_000 => Some("5 GHz"),
+            5_925_000..=7_125_000 => Some("6 GHz"),
+            _ => None,
+        }
+    }
+
+    /// Center frequency (kHz) of the BSS we're associated with
+    unsafe fn connected_frequency(
+        handle: HANDLE,
+        interface: &GUID,
+        attributes: &WLAN_CONNECTION_ATTRIBUTES,
+    ) -> Option<u32> {
+        let association = &attributes.wlanAssociationAttributes;
+        let mut list: *mut WLAN_BSS_LIST = std::ptr::null_mut();
+        let status = WlanGetNetworkBssList(
+            handle,
+            interface,
+            Some(&association.dot11Ssid),
+            association.dot11BssType,
+            attributes.wlanSecurityAttributes.bSecurityEnabled,
+            None,
+            &mut list,
+        );
+        if status != ERROR_SUCCESS.0 || list.is_null() {
+            return None;
+        }
+
+        let entries: &[WLAN_BSS_ENTRY] = std::slice::from_raw_parts(
+            (*list).wlanBssEntries.as_ptr(),
+            (*list).dwNumberOfItems as usize,
+        );
+        let frequency = entries
+            .iter()
+            .find(|entry| entry.dot11Bssid == association.dot11Bssid)
+            .map(|entry| entry.ulChCenterFrequency);
+        WlanFreeMemory(list as *const _);
+        frequency
+    }
+
+    /// Band guessed from the channel number (5 GHz and 6 GHz channel numbers overlap)
+    unsafe fn band_from_channel(handle: HANDLE, interface: &GUID) -> Option<&'static str> {
+        let mut size = 0u32;
+        let mut data: *mut core::ffi::c_void = std::ptr::null_mut();
+        let status = WlanQueryInterface(
+            handle,
+            interface,
+            wlan_intf_opcode_channel_number,
+            None,
+            &mut size,
+            &mut data,
+            None,
+        );
+        if status != ERROR_SUCCESS.0 || data.is_null() {
+            return None;
+        }
+        let channel = *(data as *const u32);
+        WlanFreeMemory(data);
+        match channel {
+            1..=14 => Some("2.4 GHz"),
+            32..=177 => Some("5 GHz"),
+            _ => None,
+        }
+    }
+
+    unsafe fn interface_info(handle: HANDLE, interface: &WLAN_INTERFACE_INFO) -> WifiInfo {
+        let mut info = WifiInfo {
+            has_adapter: true,
+            ..Default::default()
+        };
+        if interface.isState != wlan_interface_state_connected {
+            return info;
+        }
+
+        let guid = interface.InterfaceGuid;
+        let mut size = 0u32;
+        let mut data: *mut core::ffi::c_void = std::ptr::null_mut();
+        let status = WlanQueryInterface(
+            handle,
+            &guid,
+            wlan_intf_opcode_current_connection,
+            None,
+            &mut size,
+            &mut data,
+            None,
+        );
+        if status != ERROR_SUCCESS.0 || data.is_null() {
+            return info;
+        }
+
+        let attributes = &*(data as *const WLAN_CONNECTION_ATTRIBUTES);
+        let ssid = &attributes.wlanAssociationAttributes.dot11Ssid;
+        let ssid_len = (ssid.uSSIDLength as usize).min(ssid.ucSSID.len());
+        info.is_connected = true;
+        info.ssid = String::from_utf8_lossy(&ssid.ucSSID[..ssid_len]).to_string();
+        info.signal_percent = attributes
+            .wlanAssociationAttributes
+            .wlanSignalQuality
+            .min(100) as u8;
+        info.band = connected_frequency(handle, &guid, attributes)
+            .and_then(band_from_frequency_khz)
+            .or_else(|| band_from_channel(handle, &guid))
+            .unwrap_or_default()
+            .to_string();
+        WlanFreeMemory(data);
+
+        info
+    }
+
+    pub fn get_wifi_info() -> Result<WifiInfo, String> {
+        unsafe {
+            let mut negotiated = 0u32;
+            let mut handle = HANDLE::default();
+            let status = WlanOpenHandle(WLAN_CLIENT_VERSION, None, &mut negotiated, &mut handle);
+            if status != ERROR_SUCCESS.0 {
+                // The WLAN service isn't running on machines without wireless hardware.
+                return Ok(WifiInfo::default());
+            }
+
+            let mut list: *mut WLAN_INTERFACE_INFO_LIST = std::ptr::null_mut();
+            let status = WlanEnumInterfaces(handle, None, &mut list);
+            if status != ERROR_SUCCESS.0 || list.is_null() {
+                WlanCloseHandle(handle, None);
+                return Err(format!(
+                    "Failed to enumerate wireless interfaces (error {status})"
+                ));
+            }
+
+            let interfaces: &[WLAN_INTERFACE_INFO] = std::slice::from_raw_parts(
+                (*list).InterfaceInfo.as_ptr(),
+                (*list).dwNumberOfItems as usize,
+            );
+            // Prefer the connected adapter when there are several.
+            let mut info = WifiInfo::default();
+            for interface in interfaces {
+                info = interface_info(handle, interface);
+                if info.is_connected {
+                    break;
+                }
+            }
+
+            WlanFreeMemory(list as *const _);
+            WlanCloseHandle(handle, None);
+            Ok(info)
+        }
+    }
+}
+
+#[cfg(windows)]
+pub use windows_impl::get_wifi_info;
+
+// Non-Windows fallback
+#[cfg(not(windows))]
+pub fn get_wifi_info() -> Result<WifiInfo, String> {
+    Err("Wi-Fi info is only supported on Windows".to_string())
+}
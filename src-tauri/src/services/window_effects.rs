@@ -0,0 +1,86 @@
+//! Native blur-behind (acrylic) for the taskbar window
+//!
+//! Uses the undocumented `SetWindowCompositionAttribute` from user32, the same API the
+//! Windows shell uses for its own acrylic surfaces. The bar background color/opacity is
+//! painted by the webview, so the native layer only provides the blur.
+
+#[cfg(windows)]
+mod windows_impl {
+    use std::ffi::c_void;
+
+    const WCA_ACCENT_POLICY: u32 = 19;
+
+    const ACCENT_DISABLED: u32 = 0;
+    const ACCENT_ENABLE_BLURBEHIND: u32 = 3;
+    const ACCENT_ENABLE_ACRYLICBLURBEHIND: u32 = 4;
+
+    #[repr(C)]
+    struct AccentPolicy {
+        accent_state: u32,
+        accent_flags: u32,
+        /// ABGR tint (acrylic only)
+        gradient_color: u32,
+        animation_id: u32,
+    }
+
+    #[repr(C)]
+    struct WindowCompositionAttribData {
+        attrib: u32,
+        data: *mut c_void,
+        size: usize,
+    }
+
+    type SetWindowCompositionAttributeFn =
+        unsafe extern "system" fn(isize, *mut WindowCompositionAttribData) -> i32;
+
+    fn set_accent(func: &SetWindowCompositionAttributeFn, hwnd: isize, accent_state: u32) -> bool {
+        let mut policy = AccentPolicy {
+            accent_state,
+            accent_flags: 0,
+            // Acrylic needs a non-zero alpha to render; keep the tint invisible.
+            gradient_color: 0x0100_0000,
+            animation_id: 0,
+        };
+        let mut data = WindowCompositionAttribData {
+            attrib: WCA_ACCENT_POLICY,
+            data: &mut policy as *mut _ as *mut c_void,
+            size: std::mem::size_of::<AccentPolicy>(),
+        };
+        unsafe { func(hwnd, &mut data) != 0 }
+    }
+
+    /// Enable (acrylic, falling back to classic blur) or disable blur-behind.
+    pub fn set_blur(hwnd: isize, blur: bool) -> Result<(), String> {
+        unsafe {
+            let lib = libloading::Library::new("user32.dll").map_err(|e| e.to_string())?;
+            let func: libloading::Symbol<SetWindowCompositionAttributeFn> = lib
+                .get(b"SetWindowCompositionAttribute")
+                .map_err(|_| "Window blur requires Windows 10 or later".to_string())?;
+
+            if !blur {
+                return if set_accent(&func, hwnd, ACCENT_DISABLED) {
+                    Ok(())
+                } else {
+                    Err("Failed to disable window blur".to_string())
+                };
+            }
+
+            // Acrylic is only available from Windows 10 1803; older builds reject it.
+            if set_accent(&func, hwnd, ACCENT_ENABLE_ACRYLICBLURBEHIND)
+                || set_accent(&func, hwnd, ACCENT_ENABLE_BLURBEHIND)
+            {
+                Ok(())
+            } else {
+                Err("Window blur is not supported on this version of Windows".to_string())
+            }
+        }
+    }
+}
+
+#[cfg(windows)]
+pub use windows_impl::set_blur;
+
+#[cfg(not(windows))]
+pub fn set_blur(_hwnd: isize, _blur: bool) -> Result<(), String> {
+    Err("Window blur is only supported on Windows".to_string())
+}
@@ -1,12 +1,15 @@
 //! Windows window enumeration and management service
 
 use serde::Serialize;
-use std::ffi::OsString;
-use std::os::windows::ffi::OsStringExt;
+use std::collections::{HashMap, VecDeque};
 use std::path::PathBuf;
 use std::sync::{Mutex, OnceLock};
 use std::time::{Duration, Instant};
 
+#[cfg(windows)]
+use std::ffi::OsString;
+#[cfg(windows)]
+use std::os::windows::ffi::OsStringExt;
 #[cfg(windows)]
 use windows::Win32::Foundation::{BOOL, HWND, LPARAM, MAX_PATH};
 #[cfg(windows)]
@@ -21,6 +24,8 @@ use windows::Win32::UI::WindowsAndMessaging::{
 };
 
 const CACHE_DURATION_MS: u64 = 500;
+/// Max number of process icons kept in memory (oldest inserted are evicted first)
+const ICON_CACHE_MAX_ENTRIES: usize = 256;
 
 /// Information about a running window
 #[derive(Serialize, Clone, Debug)]
@@ -59,6 +64,20 @@ fn get_cache() -> &'static Mutex<WindowCache> {
     WINDOW_CACHE.get_or_init(|| Mutex::new(WindowCache::default()))
 }
 
+// Cache for extracted process icons, keyed by "size|exe path".
+// Negative results (no icon) are cached too so we don't retry extraction on every refresh.
+static ICON_CACHE: OnceLock<Mutex<IconCache>> = OnceLock::new();
+
+#[derive(Default)]
+struct IconCache {
+    icons: HashMap<String, Option<String>>,
+    insertion_order: VecDeque<String>,
+}
+
+fn get_icon_cache() -> &'static Mutex<IconCache> {
+    ICON_CACHE.get_or_init(|| Mutex::new(IconCache::default()))
+}
+
 #[cfg(windows)]
 fn get_window_text(hwnd: HWND) -> String {
     unsafe {
@@ -88,7 +107,7 @@ fn get_class_name(hwnd: HWND) -> String {
 }
 
 #[cfg(windows)]
-fn get_process_path(pid: u32) -> Option<PathBuf> {
+pub(crate) fn get_process_path(pid: u32) -> Option<PathBuf> {
     unsafe {
         let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid).ok()?;
         let mut buffer: Vec<u16> = vec![0; MAX_PATH as usize];
@@ -254,6 +273,160 @@ fn fetch_window_list() -> WindowList {
     WindowList::default()
 }
 
+/// Debounce for window events: creating one app window fires a burst of them
+#[cfg(windows)]
+const WINDOW_EVENT_DEBOUNCE_MS: u32 = 100;
+
+/// Thread id of the running window-event hook loop so it can be unhooked on exit
+#[cfg(windows)]
+static WINDOW_EVENT_THREAD: Mutex<Option<u32>> = Mutex::new(None);
+
+#[cfg(windows)]
+thread_local! {
+    /// Pending debounce timer on the hook thread (0 = none)
+    static PENDING_REFRESH_TIMER: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+#[cfg(windows)]
+unsafe extern "system" fn window_event_callback(
+    _hook: windows::Win32::UI::Accessibility::HWINEVENTHOOK,
+    _event: u32,
+    _hwnd: HWND,
+    id_object: i32,
+    id_child: i32,
+    _event_thread: u32,
+    _event_time: u32,
+) {
+    use windows::Win32::UI::WindowsAndMessaging::{SetTimer, CHILDID_SELF, OBJID_WINDOW};
+
+    // Only whole windows matter, not carets/scrollbars/child objects
+    if id_object != OBJID_WINDOW.0 || id_child != CHILDID_SELF as i32 {
+        return;
+    }
+    PENDING_REFRESH_TIMER.with(|timer| {
+        if timer.get() == 0 {
+            timer.set(SetTimer(None, 0, WINDOW_EVENT_DEBOUNCE_MS, None));
+        }
+    });
+}
+
+/// Watch for foreground changes and windows being created/destroyed/shown/hidden, and emit
+/// `windows-changed` whenever the Alt+Tab window list (or its order) actually changes.
+/// The refreshed list goes straight into the cache, so the next `get_window_list` is fresh.
+pub fn start_event_watcher(app: tauri::AppHandle) {
+    #[cfg(windows)]
+    {
+        use std::sync::mpsc;
+        use tauri::Emitter;
+        use windows::Win32::System::Threading::GetCurrentThreadId;
+        use windows::Win32::UI::Accessibility::{SetWinEventHook, UnhookWinEvent};
+        use windows::Win32::UI::WindowsAndMessaging::{
+            GetMessageW, KillTimer, EVENT_OBJECT_CREATE, EVENT_OBJECT_HIDE,
+            EVENT_SYSTEM_FOREGROUND, MSG, WINEVENT_OUTOFCONTEXT, WINEVENT_SKIPOWNPROCESS, WM_TIMER,
+        };
+
+        let (tx, rx) = mpsc::channel();
+        let spawned = std::thread::Builder::new()
+            .name("window-events".to_string())
+            .spawn(move || unsafe {
+                let flags = WINEVENT_OUTOFCONTEXT | WINEVENT_SKIPOWNPROCESS;
+                let hooks = [
+                    SetWinEventHook(
+                        EVENT_SYSTEM_FOREGROUND,
+                        EVENT_SYSTEM_FOREGROUND,
+                        None,
+                        Some(window_event_callback),
+                        0,
+                        0,
+                        flags,
+                    ),
+                    // CREATE, DESTROY, SHOW, HIDE
+                    SetWinEventHook(
+                        EVENT_OBJECT_CREATE,
+                        EVENT_OBJECT_HIDE,
+                        None,
+                        Some(window_event_callback),
+                        0,
+                        0,
+                        flags,
+                    ),
+                ];
+                let _ = tx.send(GetCurrentThreadId());
+
+                let mut last: Vec<isize> =
+                    fetch_window_list().windows.iter().map(|w| w.hwnd).collect();
+
+                let mut msg = MSG::default();
+                while GetMessageW(&mut msg, None, 0, 0).as_bool() {
+                    let pending = PENDING_REFRESH_TIMER.with(|t| t.get());
+                    if msg.message != WM_TIMER || pending == 0 || msg.wParam.0 != pending {
+                        continue;
+                    }
+                    let _ = KillTimer(None, pending);
+                    PENDING_REFRESH_TIMER.with(|t| t.set(0));
+
+                    let data = fetch_window_list();
+                    let current: Vec<isize> = data.windows.iter().map(|w| w.hwnd).collect();
+                    if current == last {
+                        continue;
+                    }
+                    last = current;
+
+                    if let Ok(mut guard) = get_cache().lock() {
+                        guard.data = data;
+                        guard.last_update = Some(Instant::now());
+                    }
+                    let _ = app.emit("windows-changed", ());
+                }
+
+                let pending = PENDING_REFRESH_TIMER.with(|t| t.get());
+                if pending != 0 {
+                    let _ = KillTimer(None, pending);
+                }
+                for hook in hooks {
+                    if !hook.is_invalid() {
+                        let _ = UnhookWinEvent(hook);
+                    }
+                }
+            });
+
+        match spawned {
+            Ok(_) => {
+                if let Ok(thread_id) = rx.recv() {
+                    if let Ok(mut thread) = WINDOW_EVENT_THREAD.lock() {
+                        *thread = Some(thread_id);
+                    }
+                }
+            }
+            Err(e) => eprintln!("Failed to start window event watcher: {e}"),
+        }
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = app;
+    }
+}
+
+/// Ask the window event watcher to unhook and exit.
+///
+/// Doesn't wait for the thread: this runs on the exit paths, where blocking on a hook
+/// thread stuck in a callback would hang the quit. Hooks die with the process anyway.
+pub fn stop_event_watcher() {
+    #[cfg(windows)]
+    {
+        use windows::Win32::Foundation::WPARAM;
+        use windows::Win32::UI::WindowsAndMessaging::{PostThreadMessageW, WM_QUIT};
+
+        let running = WINDOW_EVENT_THREAD.lock().ok().and_then(|mut t| t.take());
+        if let Some(thread_id) = running {
+            unsafe {
+                let _ = PostThreadMessageW(thread_id, WM_QUIT, WPARAM(0), LPARAM(0));
+            }
+        }
+    }
+}
+
 /// Bring a window to foreground
 pub fn focus_window(hwnd: isize) -> Result<(), String> {
     #[cfg(windows)]
@@ -282,6 +455,273 @@ pub fn focus_window(hwnd: isize) -> Result<(), String> {
     }
 }
 
+/// Flash a window's taskbar button to request attention.
+///
+/// Flashes `count` times, then keeps the button highlighted until the window comes to the
+/// foreground.
+pub fn flash_window(hwnd: isize, count: u32) -> Result<(), String> {
+    #[cfg(windows)]
+    {
+        use windows::Win32::UI::WindowsAndMessaging::{
+            FlashWindowEx, IsWindow, FLASHWINFO, FLASHW_TIMERNOFG, FLASHW_TRAY,
+        };
+
+        unsafe {
+            let handle = HWND(hwnd as *mut std::ffi::c_void);
+            if !IsWindow(handle).as_bool() {
+                return Err(format!("Invalid window handle: {hwnd}"));
+            }
+
+            let info = FLASHWINFO {
+                cbSize: std::mem::size_of::<FLASHWINFO>() as u32,
+                hwnd: handle,
+                dwFlags: FLASHW_TRAY | FLASHW_TIMERNOFG,
+                uCount: count,
+                // 0 = default cursor blink rate
+                dwTimeout: 0,
+            };
+            // The return value is the previous flash state, not success/failure.
+            let _ = FlashWindowEx(&info);
+            Ok(())
+        }
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = (hwnd, count);
+        Err("Window flashing is only supported on Windows".to_string())
+    }
+}
+
+/// Minimize all windows, or restore them on the next call (like Win+D).
+///
+/// Returns `true` while the desktop is shown.
+pub fn toggle_show_desktop() -> Result<bool, String> {
+    #[cfg(windows)]
+    {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use windows::core::w;
+        use windows::Win32::Foundation::WPARAM;
+        use windows::Win32::UI::WindowsAndMessaging::{FindWindowW, PostMessageW, WM_COMMAND};
+
+        // Explorer tray commands (same ones the taskbar context menu uses)
+        const TRAY_MINIMIZE_ALL: usize = 0x1A3;
+        const TRAY_MINIMIZE_ALL_UNDO: usize = 0x1A0;
+
+        // Whether the last call minimized everything (so the next one restores)
+        static SHOW_DESKTOP_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+        let show = !SHOW_DESKTOP_ACTIVE.load(Ordering::Relaxed);
+        let command = if show {
+            TRAY_MINIMIZE_ALL
+        } else {
+            TRAY_MINIMIZE_ALL_UNDO
+        };
+
+        unsafe {
+            let tray = FindWindowW(w!("Shell_TrayWnd"), None)
+                .map_err(|e| format!("Taskbar window not found: {e}"))?;
+            PostMessageW(tray, WM_COMMAND, WPARAM(command), LPARAM(0))
+                .map_err(|e| format!("Failed to toggle desktop: {e}"))?;
+        }
+
+        SHOW_DESKTOP_ACTIVE.store(show, Ordering::Relaxed);
+        Ok(show)
+    }
+
+    #[cfg(not(windows))]
+    {
+        Err("Show desktop is only supported on Windows".to_string())
+    }
+}
+
+/// Work area (monitor rect minus taskbars/appbars) of a monitor
+#[cfg(windows)]
+fn monitor_work_area(
+    monitor: windows::Win32::Graphics::Gdi::HMONITOR,
+) -> Option<windows::Win32::Foundation::RECT> {
+    use windows::Win32::Graphics::Gdi::{GetMonitorInfoW, MONITORINFO};
+
+    let mut info = MONITORINFO {
+        cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+        ..Default::default()
+    };
+    unsafe { GetMonitorInfoW(monitor, &mut info) }
+        .as_bool()
+        .then_some(info.rcWork)
+}
+
+/// Move a window onto the monitor containing (`monitor_x`, `monitor_y`), keeping its offset
+/// and size where they fit inside the target work area. Maximized windows stay maximized.
+pub fn move_window_to_monitor(hwnd: isize, monitor_x: i32, monitor_y: i32) -> Result<(), String> {
+    #[cfg(windows)]
+    {
+        use windows::Win32::Foundation::{POINT, RECT};
+        use windows::Win32::Graphics::Gdi::{
+            MonitorFromPoint, MonitorFromWindow, MONITOR_DEFAULTTONEAREST, MONITOR_DEFAULTTONULL,
+        };
+        use windows::Win32::UI::WindowsAndMessaging::{
+            GetWindowRect, IsWindow, IsZoomed, SetWindowPos, SWP_NOACTIVATE, SWP_NOZORDER,
+            SW_MAXIMIZE,
+        };
+
+        unsafe {
+            let handle = HWND(hwnd as *mut std::ffi::c_void);
+            if !IsWindow(handle).as_bool() {
+                return Err(format!("Invalid window handle: {hwnd}"));
+            }
+
+            let target = MonitorFromPoint(
+                POINT {
+                    x: monitor_x,
+                    y: monitor_y,
+                },
+                MONITOR_DEFAULTTONULL,
+            );
+            if target.is_invalid() {
+                return Err("Monitor not found".to_string());
+            }
+            let target = monitor_work_area(target).ok_or("Failed to read monitor work area")?;
+            let source = monitor_work_area(MonitorFromWindow(handle, MONITOR_DEFAULTTONEAREST))
+                .unwrap_or(target);
+
+            // Maximized (and minimized) windows report a rect that isn't their real
+            // placement, so restore before measuring
+            let was_maximized = IsZoomed(handle).as_bool();
+            if was_maximized || IsIconic(handle).as_bool() {
+                let _ = ShowWindow(handle, SW_RESTORE);
+            }
+
+            let mut rect = RECT::default();
+            GetWindowRect(handle, &mut rect)
+                .map_err(|e| format!("Failed to get window rect: {e}"))?;
+
+            let target_width = target.right - target.left;
+            let target_height = target.bottom - target.top;
+            let width = (rect.right - rect.left).min(target_width);
+            let height = (rect.bottom - rect.top).min(target_height);
+            let x =
+                (target.left + rect.left - source.left).clamp(target.left, target.right - width);
+            let y = (target.top + rect.top - source.top).clamp(target.top, target.bottom - height);
+
+            SetWindowPos(
+                handle,
+                None,
+                x,
+                y,
+                width,
+                height,
+                SWP_NOZORDER | SWP_NOACTIVATE,
+            )
+            .map_err(|e| format!("Failed to move window: {e}"))?;
+
+            if was_maximized {
+                let _ = ShowWindow(handle, SW_MAXIMIZE);
+            }
+            Ok(())
+        }
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = (hwnd, monitor_x, monitor_y);
+        Err("Moving windows is only supported on Windows".to_string())
+    }
+}
+
+/// Snap a window to part of its current monitor's work area.
+///
+/// `layout` is one of "left", "right", "top", "bottom", "top-left", "top-right",
+/// "bottom-left", "bottom-right" or "maximize".
+pub fn snap_window(hwnd: isize, layout: &str) -> Result<(), String> {
+    #[cfg(windows)]
+    {
+        use windows::Win32::Foundation::RECT;
+        use windows::Win32::Graphics::Dwm::{DwmGetWindowAttribute, DWMWA_EXTENDED_FRAME_BOUNDS};
+        use windows::Win32::Graphics::Gdi::{MonitorFromWindow, MONITOR_DEFAULTTONEAREST};
+        use windows::Win32::UI::WindowsAndMessaging::{
+            GetWindowRect, IsWindow, IsZoomed, SetWindowPos, SWP_NOACTIVATE, SWP_NOZORDER,
+            SW_MAXIMIZE,
+        };
+
+        // (left, top, right, bottom) as fractions of the work area
+        let (fx0, fy0, fx1, fy1) = match layout {
+            "left" => (0.0, 0.0, 0.5, 1.0),
+            "right" => (0.5, 0.0, 1.0, 1.0),
+            "top" => (0.0, 0.0, 1.0, 0.5),
+            "bottom" => (0.0, 0.5, 1.0, 1.0),
+            "top-left" => (0.0, 0.0, 0.5, 0.5),
+            "top-right" => (0.5, 0.0, 1.0, 0.5),
+            "bottom-left" => (0.0, 0.5, 0.5, 1.0),
+            "bottom-right" => (0.5, 0.5, 1.0, 1.0),
+            "maximize" => (0.0, 0.0, 1.0, 1.0),
+            other => return Err(format!("Unknown snap layout: {other}")),
+        };
+
+        unsafe {
+            let handle = HWND(hwnd as *mut std::ffi::c_void);
+            if !IsWindow(handle).as_bool() {
+                return Err(format!("Invalid window handle: {hwnd}"));
+            }
+
+            if IsIconic(handle).as_bool() || (IsZoomed(handle).as_bool() && layout != "maximize") {
+                let _ = ShowWindow(handle, SW_RESTORE);
+            }
+            if layout == "maximize" {
+                let _ = ShowWindow(handle, SW_MAXIMIZE);
+                return Ok(());
+            }
+
+            let area = monitor_work_area(MonitorFromWindow(handle, MONITOR_DEFAULTTONEAREST))
+                .ok_or("Failed to read monitor work area")?;
+            let width = (area.right - area.left) as f64;
+            let height = (area.bottom - area.top) as f64;
+            let mut target = RECT {
+                left: area.left + (width * fx0).round() as i32,
+                top: area.top + (height * fy0).round() as i32,
+                right: area.left + (width * fx1).round() as i32,
+                bottom: area.top + (height * fy1).round() as i32,
+            };
+
+            // Windows 10/11 frames have invisible resize borders; grow the rect by them so
+            // the visible edges line up with the work area instead of leaving gaps
+            let mut window_rect = RECT::default();
+            let mut frame = RECT::default();
+            if GetWindowRect(handle, &mut window_rect).is_ok()
+                && DwmGetWindowAttribute(
+                    handle,
+                    DWMWA_EXTENDED_FRAME_BOUNDS,
+                    &mut frame as *mut RECT as *mut _,
+                    std::mem::size_of::<RECT>() as u32,
+                )
+                .is_ok()
+            {
+                target.left -= frame.left - window_rect.left;
+                target.top -= frame.top - window_rect.top;
+                target.right += window_rect.right - frame.right;
+                target.bottom += window_rect.bottom - frame.bottom;
+            }
+
+            SetWindowPos(
+                handle,
+                None,
+                target.left,
+                target.top,
+                target.right - target.left,
+                target.bottom - target.top,
+                SWP_NOZORDER | SWP_NOACTIVATE,
+            )
+            .map_err(|e| format!("Failed to snap window: {e}"))
+        }
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = (hwnd, layout);
+        Err("Window snapping is only supported on Windows".to_string())
+    }
+}
+
 /// Get the currently focused (foreground) window
 pub fn get_foreground_window() -> Option<WindowInfo> {
     #[cfg(windows)]
@@ -331,15 +771,59 @@ pub fn get_foreground_window() -> Option<WindowInfo> {
     }
 }
 
-/// Get icon data for a process (base64 encoded PNG)
-pub fn get_process_icon(process_path: &str) -> Option<String> {
+/// Pick the closest standard icon size (32/48/256) that is at least `requested`
+fn closest_icon_size(requested: u32) -> u32 {
+    const ICON_SIZES: [u32; 3] = [32, 48, 256];
+    ICON_SIZES
+        .into_iter()
+        .find(|&s| s >= requested)
+        .unwrap_or(256)
+}
+
+/// Get icon data for a process (base64 encoded PNG), memoized per exe path and size
+pub fn get_process_icon(process_path: &str, size: u32) -> Option<String> {
+    let size = closest_icon_size(size);
+    let key = format!("{size}|{process_path}");
+
+    if let Ok(cache) = get_icon_cache().lock() {
+        if let Some(icon) = cache.icons.get(&key) {
+            return icon.clone();
+        }
+    }
+
+    let icon = extract_process_icon(process_path, size);
+
+    if let Ok(mut cache) = get_icon_cache().lock() {
+        if !cache.icons.contains_key(&key) {
+            while cache.insertion_order.len() >= ICON_CACHE_MAX_ENTRIES {
+                if let Some(oldest) = cache.insertion_order.pop_front() {
+                    cache.icons.remove(&oldest);
+                }
+            }
+            cache.insertion_order.push_back(key.clone());
+        }
+        cache.icons.insert(key, icon.clone());
+    }
+
+    icon
+}
+
+/// Drop all cached process icons (e.g. after an app update changed its icon)
+pub fn clear_icon_cache() {
+    if let Ok(mut cache) = get_icon_cache().lock() {
+        cache.icons.clear();
+        cache.insertion_order.clear();
+    }
+}
+
+fn extract_process_icon(process_path: &str, size: u32) -> Option<String> {
     #[cfg(windows)]
     {
         use windows::Win32::Graphics::Gdi::{
-            CreateCompatibleDC, DeleteDC, DeleteObject, GetDIBits, SelectObject, BITMAPINFO,
-            BITMAPINFOHEADER, BI_RGB, DIB_RGB_COLORS,
+            CreateCompatibleDC, DeleteDC, DeleteObject, GetDIBits, GetObjectW, SelectObject,
+            BITMAP, BITMAPINFO, BITMAPINFOHEADER, BI_RGB, DIB_RGB_COLORS,
         };
-        use windows::Win32::UI::Shell::ExtractIconExW;
+        use windows::Win32::UI::Shell::{ExtractIconExW, SHDefExtractIconW};
         use windows::Win32::UI::WindowsAndMessaging::{DestroyIcon, GetIconInfo, ICONINFO};
 
         if process_path.is_empty() {
@@ -354,16 +838,29 @@ pub fn get_process_icon(process_path: &str) -> Option<String> {
                 .collect();
             let mut large_icon = windows::Win32::UI::WindowsAndMessaging::HICON::default();
 
-            let count = ExtractIconExW(
+            // Ask the shell for the requested size (it picks the best image in the exe).
+            // Fall back to the default large icon if that fails.
+            let extracted = SHDefExtractIconW(
                 windows::core::PCWSTR(wide_path.as_ptr()),
                 0,
+                0,
                 Some(&mut large_icon),
                 None,
-                1,
+                size,
             );
 
-            if count == 0 || large_icon.is_invalid() {
-                return None;
+            if extracted.is_err() || large_icon.is_invalid() {
+                let count = ExtractIconExW(
+                    windows::core::PCWSTR(wide_path.as_ptr()),
+                    0,
+                    Some(&mut large_icon),
+                    None,
+                    1,
+                );
+
+                if count == 0 || large_icon.is_invalid() {
+                    return None;
+                }
             }
 
             // Get icon info
@@ -386,14 +883,30 @@ pub fn get_process_icon(process_path: &str) -> Option<String> {
                 return None;
             }
 
+            // Use the real bitmap size: the shell returns the best match when the
+            // exact size isn't available.
+            let mut bitmap = BITMAP::default();
+            let (width, height) = if GetObjectW(
+                icon_info.hbmColor,
+                std::mem::size_of::<BITMAP>() as i32,
+                Some(&mut bitmap as *mut _ as *mut _),
+            ) != 0
+                && bitmap.bmWidth > 0
+                && bitmap.bmHeight > 0
+            {
+                (bitmap.bmWidth as u32, bitmap.bmHeight as u32)
+            } else {
+                (size, size)
+            };
+
             let old_bitmap = SelectObject(hdc, icon_info.hbmColor);
 
             // Set up BITMAPINFO for 32-bit RGBA
             let mut bmi = BITMAPINFO {
                 bmiHeader: BITMAPINFOHEADER {
                     biSize: std::mem::size_of::<BITMAPINFOHEADER>() as u32,
-                    biWidth: 32,
-                    biHeight: -32, // Top-down
+                    biWidth: width as i32,
+                    biHeight: -(height as i32), // Top-down
                     biPlanes: 1,
                     biBitCount: 32,
                     biCompression: BI_RGB.0,
@@ -406,13 +919,13 @@ pub fn get_process_icon(process_path: &str) -> Option<String> {
                 bmiColors: [windows::Win32::Graphics::Gdi::RGBQUAD::default()],
             };
 
-            let mut pixels: Vec<u8> = vec![0; 32 * 32 * 4];
+            let mut pixels: Vec<u8> = vec![0; (width * height * 4) as usize];
 
             let result = GetDIBits(
                 hdc,
                 icon_info.hbmColor,
                 0,
-                32,
+                height,
                 Some(pixels.as_mut_ptr() as *mut _),
                 &mut bmi,
                 DIB_RGB_COLORS,
@@ -437,31 +950,13 @@ pub fn get_process_icon(process_path: &str) -> Option<String> {
                 chunk.swap(0, 2);
             }
 
-            // Encode as PNG
-            let mut png_data: Vec<u8> = Vec::new();
-            {
-                let mut encoder = png::Encoder::new(&mut png_data, 32, 32);
-                encoder.set_color(png::ColorType::Rgba);
-                encoder.set_depth(png::BitDepth::Eight);
-
-                if let Ok(mut writer) = encoder.write_header() {
-                    if writer.write_image_data(&pixels).is_err() {
-                        return None;
-                    }
-                } else {
-                    return None;
-                }
-            }
-
-            use base64::Engine;
-            let base64_str = base64::engine::general_purpose::STANDARD.encode(&png_data);
-            return Some(format!("data:image/png;base64,{}", base64_str));
+            return super::image::encode_rgba_png_data_url(width, height, &pixels).ok();
         }
     }
 
     #[cfg(not(windows))]
     {
-        let _ = process_path;
+        let _ = (process_path, size);
         None
     }
 }
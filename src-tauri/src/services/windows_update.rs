@@ -0,0 +1,76 @@
+//! Windows Update status (pending reboot, active hours) read from the registry
+
+use serde::Serialize;
+
+#[derive(Serialize, Clone, Debug, Default, PartialEq)]
+pub struct WindowsUpdateStatus {
+    /// A restart is required to finish installing updates
+    pub reboot_pending: bool,
+    /// Number of installed updates waiting for the restart (if known)
+    pub updates_awaiting_reboot: Option<u32>,
+    /// Active hours start (0-23), during which Windows avoids automatic restarts
+    pub active_hours_start: Option<u32>,
+    /// Active hours end (0-23)
+    pub active_hours_end: Option<u32>,
+}
+
+#[cfg(windows)]
+pub fn get_status() -> WindowsUpdateStatus {
+    use super::registry;
+    use windows::Win32::System::Registry::HKEY_LOCAL_MACHINE;
+
+    const WU_REBOOT_REQUIRED: &str =
+        r"SOFTWARE\Microsoft\Windows\CurrentVersion\WindowsUpdate\Auto Update\RebootRequired";
+    const CBS_REBOOT_PENDING: &str =
+        r"SOFTWARE\Microsoft\Windows\CurrentVersion\Component Based Servicing\RebootPending";
+    const UX_SETTINGS: &str = r"SOFTWARE\Microsoft\WindowsUpdate\UX\Settings";
+
+    let wu_reboot = registry::key_exists(HKEY_LOCAL_MACHINE, WU_REBOOT_REQUIRED);
+    let cbs_reboot = registry::key_exists(HKEY_LOCAL_MACHINE, CBS_REBOOT_PENDING);
+
+    // Each value under RebootRequired is the GUID of an update waiting for the restart.
+    let updates_awaiting_reboot = if wu_reboot {
+        registry::value_count(HKEY_LOCAL_MACHINE, WU_REBOOT_REQUIRED)
+    } else {
+        Some(0)
+    };
+
+    WindowsUpdateStatus {
+        reboot_pending: wu_reboot || cbs_reboot,
+        updates_awaiting_reboot,
+        active_hours_start: registry::read_dword(
+            HKEY_LOCAL_MACHINE,
+            UX_SETTINGS,
+            "ActiveHoursStart",
+        ),
+        active_hours_end: registry::read_dword(HKEY_LOCAL_MACHINE, UX_SETTINGS, "ActiveHoursEnd"),
+    }
+}
+
+#[cfg(not(windows))]
+pub fn get_status() -> WindowsUpdateStatus {
+    WindowsUpdateStatus::default()
+}
+
+/// Poll the update status and emit `windows-update-reboot-pending` when a reboot becomes pending.
+pub fn start_watcher(app: tauri::AppHandle) {
+    use std::time::Duration;
+    use tauri::Emitter;
+
+    std::thread::Builder::new()
+        .name("windows-update-watcher".to_string())
+        .spawn(move || {
+            let mut was_pending = false;
+            loop {
+                let status = get_status();
+                if status.reboot_pending && !was_pending {
+                    let _ = app.emit("windows-update-reboot-pending", status.clone());
+                }
+                was_pending = status.reboot_pending;
+
+                // Update installs are slow; checking every few minutes is plenty.
+                std::thread::sleep(Duration::from_secs(300));
+            }
+        })
+        .ok();
+}
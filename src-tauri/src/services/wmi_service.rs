@@ -1,12 +1,17 @@
 //! Shared WMI service with connection pooling and timeout handling
 //! Also includes NVIDIA GPU monitoring via NVML
 
+use serde::Serialize;
+#[cfg(windows)]
 use std::collections::HashMap;
+use std::collections::VecDeque;
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
+#[cfg(windows)]
 use wmi::{Variant, WMIConnection};
 
+#[cfg(windows)]
 use crate::services::pdh;
 
 /// NVIDIA GPU data from NVML
@@ -18,7 +23,15 @@ pub struct NvidiaGpuData {
     pub memory_used_mb: u64,
     pub memory_total_mb: u64,
     pub power_draw_w: u32,
+    /// Fastest fan (kept for single-fan consumers)
     pub fan_speed_percent: u32,
+    /// Every fan reported by the card, in NVML fan index order
+    pub fan_speeds_percent: Vec<u32>,
+    pub power_limit_w: Option<u32>,
+    pub core_clock_mhz: Option<u32>,
+    pub memory_clock_mhz: Option<u32>,
+    /// Performance state ("P0" = maximum performance ... "P15")
+    pub perf_state: Option<String>,
     pub available: bool,
 }
 
@@ -39,6 +52,8 @@ pub struct CachedSystemData {
     pub cpu_name: String,
     pub cpu_usage: f32,
     pub cpu_clock_mhz: u32,
+    pub cpu_max_clock_mhz: u32,
+    pub cpu_core_clocks_mhz: Vec<u32>,
     pub gpu_name: String,
     pub gpu_vendor: String,
     pub gpu_usage_percent: f32,
@@ -60,9 +75,53 @@ pub struct CachedDriveInfo {
     pub free_bytes: u64,
 }
 
+/// Longest window kept in the usage history (used by the 5-minute average)
+const USAGE_HISTORY_WINDOW: Duration = Duration::from_secs(5 * 60);
+
+/// A single CPU/GPU usage sample
+#[derive(Clone, Copy, Debug)]
+struct UsageSample {
+    at: Instant,
+    cpu_usage: f32,
+    gpu_usage: f32,
+}
+
+/// Rolling CPU/GPU usage averages
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct LoadAverages {
+    pub cpu_1min: f32,
+    pub cpu_5min: f32,
+    pub gpu_1min: f32,
+    pub gpu_5min: f32,
+    /// Number of samples in the 5-minute window
+    pub samples: usize,
+}
+
+/// Record a usage sample and drop the ones older than the history window
+fn push_usage_sample(
+    usage_history: &Mutex<VecDeque<UsageSample>>,
+    data: &CachedSystemData,
+    now: Instant,
+) {
+    if let Ok(mut history) = usage_history.lock() {
+        history.push_back(UsageSample {
+            at: now,
+            cpu_usage: data.cpu_usage,
+            gpu_usage: data.gpu_usage_percent,
+        });
+        while history
+            .front()
+            .is_some_and(|s| now.duration_since(s.at) > USAGE_HISTORY_WINDOW)
+        {
+            history.pop_front();
+        }
+    }
+}
+
 /// WMI service that runs queries in background and caches results
 pub struct WmiService {
     cache: Arc<Mutex<CachedSystemData>>,
+    usage_history: Arc<Mutex<VecDeque<UsageSample>>>,
     is_running: Arc<Mutex<bool>>,
 }
 
@@ -76,6 +135,7 @@ impl WmiService {
     pub fn new() -> Self {
         let service = Self {
             cache: Arc::new(Mutex::new(CachedSystemData::default())),
+            usage_history: Arc::new(Mutex::new(VecDeque::new())),
             is_running: Arc::new(Mutex::new(false)),
         };
 
@@ -85,8 +145,10 @@ impl WmiService {
         service
     }
 
+    #[cfg(windows)]
     fn start_background_updates(&self) {
         let cache = Arc::clone(&self.cache);
+        let usage_history = Arc::clone(&self.usage_history);
         let is_running = Arc::clone(&self.is_running);
 
         thread::spawn(move || {
@@ -100,7 +162,9 @@ impl WmiService {
             };
 
             // Initialize NVML for NVIDIA GPU monitoring
+            #[cfg(feature = "nvidia")]
             let nvml = nvml_wrapper::Nvml::init().ok();
+            #[cfg(feature = "nvidia")]
             let nvidia_device = nvml.as_ref().and_then(|n| n.device_by_index(0).ok());
 
             {
@@ -117,6 +181,15 @@ impl WmiService {
                     new_data.cpu_name = cpu_data.0;
                     new_data.cpu_usage = cpu_data.1;
                     new_data.cpu_clock_mhz = cpu_data.2;
+                    new_data.cpu_max_clock_mhz = cpu_data.3;
+                }
+
+                // Per-core clocks (approximated from the performance percentage)
+                if new_data.cpu_max_clock_mhz > 0 {
+                    if let Ok(clocks) = query_cpu_core_clocks(&wmi_con, new_data.cpu_max_clock_mhz)
+                    {
+                        new_data.cpu_core_clocks_mhz = clocks;
+                    }
                 }
 
                 // GPU data (WMI fallback)
@@ -134,6 +207,7 @@ impl WmiService {
                 }
 
                 // NVIDIA GPU data via NVML
+                #[cfg(feature = "nvidia")]
                 if let Some(ref device) = nvidia_device {
                     new_data.nvidia_gpu = query_nvidia_gpu(device);
                     // Override name with NVML data if available
@@ -166,17 +240,56 @@ impl WmiService {
                 // Network - get previous data for speed calculation
                 let prev_network = { cache.lock().map(|c| c.network.clone()).unwrap_or_default() };
                 if let Ok(net) = query_network(&wmi_con, &prev_network) {
+                    super::network_usage::record_sample(
+                        net.download_bytes_sec,
+                        net.upload_bytes_sec,
+                    );
                     new_data.network = net;
                 }
 
-                new_data.last_updated = Some(Instant::now());
+                let now = Instant::now();
+                new_data.last_updated = Some(now);
+
+                // Usage history for rolling averages
+                push_usage_sample(&usage_history, &new_data, now);
 
                 // Update cache
                 if let Ok(mut cache_guard) = cache.lock() {
                     *cache_guard = new_data;
                 }
 
-                // Sleep for 2 seconds before next update
+                // Sleep for 2 seconds before next update (30s while the session is locked)
+                super::session::sleep_poll(Duration::from_secs(2));
+            }
+        });
+    }
+
+    /// Without WMI there is nothing to poll; demo mode feeds fake samples instead.
+    #[cfg(not(windows))]
+    fn start_background_updates(&self) {
+        if !super::demo::is_enabled() {
+            return;
+        }
+
+        let cache = Arc::clone(&self.cache);
+        let usage_history = Arc::clone(&self.usage_history);
+        let is_running = Arc::clone(&self.is_running);
+
+        thread::spawn(move || {
+            if let Ok(mut running) = is_running.lock() {
+                *running = true;
+            }
+
+            loop {
+                let new_data = super::demo::system_data();
+                let now = Instant::now();
+
+                push_usage_sample(&usage_history, &new_data, now);
+
+                if let Ok(mut cache_guard) = cache.lock() {
+                    *cache_guard = new_data;
+                }
+
                 thread::sleep(Duration::from_secs(2));
             }
         });
@@ -189,6 +302,40 @@ impl WmiService {
             .unwrap_or_default()
     }
 
+    /// Average CPU/GPU usage over the last 1 and 5 minutes
+    pub fn get_load_averages(&self) -> LoadAverages {
+        let Ok(history) = self.usage_history.lock() else {
+            return LoadAverages::default();
+        };
+
+        let now = Instant::now();
+        let average = |window: Duration| -> (f32, f32) {
+            let samples: Vec<&UsageSample> = history
+                .iter()
+                .filter(|s| now.duration_since(s.at) <= window)
+                .collect();
+            if samples.is_empty() {
+                return (0.0, 0.0);
+            }
+            let n = samples.len() as f32;
+            (
+                samples.iter().map(|s| s.cpu_usage).sum::<f32>() / n,
+                samples.iter().map(|s| s.gpu_usage).sum::<f32>() / n,
+            )
+        };
+
+        let (cpu_1min, gpu_1min) = average(Duration::from_secs(60));
+        let (cpu_5min, gpu_5min) = average(USAGE_HISTORY_WINDOW);
+
+        LoadAverages {
+            cpu_1min,
+            cpu_5min,
+            gpu_1min,
+            gpu_5min,
+            samples: history.len(),
+        }
+    }
+
     pub fn is_ready(&self) -> bool {
         self.cache
             .lock()
@@ -197,9 +344,12 @@ impl WmiService {
     }
 }
 
-fn query_cpu(wmi_con: &WMIConnection) -> Result<(String, f32, u32), String> {
+#[cfg(windows)]
+fn query_cpu(wmi_con: &WMIConnection) -> Result<(String, f32, u32, u32), String> {
     let results: Vec<HashMap<String, Variant>> = wmi_con
-        .raw_query("SELECT Name, LoadPercentage, CurrentClockSpeed FROM Win32_Processor")
+        .raw_query(
+            "SELECT Name, LoadPercentage, CurrentClockSpeed, MaxClockSpeed FROM Win32_Processor",
+        )
         .map_err(|e| e.to_string())?;
 
     if let Some(cpu) = results.first() {
@@ -219,12 +369,65 @@ fn query_cpu(wmi_con: &WMIConnection) -> Result<(String, f32, u32), String> {
             _ => 0,
         };
 
-        Ok((name, usage, clock_mhz))
+        let max_clock_mhz = match cpu.get("MaxClockSpeed") {
+            Some(Variant::UI4(v)) => *v,
+            _ => 0,
+        };
+
+        Ok((name, usage, clock_mhz, max_clock_mhz))
     } else {
         Err("No CPU data".to_string())
     }
 }
 
+/// Query per-core clocks in MHz.
+///
+/// Windows reports `PercentProcessorPerformance` per logical core relative to the base
+/// clock (values above 100 mean turbo/boost), so multiplying by `MaxClockSpeed` gives a
+/// reasonable approximation of the effective clock of each core.
+#[cfg(windows)]
+fn query_cpu_core_clocks(wmi_con: &WMIConnection, base_clock_mhz: u32) -> Result<Vec<u32>, String> {
+    let results: Vec<HashMap<String, Variant>> = wmi_con
+        .raw_query(
+            "SELECT Name, PercentProcessorPerformance FROM Win32_PerfFormattedData_Counters_ProcessorInformation",
+        )
+        .map_err(|e| e.to_string())?;
+
+    // Instance names look like "0,3" (group,core); skip the "_Total" aggregates.
+    let mut cores: Vec<((u32, u32), u32)> = results
+        .iter()
+        .filter_map(|row| {
+            let name = match row.get("Name") {
+                Some(Variant::String(s)) => s,
+                _ => return None,
+            };
+            if name.contains("_Total") {
+                return None;
+            }
+
+            let (group, core) = name.split_once(',')?;
+            let key = (group.trim().parse().ok()?, core.trim().parse().ok()?);
+
+            let percent: u64 = match row.get("PercentProcessorPerformance") {
+                Some(Variant::String(s)) => s.parse().unwrap_or(0),
+                Some(Variant::UI8(v)) => *v,
+                Some(Variant::UI4(v)) => *v as u64,
+                _ => 0,
+            };
+
+            Some((key, (base_clock_mhz as u64 * percent / 100) as u32))
+        })
+        .collect();
+
+    if cores.is_empty() {
+        return Err("No per-core processor data".to_string());
+    }
+
+    cores.sort_by_key(|(key, _)| *key);
+    Ok(cores.into_iter().map(|(_, mhz)| mhz).collect())
+}
+
+#[cfg(windows)]
 fn query_gpu(wmi_con: &WMIConnection) -> Result<(String, String, u64), String> {
     let results: Vec<HashMap<String, Variant>> = wmi_con
         .raw_query("SELECT Name, AdapterRAM FROM Win32_VideoController")
@@ -262,6 +465,7 @@ fn query_gpu(wmi_con: &WMIConnection) -> Result<(String, String, u64), String> {
 ///
 /// Works on Windows 10/11 when GPU performance counters are available.
 /// Falls back to PDH elsewhere.
+#[cfg(windows)]
 fn query_gpu_usage_percent(wmi_con: &WMIConnection) -> Result<f32, String> {
     let results: Vec<HashMap<String, Variant>> = wmi_con
         .raw_query(
@@ -301,6 +505,7 @@ fn query_gpu_usage_percent(wmi_con: &WMIConnection) -> Result<f32, String> {
     Ok(max_value.clamp(0.0, 100.0))
 }
 
+#[cfg(windows)]
 fn query_ram_speed(wmi_con: &WMIConnection) -> Result<u32, String> {
     let results: Vec<HashMap<String, Variant>> = wmi_con
         .raw_query("SELECT Speed FROM Win32_PhysicalMemory")
@@ -316,6 +521,7 @@ fn query_ram_speed(wmi_con: &WMIConnection) -> Result<u32, String> {
     }
 }
 
+#[cfg(windows)]
 fn query_storage(wmi_con: &WMIConnection) -> Result<Vec<CachedDriveInfo>, String> {
     let results: Vec<HashMap<String, Variant>> = wmi_con
         .raw_query("SELECT DeviceID, VolumeName, FileSystem, Size, FreeSpace FROM Win32_LogicalDisk WHERE DriveType=3")
@@ -376,7 +582,10 @@ fn query_storage(wmi_con: &WMIConnection) -> Result<Vec<CachedDriveInfo>, String
 }
 
 /// Query NVIDIA GPU data via NVML
+#[cfg(all(windows, feature = "nvidia"))]
 fn query_nvidia_gpu(device: &nvml_wrapper::Device) -> NvidiaGpuData {
+    use nvml_wrapper::enum_wrappers::device::{Clock, PerformanceState};
+
     let mut data = NvidiaGpuData::default();
 
     // Get device name
@@ -407,16 +616,31 @@ fn query_nvidia_gpu(device: &nvml_wrapper::Device) -> NvidiaGpuData {
         data.power_draw_w = power / 1000;
     }
 
-    // Get fan speed
-    if let Ok(fan) = device.fan_speed(0) {
-        data.fan_speed_percent = fan;
-    }
+    // Enforced power limit (milliwatts)
+    data.power_limit_w = device.enforced_power_limit().ok().map(|mw| mw / 1000);
+
+    // Current clocks
+    data.core_clock_mhz = device.clock_info(Clock::Graphics).ok();
+    data.memory_clock_mhz = device.clock_info(Clock::Memory).ok();
+
+    data.perf_state = match device.performance_state() {
+        Ok(PerformanceState::Unknown) | Err(_) => None,
+        Ok(state) => Some(format!("P{}", state.as_c())),
+    };
+
+    // Get fan speeds (multi-fan cards report one entry per fan)
+    let fan_count = device.num_fans().unwrap_or(1);
+    data.fan_speeds_percent = (0..fan_count)
+        .filter_map(|i| device.fan_speed(i).ok())
+        .collect();
+    data.fan_speed_percent = data.fan_speeds_percent.iter().copied().max().unwrap_or(0);
 
     data.available = true;
     data
 }
 
 /// Query network interface data via WMI
+#[cfg(windows)]
 fn query_network(
     wmi_con: &WMIConnection,
     prev: &CachedNetworkData,
@@ -3,7 +3,7 @@ import { listen } from '@tauri-apps/api/event'
 import { useEffect, useRef, useState, type CSSProperties } from 'react'
 import { Taskbar } from './components/Taskbar'
 import { buildDefaultConfig } from './config/defaultConfig'
-import { AppConfig, SystemSnapshot } from './types'
+import { AppConfig, SystemSnapshot, WidgetConfig } from './types'
 import { normalizeConfig } from './utils/widgets'
 
 function App() {
@@ -35,17 +35,29 @@ function App() {
     void invoke('prewarm_popups').catch(() => {})
   }, [])
 
-  // Listen for config changes from settings popup
+  // Listen for config changes from settings popup (and hand edits of the profile file)
   useEffect(() => {
-    const unlisten = listen<AppConfig>('config-changed', (event) => {
-      console.warn('Config changed event received:', event.payload)
+    const applyConfig = (next: AppConfig) => {
       isHeightPreviewRef.current = false
       isOpacityPreviewRef.current = false
       isBlurPreviewRef.current = false
-      setConfig(normalizeConfig(event.payload))
+      setConfig(normalizeConfig(next))
+    }
+    const unlisten = listen<AppConfig>('config-changed', (event) => {
+      console.warn('Config changed event received:', event.payload)
+      applyConfig(event.payload)
+    })
+    const unlistenExternal = listen<AppConfig>('profile-externally-changed', (event) => {
+      applyConfig(event.payload)
+    })
+    // Widget commands (toggle/add/remove/reorder) only send the widget list
+    const unlistenWidgets = listen<WidgetConfig[]>('widgets-updated', (event) => {
+      setConfig(prev => normalizeConfig({ ...prev, widgets: event.payload }))
     })
     return () => {
       unlisten.then(fn => fn())
+      unlistenExternal.then(fn => fn())
+      unlistenWidgets.then(fn => fn())
     }
   }, [])
 
@@ -1,6 +1,6 @@
 import { useEffect, useState } from 'react'
 import { invoke } from '@tauri-apps/api/core'
-import { FolderShortcut, FolderShortcutsConfig } from '../../types'
+import { FolderShortcut, FolderShortcutsConfig, OpenFolderResult } from '../../types'
 import { getFolderIconByName } from '../icons'
 import { usePopupExit } from '../../utils/usePopupExit'
 import '../../index.css'
@@ -35,7 +35,11 @@ export default function FoldersPopup() {
     }, [])
 
     const openFolder = (path: string) => {
-        invoke('open_folder', { path }).catch(console.warn)
+        invoke<OpenFolderResult>('open_folder', { path })
+            .then(result => {
+                if (result.warning) console.warn(result.warning)
+            })
+            .catch(console.warn)
         handleClose()
     }
 
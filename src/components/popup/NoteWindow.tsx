@@ -0,0 +1,52 @@
+import { invoke } from '@tauri-apps/api/core'
+import '../../index.css'
+import { NotesEditMode, useNotes } from './notes'
+
+interface NoteWindowProps {
+    noteId: string | null
+}
+
+/** Detached sticky-note window opened via `open_note_window`. */
+export default function NoteWindow({ noteId }: NoteWindowProps) {
+    const { selected, saveState, lastSavedAt, remove, updateSelected } = useNotes(noteId)
+
+    // Only edit the note this window was opened for, never a fallback one.
+    const note = selected && selected.id === noteId ? selected : null
+
+    const close = () => {
+        if (!noteId) return
+        void invoke('close_note_window', { noteId }).catch((err) => {
+            console.warn('Failed to close note window:', err)
+        })
+    }
+
+    const handleRemove = async () => {
+        await remove()
+        close()
+    }
+
+    return (
+        <div className="popup-container notes-popup notes-popup--edit">
+            <div className="popup-header notes-popup__header">
+                <span className="popup-title">{note?.title || 'Nota'}</span>
+                <div className="notes-popup__meta">
+                    <button className="notes-popup__tool" onClick={close} title="Fechar">✕</button>
+                </div>
+            </div>
+
+            <div className="notes-popup__body">
+                {note ? (
+                    <NotesEditMode
+                        selected={note}
+                        saveState={saveState}
+                        lastSavedAt={lastSavedAt}
+                        onUpdate={updateSelected}
+                        onRemove={handleRemove}
+                    />
+                ) : (
+                    <div className="notes-popup__empty">Nota não encontrada</div>
+                )}
+            </div>
+        </div>
+    )
+}
@@ -18,7 +18,7 @@ interface UseNotesReturn {
     togglePinned: () => Promise<void>
 }
 
-export function useNotes(): UseNotesReturn {
+export function useNotes(initialId?: string | null): UseNotesReturn {
     const [notes, setNotes] = useState<Note[]>([])
     const [selectedId, setSelectedId] = useState<string | null>(null)
     const [pinned, setPinned] = useState(false)
@@ -31,9 +31,10 @@ export function useNotes(): UseNotesReturn {
             try {
                 const list = await invoke<Note[]>('list_notes')
                 setNotes(list)
-                setSelectedId(list[0]?.id ?? null)
+                const initial = list.find(n => n.id === initialId) ?? list[0]
+                setSelectedId(initial?.id ?? null)
                 setSaveState('idle')
-                setLastSavedAt(list[0]?.updated_at ?? null)
+                setLastSavedAt(initial?.updated_at ?? null)
                 const isPinned = await invoke<boolean>('get_popup_pinned', { popupName: 'notes-popup' })
                 setPinned(isPinned)
             } catch (err) {
@@ -48,7 +49,7 @@ export function useNotes(): UseNotesReturn {
                 saveTimerRef.current = null
             }
         }
-    }, [])
+    }, [initialId])
 
     const selected = useMemo(() => notes.find(n => n.id === selectedId) ?? null, [notes, selectedId])
 
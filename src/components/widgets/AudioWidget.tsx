@@ -49,7 +49,8 @@ export function AudioWidget({ isLoading }: AudioWidgetProps) {
         
         // deltaY negative = scroll up = increase volume
         // deltaY positive = scroll down = decrease volume
-        const delta = e.deltaY < 0 ? 5 : -5
+        // Only the sign matters; the step size comes from the profile's volumeStep
+        const delta = e.deltaY < 0 ? 1 : -1
         
         try {
             await invoke('adjust_master_volume', { delta })
@@ -11,6 +11,7 @@ import GpuPopup from './components/popup/GpuPopup'
 import HeadsetPopup from './components/popup/HeadsetPopup'
 import MediaPopup from './components/popup/MediaPopup'
 import NetworkPopup from './components/popup/NetworkPopup'
+import NoteWindow from './components/popup/NoteWindow'
 import NotesPopup from './components/popup/NotesPopup'
 import PowerPopup from './components/popup/PowerPopup'
 import RamPopup from './components/popup/RamPopup'
@@ -47,6 +48,8 @@ function Router() {
       return <PowerPopup />
     case 'notes':
       return <NotesPopup />
+    case 'note':
+      return <NoteWindow noteId={params.get('id')} />
     case 'folders':
       return <FoldersPopup />
     case 'taskswitcher':
@@ -7,6 +7,9 @@ export interface CpuData {
     logical_cores: number
     physical_cores: number
     clock_mhz: number | null
+    core_clocks_mhz: number[]
+    /** Highest per-core clock observed since the app started */
+    boost_clock_mhz: number | null
 }
 
 export interface RamData {
@@ -36,6 +39,8 @@ export interface GpuDetailedData extends GpuBasicData {
     memory_clock_mhz: number | null
     fan_speed_rpm: number | null
     fan_speed_percent: number | null
+    /** One entry per fan (multi-fan cards) */
+    fan_speeds_percent: number[]
     voltage_mv: number | null
     pcie_gen: number | null
     pcie_lanes: number | null
@@ -46,6 +51,14 @@ export type GpuData =
     | { type: 'Basic' } & GpuBasicData
     | { type: 'Detailed' } & GpuDetailedData
 
+/** Entry returned by get_gpu_processes (NVIDIA only) */
+export interface GpuProcess {
+    pid: number
+    name: string
+    /** null when the driver doesn't report per-process memory (WDDM) */
+    used_memory_mb: number | null
+}
+
 export interface DriveInfo {
     letter: string
     label: string
@@ -65,6 +78,11 @@ export interface StorageData {
     free_bytes: number
 }
 
+export interface RecycleBinInfo {
+    item_count: number
+    size_bytes: number
+}
+
 export interface NetworkData {
     interface_name: string
     download_bytes_sec: number
@@ -74,6 +92,21 @@ export interface NetworkData {
     is_connected: boolean
 }
 
+export interface LoadAverages {
+    cpu_1min: number
+    cpu_5min: number
+    gpu_1min: number
+    gpu_5min: number
+    samples: number
+}
+
+export interface NetworkUsageEntry {
+    /** "YYYY-MM-DD" for daily entries, "YYYY-MM" for monthly entries */
+    period: string
+    received_bytes: number
+    sent_bytes: number
+}
+
 export interface AudioDevice {
     id: string
     name: string
@@ -128,6 +161,23 @@ export interface HeadsetData {
     supported_features: HeadsetFeatures
 }
 
+/** Paired Bluetooth device (get_bluetooth_devices) */
+export interface BtDevice {
+    name: string
+    connected: boolean
+    battery_percent: number | null
+}
+
+/** Raw iCUE device info (debug_headset_devices) */
+export interface CorsairDeviceDebugInfo {
+    model: string
+    id: string
+    serial: string
+    device_type: number
+    led_count: number
+    channel_count: number
+}
+
 export interface IcueSdkStatus {
     installed: boolean
     sdk_path: string | null
@@ -136,20 +186,94 @@ export interface IcueSdkStatus {
     version: string | null
 }
 
+export type MetricLevel = 'normal' | 'warn' | 'crit'
+
+export interface MetricStatus {
+    cpu: MetricLevel
+    ram: MetricLevel
+    gpu: MetricLevel
+    /** GPU temperature ('normal' when not reported) */
+    temp: MetricLevel
+}
+
 export interface SystemSnapshot {
     cpu: CpuData
     ram: RamData
     gpu: GpuData
     storage: StorageData
+    status: MetricStatus
     timestamp: number
 }
 
+/** Process usage entry (get_top_processes) */
+export interface ProcessUsage {
+    pid: number
+    name: string
+    cpu_percent: number
+    memory_mb: number
+}
+
+/** Windows accent color (get_system_accent_color, system-theme-changed event) */
+export interface AccentColor {
+    hex: string
+    r: number
+    g: number
+    b: number
+    is_dark_theme: boolean
+}
+
+export interface Pong {
+    ok: boolean
+    version: string
+}
+
+export interface Capabilities {
+    platform: string
+    arch: string
+    version: string
+    hardware_monitor: boolean
+    nvidia: boolean
+    audio: boolean
+    headset: boolean
+    media: boolean
+    task_switcher: boolean
+    native_taskbar: boolean
+}
+
+export interface SystemUptime {
+    uptime_seconds: number
+    boot_time_unix: number
+}
+
+/** Result of pick_screen_color / start_color_eyedropper and payload of `color-sampled` */
+export interface ScreenColor {
+    r: number
+    g: number
+    b: number
+    hex: string
+}
+
+/** Entry returned by get_clipboard_history (newest first) */
+export interface ClipboardEntry {
+    text: string
+    copied_at: string
+    /** The copied text was longer than the stored limit */
+    truncated: boolean
+}
+
+/** Payload of `get_session_state` and the `session-locked` / `session-unlocked` events */
+export interface SessionState {
+    locked: boolean
+}
+
 // Config types
 export interface WidgetConfig {
     id: string
     type: string
     enabled: boolean
     order: number
+    /** Per-instance settings (e.g. clock `{ timezone, format }`) */
+    settings?: Record<string, unknown> | null
 }
 
 export interface DisplayConfig {
@@ -158,6 +282,8 @@ export interface DisplayConfig {
     theme: 'dark' | 'light'
     opacity: number
     blur: boolean
+    /** Hide the bar when the cursor leaves it; reveal at the top edge */
+    autoHide?: boolean
 }
 
 export interface PollingConfig {
@@ -172,6 +298,99 @@ export interface AppConfig {
     display: DisplayConfig
     widgets: WidgetConfig[]
     polling: PollingConfig
+    network?: NetworkConfig
+    /** Volume change (percent) per scroll-wheel notch */
+    volumeStep?: number
+    /** Accent color per widget id (e.g. "cpu-1" -> "#22c55e") */
+    widgetColors?: Record<string, string>
+    /** Pre-create popup windows at startup (default true) */
+    prewarmEnabled?: boolean
+    /** Global task switcher accelerator (e.g. "Alt+`"); null/absent = disabled */
+    taskSwitcherHotkey?: string | null
+    thresholds?: ThresholdsConfig
+    quickLaunch?: QuickLaunchConfig
+    /** Executable used to open folder shortcuts; null/absent = Explorer */
+    fileManager?: string | null
+}
+
+/** Usage percentages and temperatures (°C) at which a metric becomes warn / crit */
+export interface ThresholdsConfig {
+    cpuWarn: number
+    cpuCrit: number
+    ramWarn: number
+    ramCrit: number
+    gpuWarn: number
+    gpuCrit: number
+    tempWarn: number
+    tempCrit: number
+}
+
+export interface NetworkConfig {
+    /** Monthly data cap in GB (0 disables warnings) */
+    monthlyDataCapGb: number
+    dataCapWarningPercents: number[]
+    adapterProfiles: NetworkAdapterProfile[]
+}
+
+/** Wi-Fi connection details (get_wifi_info) */
+export interface WifiInfo {
+    has_adapter: boolean
+    is_connected: boolean
+    ssid: string
+    signal_percent: number
+    /** "2.4 GHz" | "5 GHz" | "6 GHz" | "" */
+    band: string
+}
+
+/** Public IP address (get_public_ip) */
+export interface PublicIp {
+    ip: string
+    /** Served from the 5-minute cache */
+    cached: boolean
+}
+
+/** Latency probe result (ping_host) */
+export interface PingResult {
+    min_ms: number
+    avg_ms: number
+    max_ms: number
+    loss_percent: number
+}
+
+export interface NetworkAdapterProfile {
+    name: string
+    /** Adapter name as shown by `netsh interface show interface` */
+    adapter: string
+    dhcp: boolean
+    ipAddress?: string | null
+    subnetMask?: string | null
+    gateway?: string | null
+    dnsServers: string[]
+}
+
+export type FocusAssistState = 'off' | 'priority' | 'alarms' | 'unknown'
+
+export interface WindowsUpdateStatus {
+    reboot_pending: boolean
+    updates_awaiting_reboot: number | null
+    active_hours_start: number | null
+    active_hours_end: number | null
+}
+
+export interface MeteredState {
+    interface: string
+    metered: boolean
+    cost_type: 'unknown' | 'unrestricted' | 'fixed' | 'variable'
+    is_wlan: boolean
+    writable: boolean
+    read_only_reason: string | null
+}
+
+export interface DataCapWarning {
+    threshold_percent: number
+    used_percent: number
+    used_bytes: number
+    cap_bytes: number
 }
 
 // Monitor types
@@ -184,6 +403,32 @@ export interface MonitorInfo {
     x: number
     y: number
     scale_factor: number
+    /** Current refresh rate (0 if unknown) */
+    refresh_hz: number
+    hdr_enabled: boolean
+}
+
+/** Result of is_native_taskbar_visible */
+export interface NativeTaskbarInfo {
+    visible: boolean
+    /** [x, y, width, height] in physical pixels */
+    rect: [number, number, number, number]
+    overlaps_bar: boolean
+}
+
+/** Mode accepted by set_display_resolution */
+export interface DisplayMode {
+    width: number
+    height: number
+    refresh_hz: number
+}
+
+/** Virtual desktop (list_virtual_desktops / get_current_desktop) */
+export interface VirtualDesktop {
+    index: number
+    id: string
+    name: string
+    is_current: boolean
 }
 
 export interface ProfileSummary {
@@ -206,6 +451,14 @@ export interface MediaData {
     thumbnail_base64: string | null
     position_seconds: number
     duration_seconds: number
+    is_shuffle_active: boolean | null
+    repeat_mode: 'none' | 'track' | 'list' | null
+}
+
+export interface MediaArtwork {
+    data_base64: string
+    mime_type: string
+    size_bytes: number
 }
 
 // Notes
@@ -214,6 +467,26 @@ export interface Note {
     title: string
     content: string
     updated_at: string
+    /** Unix timestamp (seconds) of a pending reminder (`note-reminder` event) */
+    remind_at?: number | null
+}
+
+// Timers
+/** Returned by start_timer / list_timers; payload of `timer-tick` (array) and `timer-finished` */
+export interface TimerState {
+    id: string
+    label: string
+    duration_seconds: number
+    remaining_seconds: number
+    started_at: string
+}
+
+export interface NoteBackup {
+    /** e.g. "notes.json.1" (1 = most recent) */
+    name: string
+    modified_at: string
+    size_bytes: number
+    note_count: number | null
 }
 
 // Weather types
@@ -252,12 +525,65 @@ export interface FolderShortcut {
     path: string
     icon: string
     enabled: boolean
+    /** Command used to open the folder, with a {path} placeholder (e.g. "code {path}") */
+    openWith?: string | null
 }
 
 export interface FolderShortcutsConfig {
     shortcuts: FolderShortcut[]
 }
 
+/** Result of get_folder_size; truncated = the walk hit its cap, totals are a lower bound */
+export interface FolderSize {
+    bytes: number
+    file_count: number
+    truncated: boolean
+}
+
+// Quick launch
+export interface QuickLaunchItem {
+    id: string
+    name: string
+    exePath: string
+    args?: string[]
+    /** Custom icon; absent = use the executable's icon (get_process_icon) */
+    icon?: string | null
+}
+
+export interface QuickLaunchConfig {
+    items: QuickLaunchItem[]
+}
+
+/** Shortcuts sharing one normalized path (see find_duplicate_shortcuts) */
+export interface DuplicateShortcutGroup {
+    path: string
+    shortcuts: FolderShortcut[]
+}
+
+export interface OpenFolderResult {
+    opened_path: string
+    /** Set when the folder was missing and its nearest existing parent was opened */
+    warning: string | null
+}
+
+/** Error returned by add_folder_shortcut / update_folder_shortcut */
+export interface FolderShortcutError {
+    kind: 'invalid_path' | 'path_not_found' | 'not_a_directory' | 'duplicate_id' | 'not_found' | 'invalid_open_with' | 'config'
+    message: string
+    path: string | null
+}
+
+// Startup programs
+export type StartupLocation = 'hkcu_run' | 'hklm_run' | 'startup_folder'
+
+export interface StartupEntry {
+    name: string
+    /** Command line (Run keys) or file path (Startup folder) */
+    command: string
+    location: StartupLocation
+    enabled: boolean
+}
+
 export interface LocationData {
     latitude: number
     longitude: number
@@ -266,3 +592,21 @@ export interface LocationData {
     country: string
     success: boolean
 }
+
+export interface AirQualityData {
+    loaded: boolean
+    pm2_5: number
+    pm10: number
+    ozone: number
+    european_aqi: number
+    us_aqi: number
+}
+
+export interface GeoResult {
+    name: string
+    country: string
+    latitude: number
+    longitude: number
+    /** State/region */
+    admin1: string
+}
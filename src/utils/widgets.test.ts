@@ -6,20 +6,45 @@ describe('widgets utils', () => {
   it('normalizes widget list and fills defaults', () => {
     const input = [
       { id: 'cpu-1', type: 'cpu', enabled: false, order: 5 },
-      { id: 'cpu-2', type: 'cpu', enabled: true, order: 1 },
+      { id: 'cpu-1', type: 'cpu', enabled: true, order: 1 },
     ]
 
     const result = normalizeWidgets(input)
 
-    const cpu = result.find(w => w.type === 'cpu')
-    expect(cpu).toBeDefined()
-    expect(cpu?.id).toBe('cpu-2')
-    expect(cpu?.enabled).toBe(true)
+    const cpus = result.filter(w => w.type === 'cpu')
+    expect(cpus).toHaveLength(1)
+    expect(cpus[0].enabled).toBe(true)
+    expect(cpus[0].order).toBe(1)
 
     const missing = result.find(w => w.type === 'network')
     expect(missing).toBeDefined()
   })
 
+  it('keeps multiple instances of the same type with distinct ids', () => {
+    const input = [
+      { id: 'clock-1', type: 'clock', enabled: true, order: 93 },
+      { id: 'clock-2', type: 'clock', enabled: true, order: 94 },
+    ]
+
+    const result = normalizeWidgets(input)
+
+    const clocks = result.filter(w => w.type === 'clock')
+    expect(clocks.map(w => w.id)).toEqual(['clock-1', 'clock-2'])
+  })
+
+  it('carries per-instance settings through', () => {
+    const input = [
+      { id: 'clock-1', type: 'clock', enabled: true, order: 93, settings: { timezone: 'UTC', format: '24h' } },
+      { id: 'clock-2', type: 'clock', enabled: true, order: 94, settings: { timezone: 'Asia/Tokyo' } },
+    ]
+
+    const result = normalizeWidgets(input)
+
+    expect(result.find(w => w.id === 'clock-1')?.settings).toEqual({ timezone: 'UTC', format: '24h' })
+    expect(result.find(w => w.id === 'clock-2')?.settings).toEqual({ timezone: 'Asia/Tokyo' })
+    expect(result.find(w => w.id === 'cpu-1')?.settings).toBeUndefined()
+  })
+
   it('buildDefaultConfig uses widget definitions', () => {
     const config = buildDefaultConfig()
 
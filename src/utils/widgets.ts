@@ -28,14 +28,15 @@ export const WIDGET_DEFINITIONS: WidgetDefinition[] = [
 ]
 
 function widgetKey(widget: Pick<WidgetConfig, 'id' | 'type'>): string {
-  // Prefer stable `type` (we have 1 widget per type today)
-  return widget.type || widget.id
+  // Instances are identified by id (add_widget creates several of one type);
+  // fall back to `type` for legacy entries without an id
+  return widget.id || widget.type
 }
 
 export function normalizeWidgets(widgets: WidgetConfig[] | undefined | null): WidgetConfig[] {
   const existing = Array.isArray(widgets) ? widgets : []
 
-  // Pick best candidate per type (or id) to avoid duplicates.
+  // Pick best candidate per id to avoid duplicates.
   const byKey = new Map<string, WidgetConfig>()
   for (const w of existing) {
     const key = widgetKey(w)
@@ -50,34 +51,36 @@ export function normalizeWidgets(widgets: WidgetConfig[] | undefined | null): Wi
     }
   }
 
+  const knownTypes = new Set(WIDGET_DEFINITIONS.map(d => d.type))
+  const instances = [...byKey.values()]
+  const normalized: WidgetConfig[] = []
+
   for (const def of WIDGET_DEFINITIONS) {
-    const key = def.type
-    if (!byKey.has(key)) {
-      byKey.set(key, {
+    const ofType = instances.filter(w => w.type === def.type)
+    if (ofType.length === 0) {
+      // Every known widget type keeps at least one (possibly disabled) instance.
+      normalized.push({
         id: def.id,
         type: def.type,
         enabled: def.defaultEnabled,
         order: def.defaultOrder,
       })
-    } else {
-      const current = byKey.get(key)!
-      // Ensure required fields exist and keep id stable when possible.
-      byKey.set(key, {
+      continue
+    }
+    for (const current of ofType) {
+      // Ensure required fields exist.
+      normalized.push({
         id: current.id || def.id,
-        type: current.type || def.type,
+        type: def.type,
         enabled: typeof current.enabled === 'boolean' ? current.enabled : def.defaultEnabled,
         order: typeof current.order === 'number' ? current.order : def.defaultOrder,
+        ...(current.settings !== undefined ? { settings: current.settings } : {}),
       })
     }
   }
 
   // Keep unknown widgets too (future-proof) by appending them.
-  const knownTypes = new Set(WIDGET_DEFINITIONS.map(d => d.type))
-  const unknown = existing
-    .filter(w => !knownTypes.has(w.type))
-    .map(w => ({ ...w }))
-
-  const normalized = [...byKey.values(), ...unknown]
+  normalized.push(...instances.filter(w => !knownTypes.has(w.type)))
 
   // Ensure deterministic ordering
   normalized.sort((a, b) => a.order - b.order)